@@ -1,9 +1,21 @@
 use anyhow::Context;
+use applykit_core::banks::load_banks;
+use applykit_core::config::{load_config, resolve_output_base};
 use applykit_core::types::{Baseline, GenerateInput, Track};
-use applykit_core::{generate_packet, GenerateOptions};
-use chrono::NaiveDate;
+use applykit_core::{
+    add_contact, add_interview, backup_database, bank_coverage, check_database, check_llm_health,
+    clear_llm_cache, collect_due, collect_fit_calibration, collect_insights,
+    collect_tracker_export, default_backup_path, delete_contact, delete_interview, generate_packet,
+    get_job_by_id, import_tracker_csv, lint_banks, list_contacts, list_interviews, list_job_events,
+    list_jobs, query_jobs, reindex_packets, reminders_to_ics, restore_database, search_jobs,
+    tracker_export_csv, update_contact, update_interview, update_job_status, CalibrationBin,
+    Contact, ContactInput, ContactRole, DuplicateReason, FitCalibration, Funnel, GapKind,
+    GenerateOptions, Insights, Interview, InterviewInput, JobQuery, JobSortKey, JobStatusUpdate,
+    LintSeverity, Reminder, Store, TermKind, TrackerImportOptions,
+};
+use chrono::{Local, NaiveDate, Utc};
 use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser, Debug)]
 #[command(name = "applykit")]
@@ -35,6 +47,466 @@ enum Commands {
         track_override: Option<String>,
         #[arg(long, default_value_t = false)]
         allow_unapproved: bool,
+        /// Call the LLM even when a cached response exists (and do not cache the result).
+        #[arg(long, default_value_t = false)]
+        no_llm_cache: bool,
+        /// Regenerate into this existing job id instead of creating a new job.
+        #[arg(long, value_name = "JOB_ID")]
+        regenerate: Option<String>,
+    },
+    Llm {
+        #[command(subcommand)]
+        command: LlmCommands,
+    },
+    Jobs {
+        #[command(subcommand)]
+        command: JobsCommands,
+    },
+    Contacts {
+        #[command(subcommand)]
+        command: ContactsCommands,
+    },
+    Interviews {
+        #[command(subcommand)]
+        command: InterviewsCommands,
+    },
+    Db {
+        #[command(subcommand)]
+        command: DbCommands,
+    },
+    Tracker {
+        #[command(subcommand)]
+        command: TrackerCommands,
+    },
+    Bank {
+        #[command(subcommand)]
+        command: BankCommands,
+    },
+    /// Conversion funnels by track, source, baseline and fit score, plus JD terms that
+    /// correlate with replies.
+    Insights {
+        #[arg(long)]
+        json: bool,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Reply and interview rates by fit score and fit component, with suggested `[scoring]`
+    /// weights and how well the current and suggested weights separate replies.
+    Calibrate {
+        #[arg(long)]
+        json: bool,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Full-text search over company, role, JD text, notes and generated resumes.
+    Search {
+        query: String,
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DbCommands {
+    /// Restore missing or stale jobs in applykit.db from the packet folders on disk.
+    Reindex {
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Copy applykit.db with SQLite's online backup API (default: <outdir>/backups/).
+    Backup {
+        #[arg(long)]
+        to: Option<PathBuf>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Replace applykit.db with a backup; the current database is kept as a pre-restore copy.
+    Restore {
+        backup: PathBuf,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Run PRAGMA integrity_check plus orphaned-row and missing-packet checks.
+    Check {
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BankCommands {
+    /// Which bullets and skills packets used, which never come up, and what JDs asked for
+    /// that the banks lack.
+    Coverage {
+        #[arg(long)]
+        json: bool,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Validate bullet_bank.json and skills_bank.json; exits non-zero on errors.
+    Lint {
+        #[arg(long)]
+        json: bool,
+        /// Treat warnings as errors.
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TrackerCommands {
+    /// Export every job with its status history and events.
+    Export {
+        /// csv or json.
+        #[arg(long, default_value = "csv")]
+        format: String,
+        /// Write here instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Create jobs from a spreadsheet CSV, skipping ones already tracked.
+    Import {
+        file: PathBuf,
+        /// Map a field to a column header, e.g. `--map role="Job Title"`. Repeatable.
+        #[arg(long = "map", value_name = "FIELD=HEADER")]
+        map: Vec<String>,
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ContactsCommands {
+    /// List a job's contacts.
+    List {
+        job_id: String,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Add a contact; recruiter and hiring manager names greet generated messages.
+    Add {
+        job_id: String,
+        /// recruiter, hiring_manager or referrer.
+        #[arg(long)]
+        role: String,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Replace a contact's details.
+    Update {
+        job_id: String,
+        id: i64,
+        #[arg(long)]
+        role: String,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    Remove {
+        id: i64,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum InterviewsCommands {
+    /// List a job's interview rounds by date.
+    List {
+        job_id: String,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    Add {
+        job_id: String,
+        /// YYYY-MM-DD, optionally followed by a time.
+        #[arg(long)]
+        date: String,
+        /// Round type, e.g. phone_screen, technical, onsite.
+        #[arg(long = "type")]
+        kind: String,
+        /// Repeat for each interviewer.
+        #[arg(long = "interviewer")]
+        interviewers: Vec<String>,
+        #[arg(long)]
+        outcome: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Replace a round's details.
+    Update {
+        job_id: String,
+        id: i64,
+        #[arg(long)]
+        date: String,
+        #[arg(long = "type")]
+        kind: String,
+        #[arg(long = "interviewer")]
+        interviewers: Vec<String>,
+        #[arg(long)]
+        outcome: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    Remove {
+        id: i64,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum JobsCommands {
+    /// List jobs with optional filters, sorting and paging.
+    List {
+        /// Repeat to match any of several statuses.
+        #[arg(long)]
+        status: Vec<String>,
+        #[arg(long)]
+        track: Option<String>,
+        #[arg(long)]
+        source: Option<String>,
+        /// Case-insensitive substring of the company name.
+        #[arg(long)]
+        company: Option<String>,
+        #[arg(long)]
+        min_fit: Option<i64>,
+        #[arg(long)]
+        max_fit: Option<i64>,
+        /// First update day to include (YYYY-MM-DD).
+        #[arg(long)]
+        from: Option<String>,
+        /// Last update day to include (YYYY-MM-DD).
+        #[arg(long)]
+        to: Option<String>,
+        /// updated, created, company, role, fit or status.
+        #[arg(long, default_value = "updated")]
+        sort: String,
+        #[arg(long, default_value_t = false)]
+        asc: bool,
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Print a job's event history, oldest first.
+    Timeline {
+        id: String,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// Move a job to another status; next action and notes are kept unless given.
+    Status {
+        id: String,
+        status: String,
+        #[arg(long)]
+        next_action: Option<String>,
+        /// Due date for the next action (YYYY-MM-DD); pass an empty string to clear it.
+        #[arg(long)]
+        due: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        /// Why the job closed; only stored when entering a closed status.
+        #[arg(long)]
+        closed_reason: Option<String>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+    /// List overdue and upcoming next actions, including suggested follow-ups.
+    Due {
+        /// How many days ahead count as upcoming.
+        #[arg(long, default_value_t = 14)]
+        days: i64,
+        /// Evaluate as of this date (YYYY-MM-DD) instead of today.
+        #[arg(long)]
+        today: Option<String>,
+        /// Also write the listed reminders to this .ics file.
+        #[arg(long)]
+        ics: Option<PathBuf>,
+        #[arg(long)]
+        outdir: Option<PathBuf>,
+    },
+}
+
+fn print_funnels(label: &str, funnels: &[Funnel]) {
+    if funnels.is_empty() {
+        return;
+    }
+    println!("{label}");
+    for f in funnels {
+        println!(
+            "  {:<28} applied {:>3}  reply {:>3} ({:>3.0}%)  interview {:>3} ({:>3.0}%)  offer {:>3} ({:>3.0}%)",
+            f.key,
+            f.applied,
+            f.replied,
+            f.reply_rate * 100.0,
+            f.interviewed,
+            f.interview_rate * 100.0,
+            f.offered,
+            f.offer_rate * 100.0
+        );
+    }
+}
+
+fn print_insights(insights: &Insights) {
+    if !insights.missing_skills.is_empty() {
+        println!("MOST MISSING SKILLS (packets)");
+    }
+    for missing in &insights.missing_skills {
+        let note = match (missing.kind, missing.unapproved) {
+            (GapKind::Tool, true) => "tool, in bank but not approved",
+            (GapKind::Tool, false) => "tool, not in bank",
+            (GapKind::Requirement, _) => "requirement",
+        };
+        println!("  {:<32} {:>3}  {note}", missing.skill, missing.jobs);
+    }
+    if insights.overall.applied == 0 {
+        println!("No applications yet; funnels start once a job reaches applied");
+        return;
+    }
+    print_funnels("OVERALL", std::slice::from_ref(&insights.overall));
+    print_funnels("BY TRACK", &insights.by_track);
+    print_funnels("BY SOURCE", &insights.by_source);
+    print_funnels("BY BASELINE", &insights.by_baseline);
+    print_funnels("BY FIT SCORE", &insights.by_fit_bucket);
+    match insights.median_days_to_reply {
+        Some(days) => {
+            println!("Median time to reply: {days:.1} days ({} replies)", insights.reply_samples)
+        }
+        None => println!("Median time to reply: no dated replies yet"),
+    }
+    if !insights.keyword_correlations.is_empty() {
+        println!("JD TERMS (replied/applied, reply rate, lift)");
+    }
+    for term in &insights.keyword_correlations {
+        let kind = match term.kind {
+            TermKind::Keyword => "keyword",
+            TermKind::Tool => "tool",
+        };
+        println!(
+            "  {:<24} {:<7} {}/{} {:>4.0}%  {:.2}x",
+            term.term,
+            kind,
+            term.positive,
+            term.jobs,
+            term.positive_rate * 100.0,
+            term.lift
+        );
+    }
+}
+
+fn print_bins(bins: &[CalibrationBin]) {
+    for bin in bins {
+        println!(
+            "    {:<9} jobs {:>3}  reply {:>3.0}%  interview {:>3.0}%",
+            bin.label,
+            bin.jobs,
+            bin.reply_rate * 100.0,
+            bin.interview_rate * 100.0
+        );
+    }
+}
+
+fn print_calibration(calibration: &FitCalibration) {
+    println!(
+        "{} applied jobs with a fit breakdown, {} got a reply",
+        calibration.jobs, calibration.replied
+    );
+    println!("FIT TOTAL");
+    print_bins(&calibration.total_bins);
+    for component in &calibration.components {
+        println!(
+            "{} (weight {} -> {}, separation {:+.2})",
+            component.component.to_ascii_uppercase(),
+            component.weight,
+            component.suggested_weight,
+            component.separation
+        );
+        print_bins(&component.bins);
+    }
+    let auc = |v: Option<f64>| v.map(|v| format!("{v:.2}")).unwrap_or_else(|| "n/a".to_string());
+    println!(
+        "Reply separation (chance a replied job outscores one without a reply): {} now, {} with suggested weights",
+        auc(calibration.separation_before),
+        auc(calibration.separation_after)
+    );
+    if !calibration.enough_data {
+        println!(
+            "Weights are suggested once 10 applied jobs have packets and some, but not all, got a reply"
+        );
+        return;
+    }
+    let s = &calibration.suggested;
+    println!("Suggested applykit.toml:");
+    println!("[scoring]");
+    println!("role_match = {}", s.role_match);
+    println!("stack_match = {}", s.stack_match);
+    println!("scale_match = {}", s.scale_match);
+    println!("rigor_match = {}", s.rigor_match);
+    println!("signal_boost = {}", s.signal_boost);
+}
+
+fn print_reminders(label: &str, reminders: &[Reminder]) {
+    for r in reminders {
+        println!("{label} {} {} - {}: {} [{}]", r.due, r.company, r.role, r.action, r.job_id);
+    }
+}
+
+/// Explicit `--outdir`, or the configured output base (where `applykit.db` lives).
+fn output_base(cwd: &Path, outdir: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    match outdir {
+        Some(dir) => Ok(dir),
+        None => Ok(resolve_output_base(&load_config(cwd)?.output.base_dir)),
+    }
+}
+
+/// The tracker under the output base, opened (and migrated) once for the command.
+fn open_store(cwd: &Path, outdir: Option<PathBuf>) -> anyhow::Result<Store> {
+    Store::open(&output_base(cwd, outdir)?.join("applykit.db"))
+}
+
+/// The tracker for read-only commands, which should never create one; `None` after saying so
+/// when the output base has no database yet.
+fn open_existing_store(cwd: &Path, outdir: Option<PathBuf>) -> anyhow::Result<Option<Store>> {
+    let db_path = output_base(cwd, outdir)?.join("applykit.db");
+    let store = Store::open_existing(&db_path)?;
+    if store.is_none() {
+        eprintln!("no tracker database at {}", db_path.display());
+    }
+    Ok(store)
+}
+
+#[derive(Subcommand, Debug)]
+enum LlmCommands {
+    /// Ping the configured provider, list its models and measure latency.
+    Check,
+    /// Delete cached LLM responses under the output base.
+    ClearCache {
+        #[arg(long)]
+        outdir: Option<PathBuf>,
     },
 }
 
@@ -53,6 +525,8 @@ fn main() -> anyhow::Result<()> {
             date,
             track_override,
             allow_unapproved,
+            no_llm_cache,
+            regenerate,
         } => {
             let jd_text = std::fs::read_to_string(&jd)
                 .with_context(|| format!("reading JD file {}", jd.display()))?;
@@ -78,7 +552,13 @@ fn main() -> anyhow::Result<()> {
                     track_override,
                     allow_unapproved,
                 },
-                GenerateOptions { repo_root: cwd },
+                GenerateOptions {
+                    repo_root: cwd,
+                    cancel: None,
+                    bypass_llm_cache: no_llm_cache,
+                    regenerate_job_id: regenerate,
+                    store: None,
+                },
             )?;
 
             println!("Packet generated successfully");
@@ -89,8 +569,560 @@ fn main() -> anyhow::Result<()> {
             for path in result.files_written {
                 println!("- {}", path.display());
             }
+            if !result.duplicate_jobs.is_empty() {
+                println!("Possible duplicates:");
+                for job in &result.duplicate_jobs {
+                    let reason = match job.reason {
+                        DuplicateReason::SameJd => "same JD",
+                        DuplicateReason::SameCompanyRole => "same company and role",
+                    };
+                    println!("- {} [{}] ({reason})", job.job_id, job.status);
+                }
+                println!("Use --regenerate <JOB_ID> to update an existing job instead.");
+            }
+        }
+        Commands::Llm { command: LlmCommands::Check } => {
+            let report = check_llm_health(&cwd)?;
+            println!("Provider: {} ({})", report.provider, report.base_url);
+            match (report.reachable, report.latency_ms) {
+                (true, Some(ms)) => println!("Reachable: yes ({ms} ms)"),
+                _ => println!("Reachable: no"),
+            }
+            if let Some(err) = &report.error {
+                println!("Error: {err}");
+            }
+            if !report.models.is_empty() {
+                println!("Models:");
+                for model in &report.models {
+                    println!("- {model}");
+                }
+            }
+            println!(
+                "Configured model {}: {}",
+                report.model,
+                if report.model_available { "available" } else { "missing" }
+            );
+            if !report.reachable || !report.model_available {
+                anyhow::bail!("llm health check failed");
+            }
+        }
+        Commands::Llm { command: LlmCommands::ClearCache { outdir } } => {
+            let removed = clear_llm_cache(&output_base(&cwd, outdir)?)?;
+            println!("Removed {removed} cached LLM responses");
+        }
+        Commands::Jobs {
+            command:
+                JobsCommands::List {
+                    status,
+                    track,
+                    source,
+                    company,
+                    min_fit,
+                    max_fit,
+                    from,
+                    to,
+                    sort,
+                    asc,
+                    limit,
+                    offset,
+                    outdir,
+                },
+        } => {
+            let parse_day = |value: Option<String>| {
+                value.map(|v| NaiveDate::parse_from_str(&v, "%Y-%m-%d")).transpose()
+            };
+            let query = JobQuery {
+                statuses: status,
+                track,
+                source,
+                company,
+                fit_min: min_fit,
+                fit_max: max_fit,
+                updated_from: parse_day(from)?,
+                updated_to: parse_day(to)?,
+                sort: sort.parse::<JobSortKey>().map_err(anyhow::Error::msg)?,
+                ascending: asc,
+                limit: Some(limit),
+                offset,
+            };
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let page = query_jobs(&store, &query)?;
+            for job in &page.jobs {
+                println!(
+                    "{}  {} - {} [{}] fit {} updated {}",
+                    job.id,
+                    job.company,
+                    job.role,
+                    job.status,
+                    job.fit_total.map(|f| f.to_string()).unwrap_or_else(|| "-".to_string()),
+                    job.updated_at
+                );
+            }
+            let shown = page.offset + page.jobs.len();
+            println!("Showing {} of {} jobs", page.jobs.len(), page.total);
+            if let Some(next) = page.next_offset {
+                println!("More: --offset {next} ({} remaining)", page.total - shown);
+            }
+        }
+        Commands::Jobs { command: JobsCommands::Timeline { id, outdir } } => {
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let events = list_job_events(&store, &id)?;
+            if events.is_empty() {
+                println!("No events for {id}");
+            }
+            for event in events {
+                println!("{} {} {}", event.created_at, event.event_type.as_str(), event.payload);
+            }
+        }
+        Commands::Jobs {
+            command:
+                JobsCommands::Status { id, status, next_action, due, notes, closed_reason, outdir },
+        } => {
+            let workflow = load_config(&cwd)?.tracker;
+            let store = open_store(&cwd, outdir)?;
+            let job = get_job_by_id(&store, &id)?
+                .ok_or_else(|| anyhow::anyhow!("job not found: {id}"))?;
+            update_job_status(
+                &store,
+                &workflow,
+                JobStatusUpdate {
+                    id: &id,
+                    status: &status,
+                    next_action: next_action.as_deref().or(job.next_action.as_deref()),
+                    next_action_due: due.as_deref().or(job.next_action_due.as_deref()),
+                    notes: notes.as_deref().or(job.notes.as_deref()),
+                    closed_reason: closed_reason.as_deref(),
+                },
+            )?;
+            println!("{id}: {} -> {}", job.status, status.to_ascii_lowercase());
+        }
+        Commands::Contacts { command } => run_contacts(&cwd, command)?,
+        Commands::Interviews { command } => run_interviews(&cwd, command)?,
+        Commands::Db { command } => run_db(&cwd, command)?,
+        Commands::Tracker { command } => run_tracker(&cwd, command)?,
+        Commands::Bank { command } => run_bank(&cwd, command)?,
+        Commands::Insights { json, outdir } => {
+            let banks = load_banks(&cwd)?;
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let insights = collect_insights(&store, &banks, &load_config(&cwd)?.tracker)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&insights)?);
+            } else {
+                print_insights(&insights);
+            }
+        }
+        Commands::Calibrate { json, outdir } => {
+            let config = load_config(&cwd)?;
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let calibration = collect_fit_calibration(&store, &config.scoring, &config.tracker)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&calibration)?);
+            } else {
+                print_calibration(&calibration);
+            }
+        }
+        Commands::Search { query, limit, outdir } => {
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let hits = search_jobs(&store, &query, limit)?;
+            if hits.is_empty() {
+                println!("No jobs match {query:?}");
+            }
+            for (rank, hit) in hits.iter().enumerate() {
+                println!(
+                    "{:>2}. {}  {} - {} [{}]",
+                    rank + 1,
+                    hit.job_id,
+                    hit.company,
+                    hit.role,
+                    hit.status
+                );
+                println!("    {}", hit.snippet.replace('\n', " "));
+            }
+        }
+        Commands::Jobs { command: JobsCommands::Due { days, today, ics, outdir } } => {
+            let workflow = load_config(&cwd)?.tracker;
+            let Some(store) = open_existing_store(&cwd, outdir)? else {
+                return Ok(());
+            };
+            let today = match today {
+                Some(v) => NaiveDate::parse_from_str(&v, "%Y-%m-%d")?,
+                None => Local::now().date_naive(),
+            };
+            let report = collect_due(&list_jobs(&store)?, &workflow, today, days);
+            if report.overdue.is_empty() && report.upcoming.is_empty() {
+                println!("Nothing due in the next {days} days");
+            }
+            print_reminders("OVERDUE", &report.overdue);
+            print_reminders("UPCOMING", &report.upcoming);
+            if let Some(path) = ics {
+                let reminders =
+                    report.overdue.iter().chain(&report.upcoming).cloned().collect::<Vec<_>>();
+                std::fs::write(&path, reminders_to_ics(&reminders, Utc::now()))
+                    .with_context(|| format!("writing {}", path.display()))?;
+                println!("Wrote {} reminders to {}", reminders.len(), path.display());
+            }
         }
     }
 
     Ok(())
 }
+
+fn run_db(cwd: &Path, command: DbCommands) -> anyhow::Result<()> {
+    match command {
+        DbCommands::Reindex { outdir } => {
+            let base = output_base(cwd, outdir)?;
+            let report = reindex_packets(&Store::open(&base.join("applykit.db"))?, &base)?;
+            println!("Scanned {} packet folders in {}", report.scanned, base.display());
+            for (label, ids) in [("Restored", &report.inserted), ("Refreshed", &report.updated)] {
+                for id in ids {
+                    println!("{label}: {id}");
+                }
+            }
+            println!("Unchanged: {}", report.unchanged);
+            for dir in &report.superseded {
+                println!("Superseded by a newer packet: {}", dir.display());
+            }
+            for skipped in &report.skipped {
+                println!("Skipped {}: {}", skipped.packet_dir.display(), skipped.reason);
+            }
+            for missing in &report.missing {
+                println!("Missing packet folder for {}: {}", missing.job_id, missing.output_dir);
+            }
+        }
+        DbCommands::Backup { to, outdir } => {
+            let db_path = output_base(cwd, outdir)?.join("applykit.db");
+            let dest = to.unwrap_or_else(|| default_backup_path(&db_path));
+            let info = backup_database(&db_path, &dest)?;
+            println!(
+                "Backed up {} jobs (schema v{}) to {}",
+                info.jobs,
+                info.schema_version,
+                info.path.display()
+            );
+        }
+        DbCommands::Restore { backup, outdir } => {
+            let db_path = output_base(cwd, outdir)?.join("applykit.db");
+            let info = restore_database(&db_path, &backup)?;
+            if let Some(copy) = &info.replaced_copy {
+                println!("Previous database kept at {}", copy.display());
+            }
+            println!(
+                "Restored {} jobs from {} (schema v{} -> v{})",
+                info.jobs,
+                backup.display(),
+                info.backup_version,
+                info.schema_version
+            );
+        }
+        DbCommands::Check { outdir } => {
+            let db_path = output_base(cwd, outdir)?.join("applykit.db");
+            let report = check_database(&db_path)?;
+            println!("Integrity: {}", report.integrity.join("; "));
+            println!("Schema: v{} (latest v{})", report.schema_version, report.latest_version);
+            for (table, count) in report.orphaned.iter().filter(|(_, count)| **count > 0) {
+                println!("Orphaned rows in {table}: {count}");
+            }
+            for missing in &report.missing_packets {
+                println!("Missing packet folder for {}: {}", missing.job_id, missing.output_dir);
+            }
+            if !report.is_ok() {
+                anyhow::bail!("database check found problems in {}", db_path.display());
+            }
+            println!("OK");
+        }
+    }
+    Ok(())
+}
+
+fn run_bank(cwd: &Path, command: BankCommands) -> anyhow::Result<()> {
+    match command {
+        BankCommands::Coverage { json, outdir } => {
+            let base = output_base(cwd, outdir)?;
+            let report = bank_coverage(&load_banks(cwd)?, &base)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            println!("Scanned {} packets in {}", report.packets, base.display());
+            for skipped in &report.skipped {
+                println!("Skipped {}: {}", skipped.packet_dir.display(), skipped.reason);
+            }
+            println!("BULLETS (times selected)");
+            for bullet in &report.bullets {
+                let note = if bullet.approved { "" } else { "  (unapproved)" };
+                println!("  {:<28} {:>3}{note}", bullet.id, bullet.selected);
+            }
+            if !report.never_used.is_empty() {
+                println!("Approved but never selected: {}", report.never_used.join(", "));
+            }
+            for bullet in &report.unapproved_would_select {
+                println!(
+                    "Unapproved {} would have been selected in {} packets",
+                    bullet.id, bullet.blocked
+                );
+            }
+            if !report.unknown_bullet_ids.is_empty() {
+                println!(
+                    "Selected but no longer in the bank: {}",
+                    report.unknown_bullet_ids.join(", ")
+                );
+            }
+            println!("SKILLS (JDs naming them)");
+            for skill in &report.skills {
+                let note = if skill.approved { "" } else { "  (unapproved)" };
+                println!("  {:<28} {:>3}{note}", skill.name, skill.matched);
+            }
+            if !report.unmatched_skills.is_empty() {
+                println!("Approved but never in a JD: {}", report.unmatched_skills.join(", "));
+            }
+            if !report.missing_skills.is_empty() {
+                println!("REQUESTED BUT NOT IN skills_bank.json (packets)");
+            }
+            for missing in &report.missing_skills {
+                println!(
+                    "  {:<32} {:>3}  e.g. {}",
+                    missing.skill, missing.packets, missing.example
+                );
+            }
+        }
+        BankCommands::Lint { json, strict } => {
+            let report = lint_banks(cwd)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                for issue in &report.issues {
+                    let severity = match issue.severity {
+                        LintSeverity::Error => "error",
+                        LintSeverity::Warning => "warning",
+                    };
+                    let id = issue.id.as_deref().map(|id| format!(" {id}")).unwrap_or_default();
+                    println!("{severity}[{}] {}{id}: {}", issue.code, issue.file, issue.message);
+                }
+                println!(
+                    "Checked {} bullets and {} skills: {} errors, {} warnings",
+                    report.bullets,
+                    report.skills,
+                    report.errors(),
+                    report.warnings()
+                );
+            }
+            if !report.is_ok() {
+                anyhow::bail!("bank lint found {} errors", report.errors());
+            }
+            if strict && report.warnings() > 0 {
+                anyhow::bail!("bank lint found {} warnings (--strict)", report.warnings());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_tracker(cwd: &Path, command: TrackerCommands) -> anyhow::Result<()> {
+    match command {
+        TrackerCommands::Export { format, out, outdir } => {
+            let Some(store) = open_existing_store(cwd, outdir)? else {
+                return Ok(());
+            };
+            let export = collect_tracker_export(&store)?;
+            let body = match format.to_ascii_lowercase().as_str() {
+                "csv" => tracker_export_csv(&export),
+                "json" => serde_json::to_string_pretty(&export)? + "\n",
+                other => anyhow::bail!("unknown export format: {other} (expected csv or json)"),
+            };
+            match out {
+                Some(path) => {
+                    std::fs::write(&path, body)
+                        .with_context(|| format!("writing {}", path.display()))?;
+                    println!("Exported {} jobs to {}", export.jobs.len(), path.display());
+                }
+                None => print!("{body}"),
+            }
+        }
+        TrackerCommands::Import { file, map, dry_run, outdir } => {
+            let csv_text = std::fs::read_to_string(&file)
+                .with_context(|| format!("reading {}", file.display()))?;
+            let mut column_overrides = std::collections::BTreeMap::new();
+            for pair in map {
+                let (field, header) = pair.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!("invalid --map {pair} (expected FIELD=HEADER)")
+                })?;
+                column_overrides.insert(field.trim().to_string(), header.trim().to_string());
+            }
+            let workflow = load_config(cwd)?.tracker;
+            let report = import_tracker_csv(
+                &open_store(cwd, outdir)?,
+                &csv_text,
+                &workflow,
+                &TrackerImportOptions { column_overrides, dry_run },
+            )?;
+            for (field, header) in &report.mapping {
+                println!("{field} <- {header}");
+            }
+            let verb = if report.dry_run { "Would create" } else { "Created" };
+            for id in &report.created {
+                println!("{verb}: {id}");
+            }
+            for issue in &report.duplicates {
+                println!(
+                    "Duplicate (line {}): {} - {}: {}",
+                    issue.line, issue.company, issue.role, issue.reason
+                );
+            }
+            for issue in &report.errors {
+                println!(
+                    "Error (line {}): {} - {}: {}",
+                    issue.line, issue.company, issue.role, issue.reason
+                );
+            }
+            println!(
+                "{} rows: {} {}, {} duplicates, {} errors",
+                report.rows,
+                report.created.len(),
+                if report.dry_run { "to create" } else { "created" },
+                report.duplicates.len(),
+                report.errors.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn print_contact(contact: &Contact) {
+    println!(
+        "#{} {} {}{}",
+        contact.id,
+        contact.role.as_str(),
+        contact.name,
+        contact.email.as_deref().map(|e| format!(" <{e}>")).unwrap_or_default()
+    );
+}
+
+fn print_interview(interview: &Interview) {
+    println!(
+        "#{} {} {} [{}] {}",
+        interview.id,
+        interview.scheduled_at,
+        interview.kind,
+        interview.interviewers.join(", "),
+        interview.outcome.as_deref().unwrap_or("pending")
+    );
+}
+
+fn run_contacts(cwd: &Path, command: ContactsCommands) -> anyhow::Result<()> {
+    match command {
+        ContactsCommands::List { job_id, outdir } => {
+            let Some(store) = open_existing_store(cwd, outdir)? else {
+                return Ok(());
+            };
+            let contacts = list_contacts(&store, &job_id)?;
+            if contacts.is_empty() {
+                println!("No contacts for {job_id}");
+            }
+            contacts.iter().for_each(print_contact);
+        }
+        ContactsCommands::Add { job_id, role, name, email, notes, outdir } => {
+            let contact = add_contact(
+                &open_store(cwd, outdir)?,
+                ContactInput {
+                    job_id: &job_id,
+                    role: role.parse::<ContactRole>().map_err(anyhow::Error::msg)?,
+                    name: &name,
+                    email: email.as_deref(),
+                    notes: notes.as_deref(),
+                },
+            )?;
+            print_contact(&contact);
+        }
+        ContactsCommands::Update { job_id, id, role, name, email, notes, outdir } => {
+            let contact = update_contact(
+                &open_store(cwd, outdir)?,
+                id,
+                ContactInput {
+                    job_id: &job_id,
+                    role: role.parse::<ContactRole>().map_err(anyhow::Error::msg)?,
+                    name: &name,
+                    email: email.as_deref(),
+                    notes: notes.as_deref(),
+                },
+            )?;
+            print_contact(&contact);
+        }
+        ContactsCommands::Remove { id, outdir } => {
+            if !delete_contact(&open_store(cwd, outdir)?, id)? {
+                anyhow::bail!("contact not found: {id}");
+            }
+            println!("Removed contact #{id}");
+        }
+    }
+    Ok(())
+}
+
+fn run_interviews(cwd: &Path, command: InterviewsCommands) -> anyhow::Result<()> {
+    match command {
+        InterviewsCommands::List { job_id, outdir } => {
+            let Some(store) = open_existing_store(cwd, outdir)? else {
+                return Ok(());
+            };
+            let rounds = list_interviews(&store, &job_id)?;
+            if rounds.is_empty() {
+                println!("No interviews for {job_id}");
+            }
+            rounds.iter().for_each(print_interview);
+        }
+        InterviewsCommands::Add { job_id, date, kind, interviewers, outcome, notes, outdir } => {
+            let interview = add_interview(
+                &open_store(cwd, outdir)?,
+                InterviewInput {
+                    job_id: &job_id,
+                    scheduled_at: &date,
+                    kind: &kind,
+                    interviewers: &interviewers,
+                    outcome: outcome.as_deref(),
+                    notes: notes.as_deref(),
+                },
+            )?;
+            print_interview(&interview);
+        }
+        InterviewsCommands::Update {
+            job_id,
+            id,
+            date,
+            kind,
+            interviewers,
+            outcome,
+            notes,
+            outdir,
+        } => {
+            let interview = update_interview(
+                &open_store(cwd, outdir)?,
+                id,
+                InterviewInput {
+                    job_id: &job_id,
+                    scheduled_at: &date,
+                    kind: &kind,
+                    interviewers: &interviewers,
+                    outcome: outcome.as_deref(),
+                    notes: notes.as_deref(),
+                },
+            )?;
+            print_interview(&interview);
+        }
+        InterviewsCommands::Remove { id, outdir } => {
+            if !delete_interview(&open_store(cwd, outdir)?, id)? {
+                anyhow::bail!("interview not found: {id}");
+            }
+            println!("Removed interview #{id}");
+        }
+    }
+    Ok(())
+}
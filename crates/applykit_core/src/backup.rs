@@ -0,0 +1,297 @@
+use crate::reindex::MissingPacket;
+use crate::storage::{latest_schema_version, Store};
+use anyhow::{bail, Context};
+use chrono::Utc;
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub path: PathBuf,
+    pub schema_version: i64,
+    pub jobs: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreInfo {
+    /// Schema version recorded in the backup; older versions are migrated on restore.
+    pub backup_version: i64,
+    pub schema_version: i64,
+    pub jobs: i64,
+    /// Copy of the database that was replaced, if there was one.
+    pub replaced_copy: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbCheckReport {
+    /// `PRAGMA integrity_check` output; `["ok"]` when the file is sound.
+    pub integrity: Vec<String>,
+    pub schema_version: i64,
+    pub latest_version: i64,
+    /// Rows per table whose `job_id` has no job.
+    pub orphaned: BTreeMap<String, i64>,
+    pub missing_packets: Vec<MissingPacket>,
+}
+
+impl DbCheckReport {
+    pub fn is_ok(&self) -> bool {
+        self.integrity == ["ok"]
+            && self.schema_version == self.latest_version
+            && self.orphaned.values().all(|count| *count == 0)
+            && self.missing_packets.is_empty()
+    }
+}
+
+/// Tables keyed by `job_id` that should never outlive their job.
+const JOB_CHILD_TABLES: &[&str] =
+    &["job_events", "job_status_history", "contacts", "interviews", "job_search"];
+
+fn copy_database(from: &Connection, to: &mut Connection) -> anyhow::Result<()> {
+    Backup::new(from, to)
+        .context("starting sqlite backup")?
+        .run_to_completion(256, Duration::from_millis(5), None)
+        .context("copying database pages")?;
+    Ok(())
+}
+
+/// Highest applied migration, or an error if the file is not an ApplyKit database.
+fn schema_version(conn: &Connection) -> anyhow::Result<i64> {
+    conn.query_row("SELECT MAX(version) FROM schema_migrations", [], |row| {
+        row.get::<_, Option<i64>>(0)
+    })
+    .ok()
+    .flatten()
+    .ok_or_else(|| anyhow::anyhow!("not an applykit database (no schema_migrations)"))
+}
+
+fn job_count(conn: &Connection) -> anyhow::Result<i64> {
+    Ok(conn.query_row("SELECT COUNT(*) FROM jobs", [], |row| row.get(0))?)
+}
+
+fn integrity_check(conn: &Connection) -> anyhow::Result<Vec<String>> {
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Default backup location: `<output base>/backups/applykit-<UTC stamp>.db`.
+pub fn default_backup_path(db_path: &Path) -> PathBuf {
+    let dir = db_path.parent().unwrap_or(Path::new(".")).join("backups");
+    dir.join(format!("applykit-{}.db", Utc::now().format("%Y%m%dT%H%M%SZ")))
+}
+
+/// Copies the live database with SQLite's online backup API, so it is consistent even while
+/// the app is writing. Refuses to overwrite an existing file.
+pub fn backup_database(db_path: &Path, dest: &Path) -> anyhow::Result<BackupInfo> {
+    if !db_path.exists() {
+        bail!("no database at {}", db_path.display());
+    }
+    if dest.exists() {
+        bail!("backup destination already exists: {}", dest.display());
+    }
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("creating {}", parent.display()))?;
+    }
+    let source =
+        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+    let mut target =
+        Connection::open(dest).with_context(|| format!("creating {}", dest.display()))?;
+    copy_database(&source, &mut target)?;
+    // The copy inherits WAL mode; a rollback journal keeps the backup a single file.
+    target.pragma_update(None, "journal_mode", "DELETE").context("finalizing backup")?;
+    Ok(BackupInfo {
+        path: dest.to_path_buf(),
+        schema_version: schema_version(&target)?,
+        jobs: job_count(&target)?,
+    })
+}
+
+/// Replaces the database with `backup` after checking the backup's integrity and that its
+/// schema is not newer than this build. Older schemas are migrated forward; the replaced
+/// database is first copied next to it as `applykit.db.pre-restore-<stamp>`.
+pub fn restore_database(db_path: &Path, backup: &Path) -> anyhow::Result<RestoreInfo> {
+    let source = Connection::open_with_flags(backup, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("opening {}", backup.display()))?;
+    let integrity =
+        integrity_check(&source).with_context(|| format!("checking {}", backup.display()))?;
+    if integrity != ["ok"] {
+        bail!("backup failed integrity check: {}", integrity.join("; "));
+    }
+    let backup_version = schema_version(&source)?;
+    let latest = latest_schema_version();
+    if backup_version > latest {
+        bail!(
+            "backup schema version {backup_version} is newer than this build supports ({latest})"
+        );
+    }
+
+    let replaced_copy = if db_path.exists() {
+        let copy = db_path.with_file_name(format!(
+            "{}.pre-restore-{}",
+            db_path.file_name().unwrap_or_default().to_string_lossy(),
+            Utc::now().format("%Y%m%dT%H%M%SZ")
+        ));
+        backup_database(db_path, &copy)?;
+        Some(copy)
+    } else {
+        None
+    };
+
+    {
+        let mut target =
+            Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+        copy_database(&source, &mut target)?;
+    }
+    let restored = Store::open(db_path)?;
+    let conn = restored.conn();
+    Ok(RestoreInfo {
+        backup_version,
+        schema_version: schema_version(&conn)?,
+        jobs: job_count(&conn)?,
+        replaced_copy,
+    })
+}
+
+/// Runs SQLite's integrity check plus tracker checks: schema version, rows orphaned from
+/// their job, and jobs whose packet folder is gone. Nothing is repaired.
+pub fn check_database(db_path: &Path) -> anyhow::Result<DbCheckReport> {
+    if !db_path.exists() {
+        bail!("no database at {}", db_path.display());
+    }
+    // Opened read-write (though only read) so closing checkpoints WAL and removes its files.
+    let conn =
+        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+    let integrity = integrity_check(&conn)?;
+    let schema_version = schema_version(&conn)?;
+
+    let mut orphaned = BTreeMap::new();
+    for table in JOB_CHILD_TABLES {
+        let exists =
+            conn.query_row("SELECT COUNT(*) FROM sqlite_master WHERE name = ?1", [table], |row| {
+                row.get::<_, i64>(0)
+            })? > 0;
+        if !exists {
+            continue;
+        }
+        let count = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {table} WHERE job_id NOT IN (SELECT id FROM jobs)"),
+            [],
+            |row| row.get::<_, i64>(0),
+        )?;
+        orphaned.insert(table.to_string(), count);
+    }
+
+    let mut stmt = conn.prepare("SELECT id, output_dir FROM jobs WHERE output_dir IS NOT NULL")?;
+    let missing_packets = stmt
+        .query_map([], |row| Ok(MissingPacket { job_id: row.get(0)?, output_dir: row.get(1)? }))?
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|m| !Path::new(&m.output_dir).is_dir())
+        .collect();
+
+    Ok(DbCheckReport {
+        integrity,
+        schema_version,
+        latest_version: latest_schema_version(),
+        orphaned,
+        missing_packets,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{get_job_by_id, upsert_job_record, UpsertJobRecordInput};
+
+    fn seed(db_path: &Path, id: &str, output_dir: Option<&str>) {
+        upsert_job_record(
+            &Store::open(db_path).expect("store"),
+            UpsertJobRecordInput {
+                id,
+                company: "Acme",
+                role: "Role",
+                source: "manual",
+                baseline: "1pg",
+                jd_text: "jd",
+                jd_hash: id,
+                track: None,
+                fit_total: None,
+                output_dir,
+            },
+        )
+        .expect("upsert");
+    }
+
+    #[test]
+    fn backup_and_restore_round_trip_with_version_checks() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        seed(&db_path, "job-1", None);
+
+        let dest = dir.path().join("backups/one.db");
+        let info = backup_database(&db_path, &dest).expect("backup");
+        assert_eq!((info.jobs, info.schema_version), (1, latest_schema_version()));
+        assert!(backup_database(&db_path, &dest).is_err(), "never overwrite a backup");
+
+        seed(&db_path, "job-2", None);
+        let restored = restore_database(&db_path, &dest).expect("restore");
+        assert_eq!(restored.jobs, 1);
+        assert!(get_job_by_id(&Store::open(&db_path).expect("store"), "job-2")
+            .expect("get")
+            .is_none());
+        let replaced = restored.replaced_copy.expect("safety copy");
+        let conn = Connection::open(&replaced).expect("open copy");
+        assert_eq!(job_count(&conn).expect("count"), 2);
+
+        let future = dir.path().join("future.db");
+        backup_database(&db_path, &future).expect("backup");
+        Connection::open(&future)
+            .expect("open")
+            .execute(
+                "INSERT INTO schema_migrations (version, applied_at) VALUES (?1, 'later')",
+                [latest_schema_version() + 1],
+            )
+            .expect("bump");
+        let err = restore_database(&db_path, &future).expect_err("newer schema");
+        assert!(err.to_string().contains("newer than this build"));
+
+        let not_applykit = dir.path().join("other.db");
+        Connection::open(&not_applykit)
+            .expect("open")
+            .execute_batch("CREATE TABLE t (x);")
+            .expect("t");
+        assert!(restore_database(&db_path, &not_applykit).is_err());
+    }
+
+    #[test]
+    fn check_reports_orphans_and_missing_packet_dirs() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let packet = dir.path().join("Acme_Role_2026-02-14");
+        std::fs::create_dir(&packet).expect("packet");
+        seed(&db_path, "job-1", Some(packet.to_string_lossy().as_ref()));
+        assert!(check_database(&db_path).expect("check").is_ok());
+
+        std::fs::remove_dir(&packet).expect("remove packet");
+        let conn = Connection::open(&db_path).expect("open");
+        conn.execute(
+            "INSERT INTO job_events (id, job_id, event_type, payload_json, created_at)
+             VALUES ('ghost#1', 'ghost', 'generated', '{}', 'now')",
+            [],
+        )
+        .expect("orphan");
+        drop(conn);
+
+        let report = check_database(&db_path).expect("check");
+        assert_eq!(report.integrity, vec!["ok"]);
+        assert_eq!(report.orphaned["job_events"], 1);
+        assert_eq!(report.orphaned["contacts"], 0);
+        assert_eq!(report.missing_packets.len(), 1);
+        assert!(!report.is_ok());
+    }
+}
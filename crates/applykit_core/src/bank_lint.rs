@@ -0,0 +1,577 @@
+use crate::types::{ClaimLevel, SkillLevel};
+use anyhow::Context;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+pub const BULLET_BANK_FILE: &str = "bullet_bank.json";
+pub const SKILLS_BANK_FILE: &str = "skills_bank.json";
+
+/// Bullets longer than this are flagged; they rarely fit on one resume line pair.
+pub const MAX_BULLET_CHARS: usize = 220;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    String,
+    NullableString,
+    StringList,
+    Bool,
+}
+
+impl FieldKind {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::NullableString => value.is_string() || value.is_null(),
+            Self::StringList => {
+                value.as_array().is_some_and(|items| items.iter().all(Value::is_string))
+            }
+            Self::Bool => value.is_boolean(),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::NullableString => "a string or null",
+            Self::StringList => "a list of strings",
+            Self::Bool => "true or false",
+        }
+    }
+}
+
+/// Field name, kind and whether it is required; mirrors `data/schema/*.schema.json`.
+type FieldSpec = (&'static str, FieldKind, bool);
+
+const BANK_FIELDS: &[FieldSpec] =
+    &[("version", FieldKind::String, true), ("generated_at", FieldKind::NullableString, false)];
+
+const BULLET_FIELDS: &[FieldSpec] = &[
+    ("id", FieldKind::String, true),
+    ("scope", FieldKind::String, true),
+    ("category", FieldKind::StringList, true),
+    ("tags", FieldKind::StringList, true),
+    ("tools", FieldKind::StringList, true),
+    ("seniority", FieldKind::String, true),
+    ("approved", FieldKind::Bool, true),
+    ("claim_level", FieldKind::String, true),
+    ("text", FieldKind::String, true),
+    ("notes", FieldKind::String, false),
+];
+
+const SKILL_FIELDS: &[FieldSpec] =
+    &[("level", FieldKind::String, true), ("approved", FieldKind::Bool, true)];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LintSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    pub file: String,
+    /// Bullet id or skill name, when the issue is about one entry.
+    pub id: Option<String>,
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintReport {
+    pub bullets: usize,
+    pub skills: usize,
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    pub fn errors(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == LintSeverity::Error).count()
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == LintSeverity::Warning).count()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.errors() == 0
+    }
+
+    fn push(
+        &mut self,
+        severity: LintSeverity,
+        file: &str,
+        id: Option<&str>,
+        code: &str,
+        message: String,
+    ) {
+        self.issues.push(LintIssue {
+            severity,
+            file: file.to_string(),
+            id: id.map(str::to_string),
+            code: code.to_string(),
+            message,
+        });
+    }
+
+    fn error(&mut self, file: &str, id: Option<&str>, code: &str, message: String) {
+        self.push(LintSeverity::Error, file, id, code, message);
+    }
+
+    fn warn(&mut self, file: &str, id: Option<&str>, code: &str, message: String) {
+        self.push(LintSeverity::Warning, file, id, code, message);
+    }
+
+    /// Checks `object` against `fields`; returns false when a required field is missing or
+    /// any field has the wrong type, so callers can skip checks that read those fields.
+    fn check_fields(
+        &mut self,
+        file: &str,
+        id: Option<&str>,
+        what: &str,
+        object: &Map<String, Value>,
+        fields: &[FieldSpec],
+    ) -> bool {
+        let mut ok = true;
+        for key in object.keys() {
+            if !fields.iter().any(|(name, _, _)| name == key) {
+                self.error(file, id, "unknown_field", format!("{what} has unknown field `{key}`"));
+            }
+        }
+        for (name, kind, required) in fields {
+            match object.get(*name) {
+                None if *required => {
+                    ok = false;
+                    self.error(file, id, "missing_field", format!("{what} is missing `{name}`"));
+                }
+                Some(value) if !kind.matches(value) => {
+                    ok = false;
+                    self.error(
+                        file,
+                        id,
+                        "wrong_type",
+                        format!("{what} `{name}` must be {}", kind.describe()),
+                    );
+                }
+                _ => {}
+            }
+        }
+        ok
+    }
+}
+
+/// Parses a bank file, checks its top-level fields and returns the `list_key` entry.
+fn parse_bank(report: &mut LintReport, file: &str, raw: &str, list_key: &str) -> Option<Value> {
+    let value = match serde_json::from_str::<Value>(raw) {
+        Ok(value) => value,
+        Err(err) => {
+            report.error(file, None, "invalid_json", format!("not valid JSON: {err}"));
+            return None;
+        }
+    };
+    let Value::Object(mut object) = value else {
+        report.error(file, None, "wrong_type", "top level must be an object".to_string());
+        return None;
+    };
+    let entries = object.remove(list_key);
+    report.check_fields(file, None, "bank", &object, BANK_FIELDS);
+    if entries.is_none() {
+        report.error(file, None, "missing_field", format!("bank is missing `{list_key}`"));
+    }
+    entries
+}
+
+/// Skill names by lowercase name, with whether each is approved.
+fn lint_skills(report: &mut LintReport, raw: &str) -> Option<BTreeMap<String, bool>> {
+    let file = SKILLS_BANK_FILE;
+    let Value::Object(skills) = parse_bank(report, file, raw, "skills")? else {
+        report.error(file, None, "wrong_type", "`skills` must be an object".to_string());
+        return None;
+    };
+    report.skills = skills.len();
+    if skills.is_empty() {
+        report.error(file, None, "empty_bank", "skills bank is empty".to_string());
+    }
+
+    let mut known = BTreeMap::new();
+    for (name, entry) in &skills {
+        let id = Some(name.as_str());
+        let what = format!("skill {name:?}");
+        if name.trim().is_empty() {
+            report.error(file, id, "empty_field", "skill name is empty".to_string());
+            continue;
+        }
+        if name.trim() != name {
+            report.warn(file, id, "untrimmed", format!("{what} has leading or trailing spaces"));
+        }
+        let Value::Object(entry) = entry else {
+            report.error(file, id, "wrong_type", format!("{what} must be an object"));
+            continue;
+        };
+        if !report.check_fields(file, id, &what, entry, SKILL_FIELDS) {
+            continue;
+        }
+        let level = entry["level"].as_str().unwrap_or_default();
+        match SkillLevel::parse_input(level) {
+            Err(_) => report.error(
+                file,
+                id,
+                "bad_level",
+                format!("{what} level {level:?} must be admin, operator or familiar"),
+            ),
+            Ok(_) if level.trim().eq_ignore_ascii_case("strong") => report.warn(
+                file,
+                id,
+                "legacy_level",
+                format!("{what} level \"strong\" is read as admin; write admin"),
+            ),
+            Ok(_) => {}
+        }
+        let key = name.trim().to_ascii_lowercase();
+        let approved = entry["approved"].as_bool().unwrap_or(false);
+        if known.insert(key, approved).is_some() {
+            report.error(
+                file,
+                id,
+                "duplicate_skill",
+                format!("{what} differs from another skill only by case or spaces"),
+            );
+        }
+    }
+    Some(known)
+}
+
+fn duplicates_ignoring_case(values: &[&str]) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut out = BTreeSet::new();
+    for value in values {
+        let key = value.trim().to_ascii_lowercase();
+        if !seen.insert(key.clone()) {
+            out.insert(key);
+        }
+    }
+    out.into_iter().collect()
+}
+
+fn lint_bullets(report: &mut LintReport, raw: &str, skills: Option<&BTreeMap<String, bool>>) {
+    let file = BULLET_BANK_FILE;
+    let Some(bullets) = parse_bank(report, file, raw, "bullets") else {
+        return;
+    };
+    let Value::Array(bullets) = bullets else {
+        report.error(file, None, "wrong_type", "`bullets` must be a list".to_string());
+        return;
+    };
+    report.bullets = bullets.len();
+
+    let id_pattern = Regex::new(r"^[a-z0-9_]+$").expect("regex");
+    let mut ids = BTreeSet::new();
+    let mut texts = BTreeMap::<String, String>::new();
+
+    for (index, bullet) in bullets.iter().enumerate() {
+        let Value::Object(bullet) = bullet else {
+            report.error(
+                file,
+                None,
+                "wrong_type",
+                format!("bullet #{} must be an object", index + 1),
+            );
+            continue;
+        };
+        let raw_id = bullet.get("id").and_then(Value::as_str);
+        let label = raw_id.map(str::to_string).unwrap_or_else(|| format!("#{}", index + 1));
+        let what = format!("bullet {label}");
+        let id = Some(label.as_str());
+        if !report.check_fields(file, id, &what, bullet, BULLET_FIELDS) {
+            continue;
+        }
+
+        let str_field = |name: &str| bullet[name].as_str().unwrap_or_default();
+        let list_field = |name: &str| {
+            bullet[name]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        };
+
+        if !id_pattern.is_match(&label) {
+            report.error(file, id, "bad_id", format!("{what} id must match ^[a-z0-9_]+$"));
+        }
+        if !ids.insert(label.clone()) {
+            report.error(file, id, "duplicate_id", format!("{what} id is used more than once"));
+        }
+        for name in ["scope", "seniority", "text"] {
+            if str_field(name).trim().is_empty() {
+                report.error(file, id, "empty_field", format!("{what} `{name}` is empty"));
+            }
+        }
+        let claim_level = str_field("claim_level");
+        if claim_level.parse::<ClaimLevel>().is_err() {
+            report.error(
+                file,
+                id,
+                "bad_claim_level",
+                format!(
+                    "{what} claim_level {claim_level:?} must be owned, led, partnered or supported"
+                ),
+            );
+        }
+
+        for name in ["category", "tags", "tools"] {
+            let values = list_field(name);
+            if values.iter().any(|v| v.trim().is_empty()) {
+                report.error(file, id, "empty_field", format!("{what} has an empty {name} entry"));
+            }
+            if name != "tools" && values.is_empty() {
+                report.warn(
+                    file,
+                    id,
+                    &format!("empty_{name}"),
+                    format!("{what} has no {name}, so it can only match on tools"),
+                );
+            }
+            for dup in duplicates_ignoring_case(&values) {
+                report.warn(
+                    file,
+                    id,
+                    "duplicate_entry",
+                    format!("{what} lists {name} {dup:?} twice"),
+                );
+            }
+        }
+
+        let approved = bullet["approved"].as_bool().unwrap_or(false);
+        if let Some(skills) = skills {
+            for tool in list_field("tools") {
+                match skills.get(&tool.trim().to_ascii_lowercase()) {
+                    None => {
+                        let message = format!("{what} tool {tool:?} is not in {SKILLS_BANK_FILE}");
+                        if approved {
+                            report.error(file, id, "unknown_tool", message);
+                        } else {
+                            report.warn(file, id, "unknown_tool", message);
+                        }
+                    }
+                    Some(false) if approved => report.warn(
+                        file,
+                        id,
+                        "unapproved_tool",
+                        format!("{what} is approved but its tool {tool:?} is not"),
+                    ),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        let text = str_field("text");
+        let chars = text.chars().count();
+        if chars > MAX_BULLET_CHARS {
+            report.warn(
+                file,
+                id,
+                "overlong_text",
+                format!("{what} text is {chars} characters (limit {MAX_BULLET_CHARS})"),
+            );
+        }
+        if text.matches("**").count() % 2 == 1 {
+            report.error(file, id, "unbalanced_bold", format!("{what} text has an unclosed `**`"));
+        }
+        let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_lowercase();
+        if !normalized.is_empty() {
+            if let Some(first) = texts.get(&normalized) {
+                report.warn(
+                    file,
+                    id,
+                    "duplicate_text",
+                    format!("{what} has the same text as bullet {first}"),
+                );
+            } else {
+                texts.insert(normalized, label.clone());
+            }
+        }
+    }
+}
+
+/// Lints the raw contents of `bullet_bank.json` and `skills_bank.json`: the structure in
+/// `data/schema/`, plus content rules `load_banks` does not enforce. Errors make the banks
+/// unfit to ship; warnings are worth a look.
+pub fn lint_bank_json(bullet_raw: &str, skills_raw: &str) -> LintReport {
+    let mut report = LintReport::default();
+    let skills = lint_skills(&mut report, skills_raw);
+    lint_bullets(&mut report, bullet_raw, skills.as_ref());
+    report.issues.sort_by(|a, b| {
+        a.severity
+            .cmp(&b.severity)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.id.cmp(&b.id))
+            .then_with(|| a.code.cmp(&b.code))
+    });
+    report
+}
+
+/// Lints the bank files under `repo_root/data`.
+pub fn lint_banks(repo_root: &Path) -> anyhow::Result<LintReport> {
+    let read = |name: &str| {
+        let path = repo_root.join("data").join(name);
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+    };
+    Ok(lint_bank_json(&read(BULLET_BANK_FILE)?, &read(SKILLS_BANK_FILE)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(report: &LintReport, severity: LintSeverity) -> Vec<(String, String)> {
+        report
+            .issues
+            .iter()
+            .filter(|i| i.severity == severity)
+            .map(|i| (i.id.clone().unwrap_or_default(), i.code.clone()))
+            .collect()
+    }
+
+    fn bullet(id: &str, tools: &[&str], approved: bool, text: &str) -> Value {
+        json!({
+            "id": id,
+            "scope": "Acme",
+            "category": ["ops"],
+            "tags": ["scale"],
+            "tools": tools,
+            "seniority": "senior",
+            "approved": approved,
+            "claim_level": "owned",
+            "text": text
+        })
+    }
+
+    #[test]
+    fn lint_reports_errors_and_warnings_with_bullet_ids() {
+        let skills = json!({
+            "version": "1.0.0",
+            "skills": {
+                "Okta": { "level": "operator", "approved": true },
+                "Jamf": { "level": "strong", "approved": false },
+                "okta ": { "level": "guru", "approved": true }
+            }
+        });
+        let mut no_tags = bullet("no_tags", &["Okta"], true, "Rolled out SSO.");
+        no_tags["tags"] = json!([]);
+        let mut extra = bullet("Bad-Id", &[], true, "Some text");
+        extra["owner"] = json!("me");
+        let bullets = json!({
+            "version": "1.0.0",
+            "bullets": [
+                bullet("good", &["okta"], true, "Ran **identity** for 3,000 users."),
+                bullet("bold", &["Jamf"], true, "Ran **identity for 3,000 users."),
+                bullet("dupe", &["Terraform"], false, "ran  **identity** for 3,000 users."),
+                bullet("long", &["Terraform"], true, &"x".repeat(MAX_BULLET_CHARS + 1)),
+                no_tags,
+                extra,
+                json!({ "id": "partial", "text": 5 })
+            ]
+        });
+
+        let report = lint_bank_json(&bullets.to_string(), &skills.to_string());
+        assert_eq!((report.bullets, report.skills), (7, 3));
+        let mut errors = codes(&report, LintSeverity::Error);
+        errors.sort();
+        assert_eq!(
+            errors,
+            [
+                ("Bad-Id", "bad_id"),
+                ("Bad-Id", "unknown_field"),
+                ("bold", "unbalanced_bold"),
+                ("long", "unknown_tool"),
+                ("okta ", "bad_level"),
+                ("okta ", "duplicate_skill"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "missing_field"),
+                ("partial", "wrong_type"),
+            ]
+            .map(|(id, code)| (id.to_string(), code.to_string()))
+        );
+        let mut warnings = codes(&report, LintSeverity::Warning);
+        warnings.sort();
+        assert_eq!(
+            warnings,
+            [
+                ("Jamf", "legacy_level"),
+                ("bold", "unapproved_tool"),
+                ("dupe", "duplicate_text"),
+                ("dupe", "unknown_tool"),
+                ("long", "overlong_text"),
+                ("no_tags", "empty_tags"),
+                ("okta ", "untrimmed"),
+            ]
+            .map(|(id, code)| (id.to_string(), code.to_string()))
+        );
+        assert!(!report.is_ok());
+    }
+
+    #[test]
+    fn lint_rejects_broken_json_without_checking_tools() {
+        let report = lint_bank_json(r#"{"version": "1", "bullets": []}"#, "{not json");
+        assert_eq!(
+            codes(&report, LintSeverity::Error),
+            vec![(String::new(), "invalid_json".to_string())]
+        );
+        assert_eq!(report.issues[0].file, SKILLS_BANK_FILE);
+    }
+
+    #[test]
+    fn schema_files_match_the_linted_fields() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../data/schema");
+        let load = |name: &str| {
+            let raw = std::fs::read_to_string(root.join(name)).expect("schema");
+            serde_json::from_str::<Value>(&raw).expect("schema json")
+        };
+        let names = |value: &Value| {
+            value.as_object().expect("object").keys().cloned().collect::<BTreeSet<_>>()
+        };
+        let required = |value: &Value| {
+            value
+                .as_array()
+                .expect("array")
+                .iter()
+                .map(|v| v.as_str().expect("str").to_string())
+                .collect::<BTreeSet<_>>()
+        };
+        let spec_names = |fields: &[FieldSpec], only_required: bool| {
+            fields
+                .iter()
+                .filter(|(_, _, req)| *req || !only_required)
+                .map(|(name, _, _)| name.to_string())
+                .collect::<BTreeSet<_>>()
+        };
+
+        let bullet_schema = load("bullet_bank.schema.json");
+        let bullet = &bullet_schema["$defs"]["bullet"];
+        assert_eq!(names(&bullet["properties"]), spec_names(BULLET_FIELDS, false));
+        assert_eq!(required(&bullet["required"]), spec_names(BULLET_FIELDS, true));
+
+        let skills_schema = load("skills_bank.schema.json");
+        let skill = &skills_schema["$defs"]["skill"];
+        assert_eq!(names(&skill["properties"]), spec_names(SKILL_FIELDS, false));
+        assert_eq!(required(&skill["required"]), spec_names(SKILL_FIELDS, true));
+
+        for schema in [&bullet_schema, &skills_schema] {
+            let mut top = spec_names(BANK_FIELDS, false);
+            let list = if schema == &bullet_schema { "bullets" } else { "skills" };
+            top.insert(list.to_string());
+            assert_eq!(names(&schema["properties"]), top);
+        }
+    }
+}
@@ -40,12 +40,60 @@ pub struct SkillRecord {
     pub approved: bool,
 }
 
+/// How a bullet's tools line up with `skills_bank.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolCheck {
+    /// Tools with no skill entry.
+    pub unknown: Vec<String>,
+    /// Tools whose skill entry is not approved, including any created below.
+    pub unapproved: Vec<String>,
+    /// Pending (unapproved, familiar) skills created for unknown tools.
+    pub created: Vec<String>,
+}
+
+impl ToolCheck {
+    pub fn warnings(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        if !self.unknown.is_empty() {
+            out.push(format!("tools not in skills bank: {}", self.unknown.join(", ")));
+        }
+        if !self.created.is_empty() {
+            out.push(format!("created pending skills: {}", self.created.join(", ")));
+        }
+        if !self.unapproved.is_empty() {
+            out.push(format!(
+                "generation skips this bullet until these skills are approved: {}",
+                self.unapproved.join(", ")
+            ));
+        }
+        out
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Banks {
     pub bullets: Vec<Bullet>,
     pub skills: BTreeMap<String, Skill>,
 }
 
+impl Banks {
+    /// Tools of `bullet` without an approved skill. The truth gate rejects drafts naming
+    /// them, so generation skips such bullets.
+    pub fn unapproved_tools(&self, bullet: &Bullet) -> Vec<String> {
+        bullet
+            .tools
+            .iter()
+            .filter(|tool| {
+                !self
+                    .skills
+                    .iter()
+                    .any(|(name, skill)| skill.approved && name.eq_ignore_ascii_case(tool.trim()))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Bullet {
     pub id: String,
@@ -158,13 +206,80 @@ pub fn save_skills_bank_file(repo_root: &Path, mut file: SkillsBankFile) -> anyh
     atomic_write_text(&skills_bank_path(repo_root), &(raw + "\n"))
 }
 
-pub fn set_bullet_approved(repo_root: &Path, id: &str, approved: bool) -> anyhow::Result<()> {
+/// Looks up each tool in the skills bank, ignoring case.
+pub fn check_bullet_tools(skills: &SkillsBankFile, tools: &[String]) -> ToolCheck {
+    let mut check = ToolCheck::default();
+    for tool in tools {
+        let found = skills.skills.iter().find(|(name, _)| name.eq_ignore_ascii_case(tool.trim()));
+        match found {
+            None => check.unknown.push(tool.clone()),
+            Some((_, skill)) if !skill.approved => check.unapproved.push(tool.clone()),
+            Some(_) => {}
+        }
+    }
+    check
+}
+
+/// Checks a bullet's tools before it is saved. Unknown tools fail an approved bullet unless
+/// `create_pending_skills` is set, in which case they are added to the skills bank as
+/// unapproved familiar skills for review. Draft bullets only get warnings.
+fn enforce_bullet_tools(
+    repo_root: &Path,
+    id: &str,
+    tools: &[String],
+    approved: bool,
+    create_pending_skills: bool,
+) -> anyhow::Result<ToolCheck> {
+    let mut skills = load_skills_bank_file(repo_root)?;
+    let mut check = check_bullet_tools(&skills, tools);
+    if check.unknown.is_empty() {
+        return Ok(check);
+    }
+    if create_pending_skills {
+        for tool in &check.unknown {
+            let name = tool.trim().to_string();
+            if skills.skills.keys().any(|existing| existing.eq_ignore_ascii_case(&name)) {
+                continue;
+            }
+            skills.skills.insert(
+                name.clone(),
+                SkillRecord {
+                    level: canonical_skill_level(SkillLevel::Familiar).to_string(),
+                    approved: false,
+                },
+            );
+            check.created.push(name);
+        }
+        save_skills_bank_file(repo_root, skills)?;
+        check.unapproved.append(&mut check.unknown);
+        check.unapproved.sort();
+    } else if approved {
+        bail!(
+            "bullet {id} uses tools not in skills bank: {} (add the skills or create them as pending)",
+            check.unknown.join(", ")
+        );
+    }
+    Ok(check)
+}
+
+pub fn set_bullet_approved(
+    repo_root: &Path,
+    id: &str,
+    approved: bool,
+    create_pending_skills: bool,
+) -> anyhow::Result<ToolCheck> {
     let mut file = load_bullet_bank_file(repo_root)?;
     let Some(row) = file.bullets.iter_mut().find(|row| row.id == id) else {
         bail!("bullet not found: {id}");
     };
     row.approved = approved;
-    save_bullet_bank_file(repo_root, file)
+    let check = if approved {
+        enforce_bullet_tools(repo_root, id, &row.tools, true, create_pending_skills)?
+    } else {
+        ToolCheck::default()
+    };
+    save_bullet_bank_file(repo_root, file)?;
+    Ok(check)
 }
 
 pub fn save_bullet_text(repo_root: &Path, id: &str, text: &str) -> anyhow::Result<()> {
@@ -180,7 +295,11 @@ pub fn save_bullet_text(repo_root: &Path, id: &str, text: &str) -> anyhow::Resul
     save_bullet_bank_file(repo_root, file)
 }
 
-pub fn add_bullet(repo_root: &Path, mut bullet: BulletRecord) -> anyhow::Result<()> {
+pub fn add_bullet(
+    repo_root: &Path,
+    mut bullet: BulletRecord,
+    create_pending_skills: bool,
+) -> anyhow::Result<ToolCheck> {
     let mut file = load_bullet_bank_file(repo_root)?;
     bullet.id = normalize_bullet_id(&bullet.id)?;
     if file.bullets.iter().any(|row| row.id == bullet.id) {
@@ -196,17 +315,40 @@ pub fn add_bullet(repo_root: &Path, mut bullet: BulletRecord) -> anyhow::Result<
     bullet.category = normalize_list(bullet.category, true);
     bullet.tags = normalize_list(bullet.tags, true);
     bullet.tools = normalize_list(bullet.tools, false);
+    let check = enforce_bullet_tools(
+        repo_root,
+        &bullet.id,
+        &bullet.tools,
+        bullet.approved,
+        create_pending_skills,
+    )?;
     file.bullets.push(bullet);
-    save_bullet_bank_file(repo_root, file)
+    save_bullet_bank_file(repo_root, file)?;
+    Ok(check)
 }
 
-pub fn set_skill_approved(repo_root: &Path, name: &str, approved: bool) -> anyhow::Result<()> {
+/// Sets a skill's approval. When unapproving, returns the approved bullets that name the
+/// skill as a tool; generation skips them until it is approved again.
+pub fn set_skill_approved(
+    repo_root: &Path,
+    name: &str,
+    approved: bool,
+) -> anyhow::Result<Vec<String>> {
     let mut file = load_skills_bank_file(repo_root)?;
     let Some(row) = file.skills.get_mut(name) else {
         bail!("skill not found: {name}");
     };
     row.approved = approved;
-    save_skills_bank_file(repo_root, file)
+    save_skills_bank_file(repo_root, file)?;
+    if approved {
+        return Ok(Vec::new());
+    }
+    Ok(load_bullet_bank_file(repo_root)?
+        .bullets
+        .into_iter()
+        .filter(|row| row.approved && row.tools.iter().any(|t| t.trim().eq_ignore_ascii_case(name)))
+        .map(|row| row.id)
+        .collect())
 }
 
 pub fn set_skill_level(repo_root: &Path, name: &str, level: &str) -> anyhow::Result<()> {
@@ -1,8 +1,11 @@
+use crate::workflow::StatusWorkflow;
 use anyhow::Context;
+use applykit_llm::{CancelToken, LlmClientOptions};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplykitConfig {
@@ -11,6 +14,8 @@ pub struct ApplykitConfig {
     pub scoring: ScoringConfig,
     pub tracks: TrackTermsConfig,
     pub llm: LlmConfig,
+    #[serde(default)]
+    pub tracker: StatusWorkflow,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +57,75 @@ pub struct LlmConfig {
     pub base_url: String,
     pub model: String,
     pub allowed_tasks: Vec<String>,
+    #[serde(default = "default_llm_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    #[serde(default = "default_llm_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    #[serde(default = "default_llm_max_retries")]
+    pub max_retries: u32,
+    #[serde(default = "default_llm_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+    #[serde(default = "default_llm_stream")]
+    pub stream: bool,
+    #[serde(default = "default_llm_n_predict")]
+    pub n_predict: i32,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub stop: Vec<String>,
+    #[serde(default = "default_llm_cache")]
+    pub cache: bool,
+    /// Fixtures read by `provider = "replay"`; relative paths resolve against the repo root.
+    #[serde(default = "default_llm_replay_dir")]
+    pub replay_dir: String,
+    /// When set, every real provider response is saved here as a replay fixture.
+    #[serde(default)]
+    pub record_dir: Option<String>,
+}
+
+fn default_llm_connect_timeout_secs() -> u64 {
+    5
+}
+
+fn default_llm_request_timeout_secs() -> u64 {
+    120
+}
+
+fn default_llm_max_retries() -> u32 {
+    2
+}
+
+fn default_llm_retry_backoff_ms() -> u64 {
+    500
+}
+
+fn default_llm_stream() -> bool {
+    true
+}
+
+fn default_llm_n_predict() -> i32 {
+    1024
+}
+
+fn default_llm_cache() -> bool {
+    true
+}
+
+fn default_llm_replay_dir() -> String {
+    "fixtures/llm".to_string()
+}
+
+impl LlmConfig {
+    pub fn client_options(&self, cancel: Option<CancelToken>) -> LlmClientOptions {
+        LlmClientOptions {
+            connect_timeout: Duration::from_secs(self.connect_timeout_secs),
+            request_timeout: Duration::from_secs(self.request_timeout_secs),
+            max_retries: self.max_retries,
+            retry_backoff: Duration::from_millis(self.retry_backoff_ms),
+            stream: self.stream,
+            cancel,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -69,6 +143,7 @@ pub fn load_config(repo_root: &Path) -> anyhow::Result<ApplykitConfig> {
     let raw =
         std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
     let config: ApplykitConfig = toml::from_str(&raw).context("parsing applykit.toml")?;
+    config.tracker.validate()?;
     Ok(config)
 }
 
@@ -0,0 +1,297 @@
+use crate::banks::Banks;
+use crate::insights::normalize_gap;
+use crate::pipeline::list_packets_from_fs;
+use crate::reindex::SkippedPacket;
+use crate::resume::rank_all_bullets;
+use crate::types::{ExtractedJd, FitScore, TailorPlan, TrackSelection};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// The parts of a packet's `ReviewData.json` that say what it asked for and what it used.
+#[derive(Debug, Deserialize)]
+struct PacketUsage {
+    extracted: ExtractedJd,
+    track: TrackSelection,
+    fit: FitScore,
+    tailor_plan: TailorPlan,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulletUsage {
+    pub id: String,
+    pub approved: bool,
+    /// Packets whose tailor plan used the bullet.
+    pub selected: usize,
+    /// Packets where it would rank among the swapped-in bullets if it were approved.
+    /// Always zero for approved bullets.
+    pub blocked: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SkillUsage {
+    pub name: String,
+    pub approved: bool,
+    /// Packets whose JD named the skill as a tool.
+    pub matched: usize,
+}
+
+/// A skill JDs asked for that `skills_bank.json` does not list at all.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequestedSkill {
+    pub skill: String,
+    pub packets: usize,
+    /// One requirement line or tool name it came from.
+    pub example: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub packets: usize,
+    pub skipped: Vec<SkippedPacket>,
+    /// Every bank bullet, most selected first.
+    pub bullets: Vec<BulletUsage>,
+    /// Approved bullets no packet selected.
+    pub never_used: Vec<String>,
+    /// Unapproved bullets that would have been swapped in, most often first.
+    pub unapproved_would_select: Vec<BulletUsage>,
+    /// Every bank skill, most matched first.
+    pub skills: Vec<SkillUsage>,
+    /// Approved skills no JD named.
+    pub unmatched_skills: Vec<String>,
+    pub missing_skills: Vec<RequestedSkill>,
+    /// Bullet ids packets used that are no longer in the bank.
+    pub unknown_bullet_ids: Vec<String>,
+}
+
+fn read_usage(packet_dir: &Path) -> anyhow::Result<PacketUsage> {
+    let path = packet_dir.join("ReviewData.json");
+    let raw =
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// Scans every packet under `output_base` and reports which bank bullets and skills they
+/// used, which never come up, and what JDs keep asking for that the banks do not have.
+/// Selection is replayed against the current banks, so bullets edited since a packet was
+/// generated are judged by their current tags and tools.
+pub fn bank_coverage(banks: &Banks, output_base: &Path) -> anyhow::Result<CoverageReport> {
+    let in_bank = |name: &str| banks.skills.keys().any(|k| k.eq_ignore_ascii_case(name));
+    let mut selected = BTreeMap::<String, usize>::new();
+    let mut blocked = BTreeMap::<String, usize>::new();
+    let mut matched = BTreeMap::<String, usize>::new();
+    let mut requested = BTreeMap::<String, RequestedSkill>::new();
+    let mut report = CoverageReport::default();
+
+    for packet_dir in list_packets_from_fs(output_base)? {
+        let usage = match read_usage(&packet_dir) {
+            Ok(usage) => usage,
+            Err(err) => {
+                report.skipped.push(SkippedPacket { packet_dir, reason: format!("{err:#}") });
+                continue;
+            }
+        };
+        report.packets += 1;
+
+        let used = usage
+            .tailor_plan
+            .edits
+            .iter()
+            .flat_map(|edit| edit.provenance_ids.iter().cloned())
+            .collect::<BTreeSet<_>>();
+        for id in &used {
+            *selected.entry(id.clone()).or_insert(0) += 1;
+        }
+
+        let ranked = rank_all_bullets(banks, &usage.extracted, usage.track.selected);
+        for bullet in ranked.into_iter().take(usage.tailor_plan.max_bullet_swaps) {
+            if !bullet.approved && !used.contains(&bullet.id) {
+                *blocked.entry(bullet.id.clone()).or_insert(0) += 1;
+            }
+        }
+
+        for name in banks.skills.keys() {
+            if usage.extracted.tools.iter().any(|t| t.eq_ignore_ascii_case(name)) {
+                *matched.entry(name.clone()).or_insert(0) += 1;
+            }
+        }
+
+        let mut seen = BTreeSet::new();
+        let tools = usage
+            .extracted
+            .tools
+            .iter()
+            .filter(|t| !in_bank(t))
+            .map(|t| (t.trim().to_ascii_lowercase(), t.as_str()));
+        let gaps = usage
+            .fit
+            .gaps
+            .iter()
+            .flat_map(|g| normalize_gap(g).into_iter().map(move |skill| (skill, g.as_str())));
+        for (skill, example) in tools.chain(gaps) {
+            if in_bank(&skill) || !seen.insert(skill.clone()) {
+                continue;
+            }
+            requested
+                .entry(skill.clone())
+                .or_insert_with(|| RequestedSkill {
+                    skill,
+                    packets: 0,
+                    example: example.trim().to_string(),
+                })
+                .packets += 1;
+        }
+    }
+
+    let count = |map: &BTreeMap<String, usize>, id: &str| map.get(id).copied().unwrap_or(0);
+    report.bullets = banks
+        .bullets
+        .iter()
+        .map(|b| BulletUsage {
+            id: b.id.clone(),
+            approved: b.approved,
+            selected: count(&selected, &b.id),
+            blocked: count(&blocked, &b.id),
+        })
+        .collect();
+    report.bullets.sort_by(|a, b| b.selected.cmp(&a.selected).then_with(|| a.id.cmp(&b.id)));
+    report.never_used = report
+        .bullets
+        .iter()
+        .filter(|b| b.approved && b.selected == 0)
+        .map(|b| b.id.clone())
+        .collect();
+    report.unapproved_would_select =
+        report.bullets.iter().filter(|b| b.blocked > 0).cloned().collect();
+    report
+        .unapproved_would_select
+        .sort_by(|a, b| b.blocked.cmp(&a.blocked).then_with(|| a.id.cmp(&b.id)));
+
+    let bank_ids = banks.bullets.iter().map(|b| b.id.as_str()).collect::<BTreeSet<_>>();
+    report.unknown_bullet_ids =
+        selected.keys().filter(|id| !bank_ids.contains(id.as_str())).cloned().collect();
+
+    report.skills = banks
+        .skills
+        .iter()
+        .map(|(name, skill)| SkillUsage {
+            name: name.clone(),
+            approved: skill.approved,
+            matched: count(&matched, name),
+        })
+        .collect();
+    report.skills.sort_by(|a, b| b.matched.cmp(&a.matched).then_with(|| a.name.cmp(&b.name)));
+    report.unmatched_skills = report
+        .skills
+        .iter()
+        .filter(|s| s.approved && s.matched == 0)
+        .map(|s| s.name.clone())
+        .collect();
+
+    report.missing_skills = requested.into_values().collect();
+    report
+        .missing_skills
+        .sort_by(|a, b| b.packets.cmp(&a.packets).then_with(|| a.skill.cmp(&b.skill)));
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::banks::{Bullet, Skill};
+    use crate::types::{ClaimLevel, SkillLevel};
+    use serde_json::json;
+
+    fn bullet(id: &str, tools: &[&str], approved: bool) -> Bullet {
+        Bullet {
+            id: id.to_string(),
+            scope: "work".to_string(),
+            category: vec!["support".to_string()],
+            tags: Vec::new(),
+            tools: tools.iter().map(|s| s.to_string()).collect(),
+            seniority: "senior".to_string(),
+            approved,
+            claim_level: ClaimLevel::Owned,
+            text: format!("Bullet {id}"),
+        }
+    }
+
+    fn write_packet(base: &Path, name: &str, tools: &[&str], gaps: &[&str], used: &[&str]) {
+        let dir = base.join(name);
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        let review = json!({
+            "extracted": {
+                "normalized_text": "",
+                "keywords": [],
+                "tools": tools,
+                "requirements": gaps,
+                "scale_signals": [],
+                "rigor_signals": []
+            },
+            "track": { "selected": "support_ops_core", "scores": [] },
+            "fit": {
+                "role_match": 10, "stack_match": 10, "scale_match": 0, "rigor_match": 0,
+                "signal_boost": 0, "total": 20, "why_match": [], "gaps": gaps
+            },
+            "tailor_plan": {
+                "edits": [{
+                    "kind": "bullet_swap",
+                    "target_section": "BOX_BULLETS",
+                    "reason": "",
+                    "provenance_ids": used
+                }],
+                "max_resume_edits": 3,
+                "max_bullet_swaps": 2
+            }
+        });
+        std::fs::write(dir.join("ReviewData.json"), review.to_string()).expect("write");
+    }
+
+    #[test]
+    fn coverage_counts_selections_blocked_bullets_and_missing_skills() {
+        let skill = |approved| Skill { level: SkillLevel::Operator, approved };
+        let banks = Banks {
+            bullets: vec![
+                bullet("b-okta", &["Okta"], true),
+                bullet("b-jamf", &["Jamf"], false),
+                bullet("b-idle", &[], true),
+            ],
+            skills: BTreeMap::from([
+                ("Okta".to_string(), skill(true)),
+                ("Jamf".to_string(), skill(false)),
+                ("Splunk".to_string(), skill(true)),
+            ]),
+        };
+        let dir = tempfile::tempdir().expect("temp");
+        write_packet(
+            dir.path(),
+            "A_2026-01-01",
+            &["Okta", "Jamf", "Terraform"],
+            &["Experience with Kubernetes"],
+            &["b-okta"],
+        );
+        write_packet(dir.path(), "B_2026-01-02", &["Jamf"], &["Kubernetes or Nomad"], &["b-old"]);
+        std::fs::create_dir_all(dir.path().join("C_2026-01-03")).expect("mkdir");
+
+        let report = bank_coverage(&banks, dir.path()).expect("coverage");
+        assert_eq!(report.packets, 2);
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(
+            report.bullets[0],
+            BulletUsage { id: "b-okta".to_string(), approved: true, selected: 1, blocked: 0 }
+        );
+        assert_eq!(report.never_used, vec!["b-idle"]);
+        assert_eq!(report.unapproved_would_select.len(), 1);
+        assert_eq!(report.unapproved_would_select[0].id, "b-jamf");
+        assert_eq!(report.unapproved_would_select[0].blocked, 2);
+        assert_eq!(report.unknown_bullet_ids, vec!["b-old"]);
+
+        let jamf = report.skills.iter().find(|s| s.name == "Jamf").expect("jamf");
+        assert_eq!((jamf.matched, jamf.approved), (2, false));
+        assert_eq!(report.unmatched_skills, vec!["Splunk"]);
+        let missing =
+            report.missing_skills.iter().map(|m| (m.skill.as_str(), m.packets)).collect::<Vec<_>>();
+        assert_eq!(missing, vec![("kubernetes", 2), ("nomad", 1), ("terraform", 1)]);
+    }
+}
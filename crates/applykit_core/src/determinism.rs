@@ -14,3 +14,10 @@ pub fn sorted_unique(mut values: Vec<String>) -> Vec<String> {
     values.dedup();
     values
 }
+
+pub fn sha256_hex(input: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
@@ -1,53 +1,993 @@
-use crate::storage::JobRecord;
+use crate::banks::Banks;
+use crate::config::ScoringConfig;
+use crate::storage::{list_all_status_history, list_jobs, JobRecord, StatusEntry, Store};
+use crate::types::{ExtractedJd, FitScore};
+use crate::workflow::{FunnelStage, StatusWorkflow};
+use chrono::DateTime;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::LazyLock;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Terms seen in fewer applied jobs than this are left out of the correlations.
+const MIN_TERM_SUPPORT: usize = 2;
+const MAX_TERMS: usize = 20;
+const MAX_MISSING: usize = 25;
+
+/// Everything insights needs to know about one job.
+#[derive(Debug, Clone)]
+pub struct JobSignals<'a> {
+    pub job: &'a JobRecord,
+    /// Status history, oldest first. Empty falls back to the job's current status.
+    pub history: &'a [StatusEntry],
+    /// `Extracted.json` from the job's packet, if it is still on disk.
+    pub extracted: Option<ExtractedJd>,
+    /// Fit breakdown from the packet's `ReviewData.json`.
+    pub fit: Option<FitScore>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Funnel {
+    pub key: String,
+    pub applied: usize,
+    pub replied: usize,
+    pub interviewed: usize,
+    pub offered: usize,
+    /// `replied / applied`, `0.0` when nothing was applied to.
+    pub reply_rate: f64,
+    pub interview_rate: f64,
+    pub offer_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum TermKind {
+    Keyword,
+    Tool,
+}
+
+/// How often applications whose JD mentioned `term` got a reply or better.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TermOutcome {
+    pub term: String,
+    pub kind: TermKind,
+    /// Applied jobs whose JD mentioned the term.
+    pub jobs: usize,
+    pub positive: usize,
+    pub positive_rate: f64,
+    /// `positive_rate` over the rate for all applied jobs with an extraction; above 1.0 is
+    /// better than average.
+    pub lift: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Insights {
-    pub replies_by_track: Vec<(String, usize)>,
-    pub common_gaps: Vec<(String, usize)>,
-    pub keyword_correlations: Vec<(String, usize)>,
+    pub overall: Funnel,
+    pub by_track: Vec<Funnel>,
+    pub by_source: Vec<Funnel>,
+    pub by_baseline: Vec<Funnel>,
+    pub by_fit_bucket: Vec<Funnel>,
+    /// Median days from first entering `applied` to first reaching a reply or later stage.
+    pub median_days_to_reply: Option<f64>,
+    pub reply_samples: usize,
+    pub missing_skills: Vec<MissingSkill>,
+    pub keyword_correlations: Vec<TermOutcome>,
 }
 
-pub fn build_insights(jobs: &[JobRecord]) -> Insights {
-    let mut replies = BTreeMap::<String, usize>::new();
-    let mut gaps = BTreeMap::<String, usize>::new();
-    let mut correlations = BTreeMap::<String, usize>::new();
+/// Furthest stage reached, as an index into [`FunnelStage`] order, and, when known, days from
+/// applying to the first reply. Stages come from the workflow's `[tracker.stages]`; a job
+/// reaches every earlier stage too, so `new -> reply` still counts as applied.
+fn progress(signals: &JobSignals<'_>, workflow: &StatusWorkflow) -> (Option<usize>, Option<f64>) {
+    let current = [StatusEntry {
+        status: signals.job.status.clone(),
+        entered_at: String::new(),
+        closed_reason: None,
+    }];
+    let history = if signals.history.is_empty() { &current[..] } else { signals.history };
 
-    for job in jobs {
-        if job.status.eq_ignore_ascii_case("reply") || job.status.eq_ignore_ascii_case("interview")
-        {
-            let key = job.track.clone().unwrap_or_else(|| "unknown".to_string());
-            *replies.entry(key).or_insert(0) += 1;
+    let mut furthest = None;
+    let mut applied_at = None;
+    let mut replied_at = None;
+    for entry in history {
+        let Some(stage) = workflow.stage_of(&entry.status) else { continue };
+        furthest = furthest.max(Some(stage as usize));
+        let at = DateTime::parse_from_rfc3339(&entry.entered_at).ok();
+        let open = !workflow.is_closed(&entry.status.to_ascii_lowercase());
+        if stage == FunnelStage::Applied && open && applied_at.is_none() {
+            applied_at = at;
         }
+        if stage > FunnelStage::Applied && replied_at.is_none() {
+            replied_at = at;
+        }
+    }
+    let days = match (applied_at, replied_at) {
+        (Some(applied), Some(replied)) if replied >= applied => {
+            Some((replied - applied).num_seconds() as f64 / 86_400.0)
+        }
+        _ => None,
+    };
+    (furthest, days)
+}
+
+fn fit_bucket(fit_total: Option<i64>) -> String {
+    match fit_total {
+        None => "unscored",
+        Some(v) if v >= 85 => "85+",
+        Some(v) if v >= 70 => "70-84",
+        Some(v) if v >= 50 => "50-69",
+        Some(_) => "<50",
+    }
+    .to_string()
+}
+
+fn rate(n: usize, d: usize) -> f64 {
+    if d == 0 {
+        0.0
+    } else {
+        n as f64 / d as f64
+    }
+}
 
-        let role_lower = job.role.to_ascii_lowercase();
-        for token in ["python", "sql", "okta", "jamf", "audit", "incident", "change"] {
-            if role_lower.contains(token) {
-                *correlations.entry(token.to_string()).or_insert(0) += 1;
+impl Funnel {
+    fn add(&mut self, furthest: usize) {
+        self.applied += 1;
+        self.replied += usize::from(furthest >= 1);
+        self.interviewed += usize::from(furthest >= 2);
+        self.offered += usize::from(furthest >= 3);
+    }
+
+    fn finish(mut self) -> Self {
+        self.reply_rate = rate(self.replied, self.applied);
+        self.interview_rate = rate(self.interviewed, self.applied);
+        self.offer_rate = rate(self.offered, self.applied);
+        self
+    }
+}
+
+fn sorted_funnels(groups: BTreeMap<String, Funnel>) -> Vec<Funnel> {
+    let mut out = groups
+        .into_iter()
+        .map(|(key, funnel)| Funnel { key, ..funnel }.finish())
+        .collect::<Vec<_>>();
+    out.sort_by(|a, b| b.applied.cmp(&a.applied).then_with(|| a.key.cmp(&b.key)));
+    out
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 { (values[mid - 1] + values[mid]) / 2.0 } else { values[mid] })
+}
+
+/// Leading phrases that say how much of a skill is wanted rather than which skill.
+const GAP_PREFIXES: &[&str] = &[
+    "requirements:",
+    "required:",
+    "requires",
+    "required",
+    "add skills:",
+    "add skills",
+    "experience with",
+    "experience in",
+    "experience",
+    "familiarity with",
+    "familiar with",
+    "knowledge of",
+    "hands-on",
+    "proven",
+    "strong",
+    "solid",
+    "deep",
+    "of",
+    "in",
+    "with",
+];
+
+/// Trailing words that restate the lead-in ("audit readiness experience").
+const GAP_SUFFIXES: &[&str] = &[" experience", " familiarity", " knowledge", " skills"];
+
+/// Leading year counts ("5+ years", "3 yrs").
+static GAP_YEARS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d+\+?\s*(?:years?|yrs)\b").expect("regex"));
+
+/// Separators between the skills of one requirement line.
+static GAP_SEPARATORS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*(?:,|;|\band\b|\bor\b)\s*").expect("regex"));
+
+/// Splits a `FitScore.gaps` requirement line into the skills it names, lowercased and
+/// without bullets, year counts or "experience with"-style lead-ins. Header-only lines
+/// such as "Requirements:" produce nothing.
+pub fn normalize_gap(raw: &str) -> Vec<String> {
+    let mut text =
+        raw.trim().trim_start_matches(['-', '*', '\u{2022}']).trim().to_ascii_lowercase();
+    loop {
+        let before = text.len();
+        text = GAP_YEARS.replace(&text, "").trim().to_string();
+        for prefix in GAP_PREFIXES {
+            if let Some(rest) = text.strip_prefix(prefix) {
+                if rest.is_empty() || rest.starts_with([' ', ':']) || prefix.ends_with(':') {
+                    text = rest.trim_start_matches(':').trim().to_string();
+                }
             }
         }
+        if text.len() == before {
+            break;
+        }
+    }
+
+    GAP_SEPARATORS
+        .split(&text)
+        .map(|part| {
+            let part = part.trim().trim_end_matches(['.', ':', ';', ')']).trim();
+            GAP_SUFFIXES.iter().find_map(|suffix| part.strip_suffix(suffix)).unwrap_or(part).trim()
+        })
+        .filter(|part| part.len() >= 2)
+        .map(str::to_string)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum GapKind {
+    /// Part of a JD requirement that matched no skill in the bank (`FitScore.gaps`).
+    Requirement,
+    /// A tool the JD names that is missing from the skills bank or not approved.
+    Tool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissingSkill {
+    pub skill: String,
+    pub kind: GapKind,
+    /// Packets that were missing it.
+    pub jobs: usize,
+    /// The skill is in the skills bank but not approved, so approving it may be enough.
+    pub unapproved: bool,
+    /// One original requirement line or tool name it came from.
+    pub example: String,
+}
 
-        if let Some(notes) = &job.notes {
-            for marker in ["gap:", "missing:"] {
-                if let Some(idx) = notes.to_ascii_lowercase().find(marker) {
-                    let value = notes[idx + marker.len()..].trim().to_string();
-                    if !value.is_empty() {
-                        *gaps.entry(value).or_insert(0) += 1;
-                    }
+/// Ranks the skills most often missing across every packet, from `FitScore.gaps` and from
+/// JD tools the skills bank does not approve.
+pub fn aggregate_missing_skills(jobs: &[JobSignals<'_>], banks: &Banks) -> Vec<MissingSkill> {
+    let approved = |name: &str| {
+        banks.skills.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.approved)
+    };
+    let mut found = BTreeMap::<(String, GapKind), MissingSkill>::new();
+
+    for signals in jobs {
+        let mut seen = BTreeSet::new();
+        let mut note = |skill: String, kind: GapKind, example: &str| {
+            if !seen.insert((skill.clone(), kind)) {
+                return;
+            }
+            let unapproved = approved(&skill) == Some(false);
+            found
+                .entry((skill.clone(), kind))
+                .or_insert_with(|| MissingSkill {
+                    skill,
+                    kind,
+                    jobs: 0,
+                    unapproved,
+                    example: example.trim().to_string(),
+                })
+                .jobs += 1;
+        };
+        if let Some(fit) = &signals.fit {
+            for gap in &fit.gaps {
+                for skill in normalize_gap(gap) {
+                    note(skill, GapKind::Requirement, gap);
                 }
             }
         }
+        if let Some(extracted) = &signals.extracted {
+            for tool in &extracted.tools {
+                if approved(tool) != Some(true) {
+                    note(tool.trim().to_ascii_lowercase(), GapKind::Tool, tool);
+                }
+            }
+        }
+    }
+
+    let mut out = found.into_values().collect::<Vec<_>>();
+    out.sort_by(|a, b| {
+        b.jobs.cmp(&a.jobs).then_with(|| b.kind.cmp(&a.kind)).then_with(|| a.skill.cmp(&b.skill))
+    });
+    out.truncate(MAX_MISSING);
+    out
+}
+
+pub fn build_insights(
+    jobs: &[JobSignals<'_>],
+    banks: &Banks,
+    workflow: &StatusWorkflow,
+) -> Insights {
+    let mut overall = Funnel { key: "all".to_string(), ..Funnel::default() };
+    let mut by_track = BTreeMap::<String, Funnel>::new();
+    let mut by_source = BTreeMap::<String, Funnel>::new();
+    let mut by_baseline = BTreeMap::<String, Funnel>::new();
+    let mut by_fit_bucket = BTreeMap::<String, Funnel>::new();
+    let mut reply_days = Vec::new();
+    let mut terms = BTreeMap::<(String, TermKind), (usize, usize)>::new();
+    let mut extracted_applied = 0usize;
+    let mut extracted_positive = 0usize;
+
+    for signals in jobs {
+        let job = signals.job;
+        let (furthest, days) = progress(signals, workflow);
+        let Some(furthest) = furthest else { continue };
+        overall.add(furthest);
+        by_track
+            .entry(job.track.clone().unwrap_or_else(|| "unknown".to_string()))
+            .or_default()
+            .add(furthest);
+        by_source.entry(job.source.clone()).or_default().add(furthest);
+        by_baseline.entry(job.baseline.clone()).or_default().add(furthest);
+        by_fit_bucket.entry(fit_bucket(job.fit_total)).or_default().add(furthest);
+        reply_days.extend(days);
+
+        let Some(extracted) = &signals.extracted else { continue };
+        let positive = furthest >= 1;
+        extracted_applied += 1;
+        extracted_positive += usize::from(positive);
+        let mut seen = BTreeSet::new();
+        let tagged = extracted
+            .keywords
+            .iter()
+            .map(|t| (t, TermKind::Keyword))
+            .chain(extracted.tools.iter().map(|t| (t, TermKind::Tool)));
+        for (term, kind) in tagged {
+            let key = (term.trim().to_ascii_lowercase(), kind);
+            if key.0.is_empty() || !seen.insert(key.clone()) {
+                continue;
+            }
+            let entry = terms.entry(key).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 += usize::from(positive);
+        }
+    }
+
+    let base_rate = rate(extracted_positive, extracted_applied);
+    let mut keyword_correlations = terms
+        .into_iter()
+        .filter(|(_, (jobs, _))| *jobs >= MIN_TERM_SUPPORT)
+        .map(|((term, kind), (jobs, positive))| {
+            let positive_rate = rate(positive, jobs);
+            let lift = if base_rate > 0.0 { positive_rate / base_rate } else { 0.0 };
+            TermOutcome { term, kind, jobs, positive, positive_rate, lift }
+        })
+        .collect::<Vec<_>>();
+    keyword_correlations.sort_by(|a, b| {
+        b.lift
+            .total_cmp(&a.lift)
+            .then_with(|| b.jobs.cmp(&a.jobs))
+            .then_with(|| a.term.cmp(&b.term))
+    });
+    keyword_correlations.truncate(MAX_TERMS);
+
+    let reply_samples = reply_days.len();
+    Insights {
+        overall: overall.finish(),
+        by_track: sorted_funnels(by_track),
+        by_source: sorted_funnels(by_source),
+        by_baseline: sorted_funnels(by_baseline),
+        by_fit_bucket: sorted_funnels(by_fit_bucket),
+        median_days_to_reply: median(&mut reply_days),
+        reply_samples,
+        missing_skills: aggregate_missing_skills(jobs, banks),
+        keyword_correlations,
+    }
+}
+
+const COMPONENTS: [&str; 5] =
+    ["role_match", "stack_match", "scale_match", "rigor_match", "signal_boost"];
+
+/// Calibration needs at least this many applied jobs with a fit breakdown, and at least one
+/// with and one without a reply, before it suggests new weights.
+const MIN_CALIBRATION_JOBS: usize = 10;
+
+fn component_points(fit: &FitScore) -> [u8; 5] {
+    [fit.role_match, fit.stack_match, fit.scale_match, fit.rigor_match, fit.signal_boost]
+}
+
+fn component_weights(scoring: &ScoringConfig) -> [u8; 5] {
+    [
+        scoring.role_match,
+        scoring.stack_match,
+        scoring.scale_match,
+        scoring.rigor_match,
+        scoring.signal_boost,
+    ]
+}
+
+fn scoring_from_weights(w: [u8; 5]) -> ScoringConfig {
+    ScoringConfig {
+        role_match: w[0],
+        stack_match: w[1],
+        scale_match: w[2],
+        rigor_match: w[3],
+        signal_boost: w[4],
     }
+}
+
+/// Reply and interview rates for the jobs that fell into one score range.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalibrationBin {
+    pub label: String,
+    pub jobs: usize,
+    pub replied: usize,
+    pub interviewed: usize,
+    pub reply_rate: f64,
+    pub interview_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ComponentCalibration {
+    pub component: String,
+    pub weight: u8,
+    pub suggested_weight: u8,
+    /// Bins by the share of `weight` the job scored: low (<1/3), mid, high (>=2/3).
+    pub bins: Vec<CalibrationBin>,
+    /// Mean share scored by jobs that got a reply minus jobs that did not, in -1.0..=1.0.
+    pub separation: f64,
+}
 
-    let mut replies_by_track = replies.into_iter().collect::<Vec<_>>();
-    replies_by_track.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitCalibration {
+    /// Applied jobs with a fit breakdown in their packet's `ReviewData.json`.
+    pub jobs: usize,
+    pub replied: usize,
+    /// False when there is too little data; `suggested` then equals `current`.
+    pub enough_data: bool,
+    pub total_bins: Vec<CalibrationBin>,
+    pub components: Vec<ComponentCalibration>,
+    pub current: ScoringConfig,
+    pub suggested: ScoringConfig,
+    /// Chance a replied job outscores one without a reply (0.5 is no better than a coin
+    /// flip), under the current and the suggested weights.
+    pub separation_before: Option<f64>,
+    pub separation_after: Option<f64>,
+}
 
-    let mut common_gaps = gaps.into_iter().collect::<Vec<_>>();
-    common_gaps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+#[derive(Default)]
+struct BinCounts {
+    jobs: usize,
+    replied: usize,
+    interviewed: usize,
+}
 
-    let mut keyword_correlations = correlations.into_iter().collect::<Vec<_>>();
-    keyword_correlations.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+impl BinCounts {
+    fn add(&mut self, furthest: usize) {
+        self.jobs += 1;
+        self.replied += usize::from(furthest >= 1);
+        self.interviewed += usize::from(furthest >= 2);
+    }
 
-    Insights { replies_by_track, common_gaps, keyword_correlations }
+    fn finish(self, label: &str) -> CalibrationBin {
+        CalibrationBin {
+            label: label.to_string(),
+            jobs: self.jobs,
+            replied: self.replied,
+            interviewed: self.interviewed,
+            reply_rate: rate(self.replied, self.jobs),
+            interview_rate: rate(self.interviewed, self.jobs),
+        }
+    }
+}
+
+fn share_bin(points: u8, weight: u8) -> usize {
+    if weight == 0 {
+        return 0;
+    }
+    let share = f64::from(points) / f64::from(weight);
+    if share >= 2.0 / 3.0 {
+        2
+    } else if share >= 1.0 / 3.0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Share of each component's weight a job scored.
+fn shares(points: [u8; 5], weights: [u8; 5]) -> [f64; 5] {
+    std::array::from_fn(|i| {
+        if weights[i] == 0 {
+            0.0
+        } else {
+            (f64::from(points[i]) / f64::from(weights[i])).min(1.0)
+        }
+    })
+}
+
+fn predicted_total(shares: &[f64; 5], weights: [u8; 5]) -> f64 {
+    shares.iter().zip(weights).map(|(s, w)| s * f64::from(w)).sum()
+}
+
+/// Probability that a random positive outscores a random negative, ties counting half.
+fn auc(positives: &[f64], negatives: &[f64]) -> Option<f64> {
+    if positives.is_empty() || negatives.is_empty() {
+        return None;
+    }
+    let mut wins = 0.0;
+    for p in positives {
+        for n in negatives {
+            wins += match p.total_cmp(n) {
+                std::cmp::Ordering::Greater => 1.0,
+                std::cmp::Ordering::Equal => 0.5,
+                std::cmp::Ordering::Less => 0.0,
+            };
+        }
+    }
+    Some(wins / (positives.len() * negatives.len()) as f64)
+}
+
+/// Moves half of the weight budget towards the components that separate replies from
+/// silence, keeping the current total. Components that separate in the wrong direction only
+/// keep their half of the old weight.
+fn suggest_weights(current: [u8; 5], separation: [f64; 5]) -> [u8; 5] {
+    let budget: u32 = current.iter().map(|w| u32::from(*w)).sum();
+    let signal: f64 = separation.iter().map(|s| s.max(0.0)).sum();
+    if budget == 0 || signal <= 0.0 {
+        return current;
+    }
+    let raw: [f64; 5] = std::array::from_fn(|i| {
+        0.5 * f64::from(current[i]) + 0.5 * f64::from(budget) * separation[i].max(0.0) / signal
+    });
+
+    // Largest remainder, so the suggested weights add up to the same total.
+    let mut out: [u8; 5] = std::array::from_fn(|i| raw[i].floor() as u8);
+    let assigned: u32 = out.iter().map(|w| u32::from(*w)).sum();
+    let mut order = (0..5).collect::<Vec<_>>();
+    order.sort_by(|a, b| (raw[*b] - raw[*b].floor()).total_cmp(&(raw[*a] - raw[*a].floor())));
+    for i in order.into_iter().take(budget.saturating_sub(assigned) as usize) {
+        out[i] += 1;
+    }
+    out
+}
+
+/// Bins applied jobs by fit total and by each fit component, and suggests `[scoring]`
+/// weights that would better separate jobs that got a reply from those that did not.
+pub fn calibrate_fit(
+    jobs: &[JobSignals<'_>],
+    scoring: &ScoringConfig,
+    workflow: &StatusWorkflow,
+) -> FitCalibration {
+    let weights = component_weights(scoring);
+    let mut total_bins = BTreeMap::<String, BinCounts>::new();
+    let mut component_bins: [[BinCounts; 3]; 5] = Default::default();
+    let mut scored = Vec::<([f64; 5], bool)>::new();
+
+    for signals in jobs {
+        let (Some(furthest), _) = progress(signals, workflow) else { continue };
+        total_bins.entry(fit_bucket(signals.job.fit_total)).or_default().add(furthest);
+        let Some(fit) = &signals.fit else { continue };
+        let points = component_points(fit);
+        for (i, bins) in component_bins.iter_mut().enumerate() {
+            bins[share_bin(points[i], weights[i])].add(furthest);
+        }
+        scored.push((shares(points, weights), furthest >= 1));
+    }
+
+    let replied = scored.iter().filter(|(_, positive)| *positive).count();
+    let enough_data = scored.len() >= MIN_CALIBRATION_JOBS && replied > 0 && replied < scored.len();
+    let separation: [f64; 5] = std::array::from_fn(|i| {
+        let mean = |want: bool| {
+            let values =
+                scored.iter().filter(|(_, p)| *p == want).map(|(s, _)| s[i]).collect::<Vec<_>>();
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<f64>() / values.len() as f64
+            }
+        };
+        mean(true) - mean(false)
+    });
+    let suggested = if enough_data { suggest_weights(weights, separation) } else { weights };
+
+    let separation_for = |w: [u8; 5]| {
+        let (pos, neg): (Vec<_>, Vec<_>) = scored.iter().partition(|(_, p)| *p);
+        let totals = |items: Vec<&([f64; 5], bool)>| {
+            items.iter().map(|(s, _)| predicted_total(s, w)).collect::<Vec<_>>()
+        };
+        auc(&totals(pos), &totals(neg))
+    };
+
+    let mut total_bins =
+        total_bins.into_iter().map(|(label, counts)| counts.finish(&label)).collect::<Vec<_>>();
+    let bucket_rank =
+        |label: &str| ["85+", "70-84", "50-69", "<50", "unscored"].iter().position(|b| *b == label);
+    total_bins.sort_by_key(|bin| bucket_rank(&bin.label));
+
+    let components = component_bins
+        .into_iter()
+        .enumerate()
+        .map(|(i, bins)| {
+            let [low, mid, high] = bins;
+            ComponentCalibration {
+                component: COMPONENTS[i].to_string(),
+                weight: weights[i],
+                suggested_weight: suggested[i],
+                bins: vec![low.finish("low"), mid.finish("mid"), high.finish("high")],
+                separation: separation[i],
+            }
+        })
+        .collect();
+
+    FitCalibration {
+        jobs: scored.len(),
+        replied,
+        enough_data,
+        total_bins,
+        components,
+        current: scoring.clone(),
+        suggested: scoring_from_weights(suggested),
+        separation_before: separation_for(weights),
+        separation_after: separation_for(suggested),
+    }
+}
+
+fn read_extracted(output_dir: Option<&str>) -> Option<ExtractedJd> {
+    let raw = std::fs::read_to_string(Path::new(output_dir?).join("Extracted.json")).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+#[derive(Deserialize)]
+struct ReviewFit {
+    fit: FitScore,
+}
+
+fn read_fit(output_dir: Option<&str>) -> Option<FitScore> {
+    let raw = std::fs::read_to_string(Path::new(output_dir?).join("ReviewData.json")).ok()?;
+    serde_json::from_str::<ReviewFit>(&raw).ok().map(|r| r.fit)
+}
+
+/// Loads every tracked job with its status history and what its packet folder still holds,
+/// and hands them to `f`. Jobs whose packet is gone are included without packet data.
+fn with_signals<T>(store: &Store, f: impl FnOnce(&[JobSignals<'_>]) -> T) -> anyhow::Result<T> {
+    let jobs = list_jobs(store)?;
+    let history = list_all_status_history(store)?;
+    let signals = jobs
+        .iter()
+        .map(|job| JobSignals {
+            job,
+            history: history.get(&job.id).map(Vec::as_slice).unwrap_or(&[]),
+            extracted: read_extracted(job.output_dir.as_deref()),
+            fit: read_fit(job.output_dir.as_deref()),
+        })
+        .collect::<Vec<_>>();
+    Ok(f(&signals))
+}
+
+/// Builds insights from every tracked job, its status history and the `Extracted.json` and
+/// fit breakdown in its packet folder. Jobs whose packet is gone still count towards the
+/// funnels.
+pub fn collect_insights(
+    store: &Store,
+    banks: &Banks,
+    workflow: &StatusWorkflow,
+) -> anyhow::Result<Insights> {
+    with_signals(store, |signals| build_insights(signals, banks, workflow))
+}
+
+/// Runs [`calibrate_fit`] over every tracked job, reading fit breakdowns from the packets.
+pub fn collect_fit_calibration(
+    store: &Store,
+    scoring: &ScoringConfig,
+    workflow: &StatusWorkflow,
+) -> anyhow::Result<FitCalibration> {
+    with_signals(store, |signals| calibrate_fit(signals, scoring, workflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::banks::Skill;
+    use crate::types::SkillLevel;
+
+    fn job(id: &str, track: &str, source: &str, fit: i64, status: &str) -> JobRecord {
+        JobRecord {
+            id: id.to_string(),
+            company: "Acme".to_string(),
+            role: "Engineer".to_string(),
+            source: source.to_string(),
+            baseline: "1pg".to_string(),
+            track: Some(track.to_string()),
+            fit_total: Some(fit),
+            status: status.to_string(),
+            next_action: None,
+            notes: None,
+            output_dir: None,
+            created_at: "2026-01-01T00:00:00+00:00".to_string(),
+            updated_at: "2026-01-01T00:00:00+00:00".to_string(),
+            status_entered_at: None,
+            closed_reason: None,
+            next_action_due: None,
+        }
+    }
+
+    fn entry(status: &str, entered_at: &str) -> StatusEntry {
+        StatusEntry {
+            status: status.to_string(),
+            entered_at: entered_at.to_string(),
+            closed_reason: None,
+        }
+    }
+
+    fn no_banks() -> Banks {
+        Banks { bullets: Vec::new(), skills: BTreeMap::new() }
+    }
+
+    fn extracted(keywords: &[&str], tools: &[&str]) -> ExtractedJd {
+        ExtractedJd {
+            normalized_text: String::new(),
+            keywords: keywords.iter().map(|s| s.to_string()).collect(),
+            tools: tools.iter().map(|s| s.to_string()).collect(),
+            requirements: Vec::new(),
+            scale_signals: Vec::new(),
+            rigor_signals: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn funnels_follow_history_and_correlate_terms() {
+        let jobs = [
+            job("a", "support", "linkedin", 90, "offer"),
+            job("b", "support", "referral", 72, "rejected"),
+            job("c", "infra", "linkedin", 40, "screening"),
+            job("d", "infra", "linkedin", 60, "new"),
+        ];
+        let history_a = [
+            entry("new", "2026-01-01T00:00:00+00:00"),
+            entry("applied", "2026-01-02T00:00:00+00:00"),
+            entry("reply", "2026-01-06T00:00:00+00:00"),
+            entry("interview", "2026-01-10T00:00:00+00:00"),
+            entry("offer", "2026-01-20T00:00:00+00:00"),
+        ];
+        let history_b = [
+            entry("applied", "2026-01-02T00:00:00+00:00"),
+            entry("rejected", "2026-01-03T00:00:00+00:00"),
+        ];
+        let history_c = [
+            entry("applied", "2026-01-01T00:00:00+00:00"),
+            entry("screening", "2026-01-03T00:00:00+00:00"),
+        ];
+        let signals = [
+            JobSignals {
+                job: &jobs[0],
+                history: &history_a,
+                extracted: Some(extracted(&["python", "audit"], &["okta"])),
+                fit: None,
+            },
+            JobSignals {
+                job: &jobs[1],
+                history: &history_b,
+                extracted: Some(extracted(&["audit"], &["jamf"])),
+                fit: None,
+            },
+            JobSignals {
+                job: &jobs[2],
+                history: &history_c,
+                extracted: Some(extracted(&["Python"], &["okta", "jamf"])),
+                fit: None,
+            },
+            JobSignals {
+                job: &jobs[3],
+                history: &[],
+                extracted: Some(extracted(&["python"], &[])),
+                fit: None,
+            },
+        ];
+
+        let insights = build_insights(&signals, &no_banks(), &StatusWorkflow::default());
+        assert_eq!(
+            (insights.overall.applied, insights.overall.replied, insights.overall.interviewed),
+            (3, 2, 1)
+        );
+        assert_eq!(insights.overall.offered, 1);
+        let support = insights.by_track.iter().find(|f| f.key == "support").expect("support");
+        assert_eq!((support.applied, support.replied, support.offered), (2, 1, 1));
+        assert_eq!(support.reply_rate, 0.5);
+        assert_eq!(insights.by_source[0].key, "linkedin");
+        assert_eq!(insights.by_source[0].applied, 2);
+        assert!(insights.by_fit_bucket.iter().any(|f| f.key == "85+" && f.offered == 1));
+        assert!(insights.by_fit_bucket.iter().all(|f| f.key != "50-69"));
+        assert_eq!(insights.reply_samples, 2);
+        assert_eq!(insights.median_days_to_reply, Some(3.0));
+
+        let python = insights
+            .keyword_correlations
+            .iter()
+            .find(|t| t.term == "python" && t.kind == TermKind::Keyword)
+            .expect("python");
+        assert_eq!((python.jobs, python.positive), (2, 2));
+        assert!((python.lift - 1.5).abs() < 1e-9);
+        let audit =
+            insights.keyword_correlations.iter().find(|t| t.term == "audit").expect("audit");
+        assert!((audit.lift - 0.75).abs() < 1e-9);
+        assert_eq!(insights.keyword_correlations[0].lift, python.lift);
+    }
+
+    #[test]
+    fn funnels_use_workflow_stages_for_custom_and_backfilled_statuses() {
+        let mut workflow = StatusWorkflow::default();
+        workflow.statuses.push("phone_screen".to_string());
+        workflow.stages.insert("phone_screen".to_string(), FunnelStage::Reply);
+        let jobs = [
+            job("custom", "support", "linkedin", 80, "phone_screen"),
+            job("backfilled", "support", "linkedin", 80, "closed"),
+            job("withdrawn", "support", "linkedin", 80, "withdrawn"),
+            job("fresh", "support", "linkedin", 80, "new"),
+        ];
+        let custom_history = [
+            entry("applied", "2026-01-01T00:00:00+00:00"),
+            entry("phone_screen", "2026-01-03T00:00:00+00:00"),
+        ];
+        // Migration 4 backfilled a single row holding the job's status at the time.
+        let backfilled_history = [entry("closed", "2026-01-05T00:00:00+00:00")];
+        let signals = [
+            JobSignals { job: &jobs[0], history: &custom_history, extracted: None, fit: None },
+            JobSignals { job: &jobs[1], history: &backfilled_history, extracted: None, fit: None },
+            JobSignals { job: &jobs[2], history: &[], extracted: None, fit: None },
+            JobSignals { job: &jobs[3], history: &[], extracted: None, fit: None },
+        ];
+
+        let insights = build_insights(&signals, &no_banks(), &workflow);
+        assert_eq!((insights.overall.applied, insights.overall.replied), (3, 1));
+        assert_eq!(insights.median_days_to_reply, Some(2.0));
+
+        let default = build_insights(&signals, &no_banks(), &StatusWorkflow::default());
+        assert_eq!((default.overall.applied, default.overall.replied), (3, 0));
+    }
+
+    fn fit(stack: u8, scale: u8) -> FitScore {
+        FitScore {
+            role_match: 15,
+            stack_match: stack,
+            scale_match: scale,
+            rigor_match: 5,
+            signal_boost: 5,
+            total: 15 + stack + scale + 10,
+            why_match: Vec::new(),
+            gaps: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn calibration_shifts_weight_to_components_that_predict_replies() {
+        let scoring = ScoringConfig {
+            role_match: 30,
+            stack_match: 30,
+            scale_match: 20,
+            rigor_match: 10,
+            signal_boost: 10,
+        };
+        // Stack match tracks replies exactly; scale match points the other way.
+        let rows = (0..12)
+            .map(|i| {
+                let replied = i % 2 == 0;
+                let stack = if replied { 20 } else { 10 };
+                let scale = if replied { 0 } else { 20 };
+                let status = if replied { "reply" } else { "rejected" };
+                (job(&format!("j{i}"), "support", "linkedin", 60, status), fit(stack, scale))
+            })
+            .collect::<Vec<_>>();
+        let mut signals = rows
+            .iter()
+            .map(|(job, fit)| JobSignals {
+                job,
+                history: &[],
+                extracted: None,
+                fit: Some(fit.clone()),
+            })
+            .collect::<Vec<_>>();
+        let unapplied = job("new", "support", "linkedin", 90, "new");
+        signals.push(JobSignals { job: &unapplied, history: &[], extracted: None, fit: None });
+
+        let calibration = calibrate_fit(&signals, &scoring, &StatusWorkflow::default());
+        assert!(calibration.enough_data);
+        assert_eq!((calibration.jobs, calibration.replied), (12, 6));
+        assert_eq!(calibration.total_bins.len(), 1);
+        assert_eq!(calibration.total_bins[0].label, "50-69");
+
+        let stack = &calibration.components[1];
+        assert_eq!(stack.component, "stack_match");
+        assert_eq!(stack.bins[2].reply_rate, 1.0);
+        assert_eq!(stack.bins[0].reply_rate, 0.0);
+        assert!(stack.suggested_weight > stack.weight);
+        assert!(calibration.components[2].suggested_weight < calibration.components[2].weight);
+
+        let suggested = component_weights(&calibration.suggested);
+        assert_eq!(suggested.iter().map(|w| u32::from(*w)).sum::<u32>(), 100);
+        let before = calibration.separation_before.expect("before");
+        let after = calibration.separation_after.expect("after");
+        assert_eq!((before, after), (0.0, 1.0));
+    }
+
+    #[test]
+    fn calibration_keeps_weights_without_enough_outcomes() {
+        let scoring = ScoringConfig {
+            role_match: 30,
+            stack_match: 30,
+            scale_match: 20,
+            rigor_match: 10,
+            signal_boost: 10,
+        };
+        let applied = job("a", "support", "linkedin", 70, "applied");
+        let signals =
+            [JobSignals { job: &applied, history: &[], extracted: None, fit: Some(fit(20, 10)) }];
+        let calibration = calibrate_fit(&signals, &scoring, &StatusWorkflow::default());
+        assert!(!calibration.enough_data);
+        assert_eq!(component_weights(&calibration.suggested), component_weights(&scoring));
+        assert_eq!(calibration.separation_before, None);
+    }
+
+    #[test]
+    fn normalize_gap_strips_lead_ins_and_splits_lists() {
+        assert_eq!(
+            normalize_gap("- Experience with change management and post-incident reviews."),
+            vec!["change management", "post-incident reviews"]
+        );
+        assert_eq!(
+            normalize_gap("5+ years of Terraform, Kubernetes; or Nomad"),
+            vec!["terraform", "kubernetes", "nomad"]
+        );
+        assert_eq!(normalize_gap("Strong familiarity with Intune"), vec!["intune"]);
+        assert_eq!(normalize_gap("Audit readiness experience"), vec!["audit readiness"]);
+        assert!(normalize_gap("Requirements:").is_empty());
+    }
+
+    #[test]
+    fn missing_skills_rank_gaps_and_unapproved_tools_across_packets() {
+        let skill = |approved| Skill { level: SkillLevel::Familiar, approved };
+        let banks = Banks {
+            bullets: Vec::new(),
+            skills: BTreeMap::from([
+                ("Okta".to_string(), skill(true)),
+                ("Jamf".to_string(), skill(false)),
+            ]),
+        };
+        let jobs = [
+            job("a", "support", "linkedin", 60, "new"),
+            job("b", "support", "linkedin", 60, "applied"),
+        ];
+        let gaps = |lines: &[&str]| FitScore {
+            gaps: lines.iter().map(|s| s.to_string()).collect(),
+            ..fit(10, 10)
+        };
+        let signals = [
+            JobSignals {
+                job: &jobs[0],
+                history: &[],
+                extracted: Some(extracted(&[], &["Okta", "Jamf"])),
+                fit: Some(gaps(&["Experience with Terraform and Kubernetes", "Requirements:"])),
+            },
+            JobSignals {
+                job: &jobs[1],
+                history: &[],
+                extracted: Some(extracted(&[], &["Jamf", "Datadog"])),
+                fit: Some(gaps(&["3 years Terraform", "Terraform certification"])),
+            },
+        ];
+
+        let missing = aggregate_missing_skills(&signals, &banks);
+        let names = missing.iter().map(|m| (m.skill.as_str(), m.jobs)).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("jamf", 2),
+                ("terraform", 2),
+                ("datadog", 1),
+                ("kubernetes", 1),
+                ("terraform certification", 1)
+            ]
+        );
+        assert!(missing[0].unapproved && missing[0].kind == GapKind::Tool);
+        assert!(!missing[2].unapproved);
+        assert_eq!(missing[1].example, "Experience with Terraform and Kubernetes");
+    }
 }
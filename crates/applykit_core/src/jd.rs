@@ -5,6 +5,7 @@ use anyhow::bail;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::LazyLock;
 
 fn normalize_whitespace(input: &str) -> String {
     let nfc: String = unicode_normalization::UnicodeNormalization::nfc(input).collect();
@@ -45,6 +46,68 @@ pub struct LlmJdSummary {
     pub rigor_signals: Vec<String>,
 }
 
+/// Patterns for JD lines that read as instructions to a model rather than job content. Each
+/// needs directive context so ordinary duties ("Fabricate prototypes", "You are an AI
+/// engineer") stay in the prompt.
+const INJECTION_PATTERNS: &[&str] = &[
+    r"\b(ignore|disregard|forget|override)\b.{0,40}\b(instructions?|prompts?|rules|directions|guidelines)\b",
+    r"\b(state|say|claim|pretend|assert|write)\s+(that\s+)?you\s+(own|owned|led|lead|have|had|are|were|built|managed|run|ran)\b",
+    r"\b(fabricate|invent|make\s+up|exaggerate|embellish|inflate)\s+(any\s+|some\s+|more\s+|additional\s+|extra\s+)?(your\s+|their\s+|the\s+candidate'?s\s+)?(experience|claims?|achievements?|skills|credentials|qualifications|accomplishments|metrics|titles?|work\s+history)\b",
+    r"\badd\s+(skills|tools|experience|achievements)\s+you\s+(don'?t|do\s+not)\s+have\b",
+    r"\bclaim\b.{0,40}\b(achievements?|experience|skills|titles?|scope)\b",
+    r"\beven\s+if\s+(not\s+listed|you\s+(don'?t|do\s+not)|it\s+is\s*n[o']t)\b",
+    r"\b(system\s+prompt|developer\s+message|jailbreak)\b",
+    r"\byou\s+are\s+now\s+(an?\s+)?(ai|assistant|language\s+model|llm|chatbot)\b",
+    r"\b(act|behave|respond)\s+as\s+(an?\s+)?(ai\s+)?(assistant|language\s+model|llm|chatbot)\b",
+];
+
+static INJECTION_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    INJECTION_PATTERNS
+        .iter()
+        .map(|p| Regex::new(&format!("(?i){p}")).expect("injection regex"))
+        .collect()
+});
+
+/// JD text with instruction-like lines removed, plus the lines that were removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InjectionScan {
+    pub sanitized_text: String,
+    pub flagged_lines: Vec<String>,
+}
+
+/// Splits `jd_text` into lines safe to show a model and lines that look like injected
+/// instructions ("ignore prior instructions", "state that you own ..."). The deterministic
+/// extractor still sees the full text; only LLM prompts use `sanitized_text`.
+pub fn scan_prompt_injection(jd_text: &str) -> InjectionScan {
+    let mut kept = Vec::new();
+    let mut flagged_lines = Vec::new();
+    for line in jd_text.lines() {
+        if INJECTION_REGEXES.iter().any(|re| re.is_match(line)) {
+            flagged_lines.push(line.trim().to_string());
+        } else {
+            kept.push(line);
+        }
+    }
+    InjectionScan { sanitized_text: kept.join("\n"), flagged_lines }
+}
+
+/// JSON schema describing [`LlmJdSummary`], used to constrain `summarize_jd` output.
+pub fn llm_jd_summary_schema() -> serde_json::Value {
+    let list = serde_json::json!({ "type": "array", "items": { "type": "string" } });
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "keywords": list,
+            "requirements": list,
+            "tools": list,
+            "scale_signals": list,
+            "rigor_signals": list,
+        },
+        "required": ["keywords", "requirements", "tools", "scale_signals", "rigor_signals"],
+        "additionalProperties": false,
+    })
+}
+
 fn extract_json_object(raw: &str) -> &str {
     let trimmed = raw.trim();
     if let (Some(start), Some(end)) = (trimmed.find('{'), trimmed.rfind('}')) {
@@ -55,10 +118,62 @@ fn extract_json_object(raw: &str) -> &str {
     trimmed
 }
 
+/// Checks `value` against the subset of JSON schema used by [`llm_jd_summary_schema`]:
+/// `type` (object/array/string), `properties`, `required`, `items` and
+/// `additionalProperties: false`.
+fn validate_against_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+) -> Result<(), String> {
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("object") => {
+            let object = value.as_object().ok_or_else(|| format!("{path} must be an object"))?;
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            for required in schema.get("required").and_then(|r| r.as_array()).into_iter().flatten()
+            {
+                let key = required.as_str().unwrap_or_default();
+                if !object.contains_key(key) {
+                    return Err(format!("{path} is missing `{key}`"));
+                }
+            }
+            for (key, child) in object {
+                match properties.and_then(|p| p.get(key)) {
+                    Some(child_schema) => {
+                        validate_against_schema(child, child_schema, &format!("{path}.{key}"))?
+                    }
+                    None if schema.get("additionalProperties")
+                        == Some(&serde_json::Value::Bool(false)) =>
+                    {
+                        return Err(format!("{path} has unexpected field `{key}`"));
+                    }
+                    None => {}
+                }
+            }
+            Ok(())
+        }
+        Some("array") => {
+            let items = value.as_array().ok_or_else(|| format!("{path} must be an array"))?;
+            if let Some(item_schema) = schema.get("items") {
+                for (idx, item) in items.iter().enumerate() {
+                    validate_against_schema(item, item_schema, &format!("{path}[{idx}]"))?;
+                }
+            }
+            Ok(())
+        }
+        Some("string") if !value.is_string() => Err(format!("{path} must be a string")),
+        _ => Ok(()),
+    }
+}
+
 pub fn parse_llm_jd_summary(raw: &str) -> anyhow::Result<LlmJdSummary> {
     let candidate = extract_json_object(raw);
-    let parsed: LlmJdSummary =
+    let value: serde_json::Value =
         serde_json::from_str(candidate).map_err(|e| anyhow::anyhow!("summary parse error: {e}"))?;
+    validate_against_schema(&value, &llm_jd_summary_schema(), "summary")
+        .map_err(|e| anyhow::anyhow!("summary schema error: {e}"))?;
+    let parsed: LlmJdSummary =
+        serde_json::from_value(value).map_err(|e| anyhow::anyhow!("summary parse error: {e}"))?;
     Ok(parsed)
 }
 
@@ -1,6 +1,9 @@
+pub mod backup;
+pub mod bank_lint;
 pub mod banks;
 pub mod classify;
 pub mod config;
+pub mod coverage;
 pub mod determinism;
 pub mod diff;
 pub mod insights;
@@ -8,17 +11,36 @@ pub mod jd;
 pub mod messages;
 pub mod packet;
 pub mod pipeline;
+pub mod prompts;
+pub mod reindex;
+pub mod reminders;
 pub mod resume;
 pub mod score;
 pub mod source_preview;
 pub mod storage;
+pub mod tracker_io;
 pub mod truth_gate;
 pub mod types;
+pub mod workflow;
 
+pub use applykit_llm::{CancelToken, HealthReport};
+pub use backup::{
+    backup_database, check_database, default_backup_path, restore_database, BackupInfo,
+    DbCheckReport, RestoreInfo,
+};
+pub use bank_lint::{lint_bank_json, lint_banks, LintIssue, LintReport, LintSeverity};
+pub use coverage::{bank_coverage, BulletUsage, CoverageReport, RequestedSkill, SkillUsage};
+pub use insights::{
+    aggregate_missing_skills, calibrate_fit, collect_fit_calibration, collect_insights,
+    normalize_gap, CalibrationBin, ComponentCalibration, FitCalibration, Funnel, GapKind, Insights,
+    MissingSkill, TermKind, TermOutcome,
+};
 pub use pipeline::{
-    generate_packet, read_packet_detail, read_packet_detail_by_job_id, GenerateOptions,
-    GenerateResult,
+    check_llm_health, clear_llm_cache, generate_packet, llm_cache_dir, read_packet_detail,
+    read_packet_detail_by_job_id, GenerateOptions, GenerateResult,
 };
+pub use reindex::{reindex_packets, MissingPacket, ReindexReport, SkippedPacket};
+pub use reminders::{collect_due, reminders_to_ics, DueReport, Reminder, ReminderKind};
 pub use source_preview::{
     create_bullet_value, create_skill_value, load_banks_preview, load_templates_preview,
     save_bullet_text_value, save_template_value, set_bullet_approved_value,
@@ -26,9 +48,20 @@ pub use source_preview::{
     MutationResponse, TemplateKey, TemplatesPreview,
 };
 pub use storage::{
-    get_job_by_id, init_db, list_jobs, update_job_status, upsert_job_record, JobRecord,
+    add_contact, add_interview, delete_contact, delete_interview, find_duplicate_jobs,
+    get_job_by_id, has_jobs, index_job_resume, latest_schema_version, list_all_status_history,
+    list_contacts, list_interviews, list_job_events, list_jobs, list_status_history, query_jobs,
+    record_export_event, search_jobs, update_contact, update_interview, update_job_status,
+    upsert_job_record, Contact, ContactInput, ContactRole, DuplicateJob, DuplicateReason,
+    Interview, InterviewInput, JobEvent, JobEventType, JobPage, JobQuery, JobRecord, JobSortKey,
+    JobStatusUpdate, SearchHit, StatusEntry, Store,
+};
+pub use tracker_io::{
+    collect_tracker_export, import_tracker_csv, tracker_export_csv, ImportIssue, TrackerExport,
+    TrackerExportJob, TrackerImportOptions, TrackerImportReport,
 };
 pub use types::{Baseline, Track};
+pub use workflow::{FunnelStage, StatusWorkflow};
 
 #[cfg(test)]
 mod tests;
@@ -17,6 +17,13 @@ fn load_template(repo_root: &Path, file: &str) -> anyhow::Result<String> {
     std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
 }
 
+/// Who each message greets; unset names fall back to "Hiring Team".
+#[derive(Debug, Clone, Default)]
+pub struct MessageRecipients {
+    pub recruiter: Option<String>,
+    pub hiring_manager: Option<String>,
+}
+
 pub fn generate_messages(
     repo_root: &Path,
     company: &str,
@@ -24,6 +31,7 @@ pub fn generate_messages(
     fit: &FitScore,
     track: Track,
     top_matches: &[String],
+    recipients: &MessageRecipients,
 ) -> anyhow::Result<(String, String, String)> {
     let recruiter_template = load_template(repo_root, "recruiter.md")?;
     let hiring_template = load_template(repo_root, "hiring_manager.md")?;
@@ -41,7 +49,6 @@ pub fn generate_messages(
     let mut vars = BTreeMap::new();
     vars.insert("company", company.to_string());
     vars.insert("role", role.to_string());
-    vars.insert("name", "Hiring Team".to_string());
     vars.insert("how_i_help", format!("Track focus: {track}"));
     vars.insert("top_match_1", top1.clone());
     vars.insert("top_match_2", top2.clone());
@@ -50,9 +57,15 @@ pub fn generate_messages(
     vars.insert("problem_2", "support-to-systems leverage".to_string());
     vars.insert("cta", "Happy to share details and tailored materials.".to_string());
 
-    let recruiter = render_template(&recruiter_template, &vars);
-    let hiring = render_template(&hiring_template, &vars);
-    let cover = render_template(&cover_template, &vars);
+    let greet = |name: &Option<String>| {
+        let mut vars = vars.clone();
+        vars.insert("name", name.clone().unwrap_or_else(|| "Hiring Team".to_string()));
+        vars
+    };
+    let recruiter = render_template(&recruiter_template, &greet(&recipients.recruiter));
+    let hiring_vars = greet(&recipients.hiring_manager);
+    let hiring = render_template(&hiring_template, &hiring_vars);
+    let cover = render_template(&cover_template, &hiring_vars);
 
     Ok((recruiter, hiring, cover))
 }
@@ -2,6 +2,7 @@ use crate::types::{ExtractedJd, FitScore, TailorPlan, Track, TrackerRow};
 use anyhow::Context;
 use chrono::NaiveDate;
 use serde_json::json;
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -10,9 +11,13 @@ use std::path::{Path, PathBuf};
 pub struct PacketWriteInput<'a> {
     pub output_base: &'a Path,
     pub date: NaiveDate,
+    /// Recorded in `Meta.json` so `applykit db reindex` can restore the same tracker row.
+    pub job_id: &'a str,
     pub company: &'a str,
     pub role: &'a str,
     pub source: &'a str,
+    pub baseline: &'a str,
+    pub jd_hash: &'a str,
     pub jd_text: &'a str,
     pub extracted: &'a ExtractedJd,
     pub fit: &'a FitScore,
@@ -24,6 +29,7 @@ pub struct PacketWriteInput<'a> {
     pub cover_short_message: &'a str,
     pub diff_md: &'a str,
     pub track: Track,
+    pub prompt_versions: &'a BTreeMap<String, String>,
 }
 
 fn slugify(s: &str) -> String {
@@ -94,26 +100,29 @@ fn tailor_plan_md(plan: &TailorPlan) -> String {
     let mut out = String::new();
     out.push_str("# Tailor Plan\n\n");
     out.push_str(&format!("- Max edits: {}\n", plan.max_resume_edits));
-    out.push_str(&format!("- Max bullet swaps: {}\n\n", plan.max_bullet_swaps));
+    out.push_str(&format!("- Max bullet swaps: {}\n", plan.max_bullet_swaps));
+    if !plan.pending_tool_bullets.is_empty() {
+        out.push_str(&format!(
+            "- Skipped until their tools are approved skills: {}\n",
+            plan.pending_tool_bullets.join(", ")
+        ));
+    }
+    out.push('\n');
     for (idx, edit) in plan.edits.iter().enumerate() {
         out.push_str(&format!("{}. {} [{}]\n", idx + 1, edit.reason, edit.target_section));
     }
     out
 }
 
-fn tracker_csv(row: &TrackerRow) -> String {
-    fn csv_escape(value: &str) -> String {
-        if value.contains(',')
-            || value.contains('"')
-            || value.contains('\n')
-            || value.contains('\r')
-        {
-            format!("\"{}\"", value.replace('"', "\"\""))
-        } else {
-            value.to_string()
-        }
+pub(crate) fn csv_escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
     }
+}
 
+fn tracker_csv(row: &TrackerRow) -> String {
     let mut out = String::new();
     out.push_str("date,company,role,source,track,fit_total,status,next_action,packet_dir\n");
     out.push_str(&format!(
@@ -189,11 +198,16 @@ pub fn write_packet(
     write_named("TrackerRow.csv", &tracker_csv(&tracker_row))?;
 
     let meta_json = serde_json::to_string_pretty(&json!({
+        "job_id": input.job_id,
         "company": input.company,
         "role": input.role,
+        "source": input.source,
+        "baseline": input.baseline,
+        "jd_hash": input.jd_hash,
         "date": input.date.format("%Y-%m-%d").to_string(),
         "track": input.track,
-        "fit_total": input.fit.total
+        "fit_total": input.fit.total,
+        "prompt_versions": input.prompt_versions
     }))?;
     write_named("Meta.json", &meta_json)?;
     sync_dir(&tmp_dir)?;
@@ -3,35 +3,64 @@ use crate::classify::classify_track;
 use crate::config::{
     load_config, load_runtime_settings, merge_config_with_runtime, resolve_output_base,
 };
+use crate::determinism::sha256_hex;
 use crate::diff::inline_diff_md;
-use crate::jd::{extract_structured, merge_extracted_with_summary, parse_llm_jd_summary};
-use crate::messages::generate_messages;
+use crate::jd::{
+    extract_structured, llm_jd_summary_schema, merge_extracted_with_summary, parse_llm_jd_summary,
+    scan_prompt_injection,
+};
+use crate::messages::{generate_messages, MessageRecipients};
 use crate::packet::{write_packet, PacketWriteInput};
+use crate::prompts::{load_prompt_set, render_prompt, render_prompt_fields};
 use crate::resume::{load_resume_template, tailor_resume};
 use crate::score::compute_fit_score;
-use crate::storage::{get_job_by_id, upsert_job_record, UpsertJobRecordInput};
+use crate::storage::{
+    find_duplicate_jobs, get_job_by_id, index_job_resume, list_contacts, upsert_job_record,
+    ContactRole, Store, UpsertJobRecordInput,
+};
 use crate::truth_gate::validate;
 use crate::types::{
     ExtractionDiagnostics, ExtractionSource, GenerateInput, GenerateResultData, PacketDetail,
     TrackerRow,
 };
 use anyhow::Context;
-use applykit_llm::{LlmAdapter, LlmRequest, LlmTask, OllamaAdapter, OpenAiCompatAdapter};
+use applykit_llm::{
+    check_health, CacheIdentity, CachedAdapter, CancelToken, HealthReport, LlamaCppAdapter,
+    LlmAdapter, LlmCache, LlmCancelled, LlmRequest, LlmTask, OllamaAdapter, OpenAiCompatAdapter,
+    OutputConstraint, RecordingAdapter, ReplayAdapter,
+};
 use chrono::{Local, NaiveDate};
-use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct GenerateOptions {
     pub repo_root: PathBuf,
+    pub cancel: Option<CancelToken>,
+    /// Skip the on-disk LLM response cache for this run (neither read nor written).
+    pub bypass_llm_cache: bool,
+    /// Write into this existing job instead of deriving a new id, keeping its tracker history.
+    pub regenerate_job_id: Option<String>,
+    /// Tracker for the output base, shared by long-lived callers; opened for this run if unset.
+    pub store: Option<Arc<Store>>,
+}
+
+impl GenerateOptions {
+    fn ensure_not_cancelled(&self) -> anyhow::Result<()> {
+        match &self.cancel {
+            Some(token) if token.is_cancelled() => {
+                Err(anyhow::Error::new(LlmCancelled).context("generation cancelled"))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 pub type GenerateResult = anyhow::Result<GenerateResultData>;
 
-fn hash_jd(jd_text: &str) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(jd_text.as_bytes());
-    format!("{:x}", hasher.finalize())
+/// Hash stored as `jobs.jd_hash` and used for duplicate detection.
+pub fn hash_jd(jd_text: &str) -> String {
+    sha256_hex(jd_text)
 }
 
 fn output_date(input: Option<NaiveDate>) -> NaiveDate {
@@ -42,54 +71,218 @@ fn task_allowed(cfg: &crate::config::LlmConfig, task: &str) -> bool {
     cfg.allowed_tasks.iter().any(|t| t.eq_ignore_ascii_case(task))
 }
 
-fn llm_rewrite(
+pub fn llm_cache_dir(output_base: &Path) -> PathBuf {
+    output_base.join(".llm_cache")
+}
+
+/// Deletes every cached LLM response under `output_base` and returns how many were removed.
+pub fn clear_llm_cache(output_base: &Path) -> anyhow::Result<usize> {
+    LlmCache::new(llm_cache_dir(output_base)).clear()
+}
+
+/// Per-run state shared by every LLM call in one generation.
+struct LlmContext<'a> {
+    cfg: &'a crate::config::LlmConfig,
+    repo_root: &'a Path,
+    cancel: Option<&'a CancelToken>,
+    cache: Option<LlmCache>,
+}
+
+fn is_replay_provider(cfg: &crate::config::LlmConfig) -> bool {
+    cfg.provider.eq_ignore_ascii_case("replay")
+}
+
+fn resolve_repo_path(repo_root: &Path, raw: &str) -> PathBuf {
+    let path = Path::new(raw);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        repo_root.join(path)
+    }
+}
+
+fn build_adapter(ctx: &LlmContext<'_>) -> Box<dyn LlmAdapter> {
+    let cfg = ctx.cfg;
+    if is_replay_provider(cfg) {
+        return Box::new(ReplayAdapter { dir: resolve_repo_path(ctx.repo_root, &cfg.replay_dir) });
+    }
+    let mut adapter = build_provider_adapter(cfg, ctx.cancel);
+    if let Some(cache) = &ctx.cache {
+        adapter = Box::new(CachedAdapter {
+            inner: adapter,
+            cache: cache.clone(),
+            identity: CacheIdentity {
+                provider: cfg.provider.to_ascii_lowercase(),
+                model: cfg.model.clone(),
+                seed: cfg.seed,
+            },
+        });
+    }
+    // Outermost, so responses served from the cache are recorded too.
+    match &cfg.record_dir {
+        Some(record_dir) => Box::new(RecordingAdapter {
+            inner: adapter,
+            dir: resolve_repo_path(ctx.repo_root, record_dir),
+        }),
+        None => adapter,
+    }
+}
+
+fn build_provider_adapter(
     cfg: &crate::config::LlmConfig,
+    cancel: Option<&CancelToken>,
+) -> Box<dyn LlmAdapter> {
+    let options = cfg.client_options(cancel.cloned());
+    match cfg.provider.to_ascii_lowercase().as_str() {
+        "ollama" => Box::new(OllamaAdapter {
+            base_url: cfg.base_url.clone(),
+            model: cfg.model.clone(),
+            options,
+        }),
+        "llamacpp" | "llama.cpp" | "llama_cpp" => Box::new(LlamaCppAdapter {
+            base_url: cfg.base_url.clone(),
+            n_predict: cfg.n_predict,
+            seed: cfg.seed,
+            stop: cfg.stop.clone(),
+            options,
+        }),
+        _ => Box::new(OpenAiCompatAdapter {
+            provider_name: cfg.provider.clone(),
+            base_url: cfg.base_url.clone(),
+            model: cfg.model.clone(),
+            options,
+        }),
+    }
+}
+
+/// Probes the configured (runtime-merged) provider. The local-only base URL policy is enforced
+/// before any request; the replay provider only checks that its fixtures directory exists.
+pub fn check_llm_health(repo_root: &Path) -> anyhow::Result<HealthReport> {
+    let runtime_settings = load_runtime_settings(repo_root)?;
+    let cfg = merge_config_with_runtime(load_config(repo_root)?, &runtime_settings).llm;
+    if is_replay_provider(&cfg) {
+        let dir = resolve_repo_path(repo_root, &cfg.replay_dir);
+        let reachable = dir.is_dir();
+        return Ok(HealthReport {
+            provider: cfg.provider,
+            base_url: dir.display().to_string(),
+            reachable,
+            latency_ms: reachable.then_some(0),
+            models: Vec::new(),
+            model: cfg.model,
+            model_available: reachable,
+            error: (!reachable).then(|| format!("replay_dir {} does not exist", dir.display())),
+        });
+    }
+    crate::config::validate_local_llm_base_url(&cfg.base_url)
+        .context("llm base_url violates local-only policy")?;
+    Ok(check_health(&cfg.provider, &cfg.base_url, &cfg.model, &cfg.client_options(None)))
+}
+
+fn llm_rewrite(
+    ctx: &LlmContext<'_>,
     task: LlmTask,
     task_name: &str,
     prompt: &str,
 ) -> anyhow::Result<Option<String>> {
+    let cfg = ctx.cfg;
     if !cfg.enabled || !task_allowed(cfg, task_name) {
         return Ok(None);
     }
-    crate::config::validate_local_llm_base_url(&cfg.base_url)
-        .context("llm base_url violates local-only policy")?;
-
-    let request = LlmRequest { task, prompt: prompt.to_string() };
-    let provider = cfg.provider.to_ascii_lowercase();
+    if !is_replay_provider(cfg) {
+        crate::config::validate_local_llm_base_url(&cfg.base_url)
+            .context("llm base_url violates local-only policy")?;
+    }
 
-    let output = if provider == "ollama" {
-        let adapter = OllamaAdapter { base_url: cfg.base_url.clone(), model: cfg.model.clone() };
-        adapter.rewrite(&request)?.output
-    } else {
-        let adapter = OpenAiCompatAdapter {
-            provider_name: cfg.provider.clone(),
-            base_url: cfg.base_url.clone(),
-            model: cfg.model.clone(),
-        };
-        adapter.rewrite(&request)?.output
+    let constraint = match task {
+        LlmTask::SummarizeJd => Some(OutputConstraint::JsonSchema(llm_jd_summary_schema())),
+        LlmTask::RewriteMessage | LlmTask::RewriteBullet => None,
     };
+    let request = LlmRequest { task, prompt: prompt.to_string(), constraint };
+    let output = build_adapter(ctx).rewrite(&request)?.output;
 
     Ok(Some(output.trim().to_string()))
 }
 
+/// Greets the job's first recruiter and hiring-manager contacts, when it has any.
+fn message_recipients(store: &Store, job_id: &str) -> anyhow::Result<MessageRecipients> {
+    let contacts = list_contacts(store, job_id)?;
+    let first = |role| contacts.iter().find(|c| c.role == role).map(|c| c.name.clone());
+    Ok(MessageRecipients {
+        recruiter: first(ContactRole::Recruiter),
+        hiring_manager: first(ContactRole::HiringManager),
+    })
+}
+
 pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> GenerateResult {
     let runtime_settings = load_runtime_settings(&options.repo_root)?;
     let cfg = merge_config_with_runtime(load_config(&options.repo_root)?, &runtime_settings);
     let banks = load_banks(&options.repo_root)?;
     let allow_unapproved = input.allow_unapproved || runtime_settings.allow_unapproved;
+    let prompts = load_prompt_set(&options.repo_root)?;
+    let prompt_versions = prompts.versions();
+    let output_base =
+        input.outdir.clone().unwrap_or_else(|| resolve_output_base(&cfg.output.base_dir));
+    // Lookups skip a tracker that does not exist yet; it is only created when the job is
+    // recorded, so a cancelled or failed first run leaves nothing behind.
+    let db_path = output_base.join("applykit.db");
+    let tracker = match &options.store {
+        Some(store) => Some(Arc::clone(store)),
+        None if db_path.exists() => Some(Arc::new(Store::open(&db_path)?)),
+        None => None,
+    };
+    let regenerate_job_id = match options.regenerate_job_id.as_deref() {
+        Some(id) => Some(
+            tracker
+                .as_deref()
+                .map(|store| get_job_by_id(store, id))
+                .transpose()?
+                .flatten()
+                .ok_or_else(|| anyhow::anyhow!("job to regenerate not found: {id}"))?
+                .id,
+        ),
+        None => None,
+    };
+    let llm_ctx = LlmContext {
+        cfg: &cfg.llm,
+        repo_root: &options.repo_root,
+        cancel: options.cancel.as_ref(),
+        cache: (cfg.llm.cache && !options.bypass_llm_cache && !is_replay_provider(&cfg.llm))
+            .then(|| LlmCache::new(llm_cache_dir(&output_base))),
+    };
 
     let deterministic_extracted = extract_structured(&input.jd_text, &banks);
     let mut extracted = deterministic_extracted.clone();
     let mut extraction_source = ExtractionSource::Deterministic;
-    let mut extraction_diagnostics = ExtractionDiagnostics::default();
+    let injection_scan = scan_prompt_injection(&deterministic_extracted.normalized_text);
+    let mut extraction_diagnostics = ExtractionDiagnostics {
+        injection_warnings: injection_scan.flagged_lines.clone(),
+        ..ExtractionDiagnostics::default()
+    };
     if cfg.llm.enabled && task_allowed(&cfg.llm, "summarize_jd") {
         extraction_diagnostics.summarize_attempted = true;
-        let summarize_prompt = format!(
-            "Summarize the following job description into strict JSON with keys: keywords, requirements, tools, scale_signals, rigor_signals. Return JSON only.\n\n{}",
-            deterministic_extracted.normalized_text
-        );
-        match llm_rewrite(&cfg.llm, LlmTask::SummarizeJd, "summarize_jd", &summarize_prompt) {
-            Ok(Some(summary_text)) => match parse_llm_jd_summary(&summary_text) {
+        let summarize_prompt = render_prompt(&prompts.summarize_jd, &injection_scan.sanitized_text);
+        match llm_rewrite(&llm_ctx, LlmTask::SummarizeJd, "summarize_jd", &summarize_prompt) {
+            Ok(Some(summary_text)) => match parse_llm_jd_summary(&summary_text).or_else(|err| {
+                // One repair round-trip: show the model its output and the validation error.
+                extraction_diagnostics.summarize_repair_attempted = true;
+                let repair_prompt = render_prompt_fields(
+                    &prompts.summarize_jd_repair,
+                    &[
+                        ("jd_text", &injection_scan.sanitized_text),
+                        ("previous_output", summary_text.trim()),
+                        ("error", &err.to_string()),
+                        ("schema", &llm_jd_summary_schema().to_string()),
+                    ],
+                );
+                match llm_rewrite(&llm_ctx, LlmTask::SummarizeJd, "summarize_jd", &repair_prompt) {
+                    Ok(Some(repaired)) => {
+                        parse_llm_jd_summary(&repaired).map_err(|_| "parse_failed")
+                    }
+                    Ok(None) => Err("parse_failed"),
+                    Err(_) => Err("repair_request_failed"),
+                }
+            }) {
                 Ok(summary) => {
                     match merge_extracted_with_summary(&deterministic_extracted, &summary, &banks) {
                         Ok(merged) => {
@@ -102,9 +295,9 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
                             .push("merge_rejected".to_string()),
                     }
                 }
-                Err(_) => extraction_diagnostics
-                    .summarize_fallback_reasons
-                    .push("parse_failed".to_string()),
+                Err(reason) => {
+                    extraction_diagnostics.summarize_fallback_reasons.push(reason.to_string())
+                }
             },
             Ok(None) => {
                 extraction_diagnostics.summarize_fallback_reasons.push("empty_response".to_string())
@@ -114,6 +307,7 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             }
         }
     }
+    options.ensure_not_cancelled()?;
     let track = classify_track(&extracted.normalized_text, &cfg, input.track_override);
     let fit = compute_fit_score(&extracted, &track, &banks, &cfg);
 
@@ -149,6 +343,18 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
         .map(|e| e.reason.clone())
         .collect::<Vec<_>>();
 
+    let date = output_date(input.run_date);
+    let jd_hash = hash_jd(&input.jd_text);
+    let job_id = regenerate_job_id.unwrap_or_else(|| {
+        format!("{}:{}:{}:{}", input.company, input.role, date.format("%Y-%m-%d"), jd_hash)
+    });
+    let (duplicate_jobs, recipients) = match tracker.as_deref() {
+        Some(store) => (
+            find_duplicate_jobs(store, &jd_hash, &input.company, &input.role, Some(&job_id))?,
+            message_recipients(store, &job_id)?,
+        ),
+        None => (Vec::new(), MessageRecipients::default()),
+    };
     let (det_recruiter_message, det_hiring_manager_message, det_cover_short_message) =
         generate_messages(
             &options.repo_root,
@@ -157,6 +363,7 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             &fit,
             track.selected,
             &top_matches,
+            &recipients,
         )?;
 
     let mut resume_1pg = resume_1pg;
@@ -173,33 +380,31 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
 
     let mut llm_applied = false;
     if cfg.llm.enabled {
-        let style_prompt = "Rewrite for clarity and tone only. Preserve all claims, tools, and metrics exactly. Do not add any new achievements, tools, titles, or stronger ownership language.";
-
         if let Ok(Some(rewrite)) = llm_rewrite(
-            &cfg.llm,
+            &llm_ctx,
             LlmTask::RewriteMessage,
             "rewrite_message",
-            &format!("{style_prompt}\n\n{}", recruiter_message),
+            &render_prompt(&prompts.rewrite_message, &recruiter_message),
         ) {
             recruiter_message = rewrite;
             llm_applied = true;
         }
 
         if let Ok(Some(rewrite)) = llm_rewrite(
-            &cfg.llm,
+            &llm_ctx,
             LlmTask::RewriteMessage,
             "rewrite_message",
-            &format!("{style_prompt}\n\n{}", hiring_manager_message),
+            &render_prompt(&prompts.rewrite_message, &hiring_manager_message),
         ) {
             hiring_manager_message = rewrite;
             llm_applied = true;
         }
 
         if let Ok(Some(rewrite)) = llm_rewrite(
-            &cfg.llm,
+            &llm_ctx,
             LlmTask::RewriteMessage,
             "rewrite_message",
-            &format!("{style_prompt}\n\n{}", cover_short_message),
+            &render_prompt(&prompts.rewrite_message, &cover_short_message),
         ) {
             cover_short_message = rewrite;
             llm_applied = true;
@@ -209,12 +414,9 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             let selected_ids = provenance_ids.iter().collect::<Vec<_>>();
             for id in selected_ids {
                 if let Some(candidate) = bullet_candidates.iter().find(|b| &b.id == id) {
-                    let prompt = format!(
-                        "Rewrite this resume bullet for concise clarity only. Keep tools/metrics/claim exactly.\n\n{}",
-                        candidate.text
-                    );
+                    let prompt = render_prompt(&prompts.rewrite_bullet, &candidate.text);
                     if let Ok(Some(rewrite)) =
-                        llm_rewrite(&cfg.llm, LlmTask::RewriteBullet, "rewrite_bullet", &prompt)
+                        llm_rewrite(&llm_ctx, LlmTask::RewriteBullet, "rewrite_bullet", &prompt)
                     {
                         if resume_1pg.contains(&candidate.text) {
                             resume_1pg = resume_1pg.replace(&candidate.text, &rewrite);
@@ -232,13 +434,12 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
         }
     }
 
+    options.ensure_not_cancelled()?;
+
     let before = load_resume_template(
         &options.repo_root,
         matches!(input.baseline, crate::types::Baseline::TwoPage),
     )?;
-    let output_base =
-        input.outdir.clone().unwrap_or_else(|| resolve_output_base(&cfg.output.base_dir));
-    let date = output_date(input.run_date);
     let mut diff_md =
         inline_diff_md(&before, &resume_2pg.clone().unwrap_or_else(|| resume_1pg.clone()));
 
@@ -253,6 +454,7 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             extracted: extracted.clone(),
             extraction_source: extraction_source_value.clone(),
             extraction_diagnostics: extraction_diagnostics.clone(),
+            prompt_versions: prompt_versions.clone(),
             track: track.clone(),
             fit: fit.clone(),
             tailor_plan: tailor_plan.clone(),
@@ -283,6 +485,7 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             },
             packet_dir: PathBuf::new(),
             files_written: Vec::new(),
+            duplicate_jobs: Vec::new(),
         };
 
     let mut validation_candidate = make_validation_candidate(
@@ -322,9 +525,12 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
     let (packet_dir, files_written, tracker_row) = write_packet(PacketWriteInput {
         output_base: &output_base,
         date,
+        job_id: &job_id,
         company: &input.company,
         role: &input.role,
         source: &input.source,
+        baseline: input.baseline.as_cli_value(),
+        jd_hash: &jd_hash,
         jd_text: &extracted.normalized_text,
         extracted: &extracted,
         fit: &fit,
@@ -336,12 +542,14 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
         cover_short_message: &cover_short_message,
         diff_md: &diff_md,
         track: track.selected,
+        prompt_versions: &prompt_versions,
     })?;
 
     let generated = GenerateResultData {
         extracted,
         extraction_source,
         extraction_diagnostics,
+        prompt_versions,
         track,
         fit,
         tailor_plan,
@@ -356,6 +564,7 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
         truth_report,
         packet_dir: packet_dir.clone(),
         files_written,
+        duplicate_jobs,
     };
 
     let review_data_path = generated.packet_dir.join("ReviewData.json");
@@ -363,21 +572,16 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
     std::fs::write(&review_data_path, review_data)
         .with_context(|| format!("writing {}", review_data_path.display()))?;
 
-    let id = format!(
-        "{}:{}:{}:{}",
-        input.company,
-        input.role,
-        date.format("%Y-%m-%d"),
-        hash_jd(&input.jd_text)
-    );
-    let jd_hash = hash_jd(&input.jd_text);
     let track_label = generated.track.selected.to_string();
     let packet_dir_string = packet_dir.to_string_lossy().to_string();
-    let db_path = output_base.join("applykit.db");
+    let store = match tracker {
+        Some(store) => store,
+        None => Arc::new(Store::open(&db_path)?),
+    };
     upsert_job_record(
-        &db_path,
+        &store,
         UpsertJobRecordInput {
-            id: &id,
+            id: &job_id,
             company: &input.company,
             role: &input.role,
             source: &input.source,
@@ -389,7 +593,13 @@ pub fn generate_packet(input: GenerateInput, options: GenerateOptions) -> Genera
             output_dir: Some(&packet_dir_string),
         },
     )
-    .with_context(|| format!("recording job in {}", db_path.display()))?;
+    .with_context(|| format!("recording job in {}", store.path().display()))?;
+    let resume_text = match &generated.resume_2pg {
+        Some(two_page) => format!("{}\n\n{two_page}", generated.resume_1pg),
+        None => generated.resume_1pg.clone(),
+    };
+    index_job_resume(&store, &job_id, &resume_text)
+        .with_context(|| format!("indexing job in {}", store.path().display()))?;
 
     Ok(generated)
 }
@@ -424,6 +634,7 @@ pub fn read_packet_detail(packet_dir: &Path) -> anyhow::Result<PacketDetail> {
             extracted: data.extracted,
             extraction_source: data.extraction_source,
             extraction_diagnostics: data.extraction_diagnostics,
+            prompt_versions: data.prompt_versions,
             fit: data.fit,
             track: data.track,
             tailor_plan: data.tailor_plan,
@@ -444,16 +655,16 @@ pub fn read_packet_detail(packet_dir: &Path) -> anyhow::Result<PacketDetail> {
     anyhow::bail!("packet detail unavailable: {} missing", review_data_path.display())
 }
 
-pub fn read_packet_detail_by_job_id(
-    output_base: &Path,
-    job_id: &str,
-) -> anyhow::Result<PacketDetail> {
-    let db_path = output_base.join("applykit.db");
-    let job = get_job_by_id(&db_path, job_id)?
-        .ok_or_else(|| anyhow::anyhow!("job not found: {job_id}"))?;
+pub fn read_packet_detail_by_job_id(store: &Store, job_id: &str) -> anyhow::Result<PacketDetail> {
+    let job =
+        get_job_by_id(store, job_id)?.ok_or_else(|| anyhow::anyhow!("job not found: {job_id}"))?;
     let packet_dir = job
         .output_dir
         .map(PathBuf::from)
         .ok_or_else(|| anyhow::anyhow!("job has no output_dir: {job_id}"))?;
-    read_packet_detail(&packet_dir)
+    let mut detail = read_packet_detail(&packet_dir)?;
+    // The packet keeps its generation-time tracker row; the database holds the live state.
+    detail.tracker_row.status = job.status;
+    detail.tracker_row.next_action = job.next_action.unwrap_or_default();
+    Ok(detail)
 }
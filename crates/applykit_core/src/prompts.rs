@@ -0,0 +1,155 @@
+use crate::determinism::sha256_hex;
+use anyhow::Context;
+use applykit_llm::LlmTask;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    pub task: LlmTask,
+    /// File stem under `templates/prompts/`; also the key in `prompt_versions`.
+    pub name: String,
+    pub content: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PromptSet {
+    pub summarize_jd: PromptTemplate,
+    pub summarize_jd_repair: PromptTemplate,
+    pub rewrite_message: PromptTemplate,
+    pub rewrite_bullet: PromptTemplate,
+}
+
+/// Follow-up `summarize_jd` prompt sent once when a summary fails validation.
+pub const SUMMARIZE_JD_REPAIR_PROMPT: &str = "summarize_jd_repair";
+
+/// Placeholders the repair prompt must contain: the sanitized JD, the rejected reply, the
+/// validation error and the summary schema.
+pub const SUMMARIZE_JD_REPAIR_PLACEHOLDERS: [&str; 4] =
+    ["jd_text", "previous_output", "error", "schema"];
+
+pub fn prompt_path(repo_root: &Path, task: LlmTask) -> PathBuf {
+    named_prompt_path(repo_root, task.as_str())
+}
+
+pub fn named_prompt_path(repo_root: &Path, name: &str) -> PathBuf {
+    repo_root.join("templates").join("prompts").join(format!("{name}.md"))
+}
+
+/// Placeholder each prompt must contain; it receives the text being summarized or rewritten.
+pub fn prompt_placeholder(task: LlmTask) -> &'static str {
+    match task {
+        LlmTask::SummarizeJd => "jd_text",
+        LlmTask::RewriteMessage => "message",
+        LlmTask::RewriteBullet => "bullet",
+    }
+}
+
+pub fn prompt_version(content: &str) -> String {
+    sha256_hex(content)
+}
+
+pub fn load_prompt(repo_root: &Path, task: LlmTask) -> anyhow::Result<PromptTemplate> {
+    load_named_prompt(repo_root, task, task.as_str())
+}
+
+fn load_named_prompt(
+    repo_root: &Path,
+    task: LlmTask,
+    name: &str,
+) -> anyhow::Result<PromptTemplate> {
+    let path = named_prompt_path(repo_root, name);
+    let content =
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let version = prompt_version(&content);
+    Ok(PromptTemplate { task, name: name.to_string(), content, version })
+}
+
+pub fn load_prompt_set(repo_root: &Path) -> anyhow::Result<PromptSet> {
+    Ok(PromptSet {
+        summarize_jd: load_prompt(repo_root, LlmTask::SummarizeJd)?,
+        summarize_jd_repair: load_named_prompt(
+            repo_root,
+            LlmTask::SummarizeJd,
+            SUMMARIZE_JD_REPAIR_PROMPT,
+        )?,
+        rewrite_message: load_prompt(repo_root, LlmTask::RewriteMessage)?,
+        rewrite_bullet: load_prompt(repo_root, LlmTask::RewriteBullet)?,
+    })
+}
+
+impl PromptSet {
+    pub fn versions(&self) -> BTreeMap<String, String> {
+        [&self.summarize_jd, &self.summarize_jd_repair, &self.rewrite_message, &self.rewrite_bullet]
+            .into_iter()
+            .map(|p| (p.name.clone(), p.version.clone()))
+            .collect()
+    }
+}
+
+pub fn render_prompt(template: &PromptTemplate, input: &str) -> String {
+    render_prompt_fields(template, &[(prompt_placeholder(template.task), input)])
+}
+
+/// Substitutes each `{{name}}` placeholder in `template` with its value.
+pub fn render_prompt_fields(template: &PromptTemplate, fields: &[(&str, &str)]) -> String {
+    let mut out = template.content.clone();
+    for (name, value) in fields {
+        out = out.replace(&format!("{{{{{name}}}}}"), value);
+    }
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo_root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("..")
+            .canonicalize()
+            .expect("repo root")
+    }
+
+    #[test]
+    fn every_task_has_a_prompt_with_its_placeholder() {
+        for task in LlmTask::ALL {
+            let prompt = load_prompt(&repo_root(), task).expect("prompt");
+            let needle = format!("{{{{{}}}}}", prompt_placeholder(task));
+            assert!(prompt.content.contains(&needle), "{} missing {needle}", task.as_str());
+            assert_eq!(prompt.version.len(), 64);
+        }
+
+        let prompts = load_prompt_set(&repo_root()).expect("prompt set");
+        for name in SUMMARIZE_JD_REPAIR_PLACEHOLDERS {
+            assert!(prompts.summarize_jd_repair.content.contains(&format!("{{{{{name}}}}}")));
+        }
+        assert_eq!(prompts.versions().len(), 4);
+        assert!(prompts.versions().contains_key(SUMMARIZE_JD_REPAIR_PROMPT));
+        let repair = render_prompt_fields(
+            &prompts.summarize_jd_repair,
+            &[
+                ("jd_text", "Administer Okta."),
+                ("previous_output", "{}"),
+                ("error", "missing keywords"),
+                ("schema", "{\"type\":\"object\"}"),
+            ],
+        );
+        assert!(repair.contains("Administer Okta.") && repair.contains("missing keywords"));
+        assert!(!repair.contains("{{"));
+    }
+
+    #[test]
+    fn render_substitutes_input_and_trims_trailing_whitespace() {
+        let template = PromptTemplate {
+            task: LlmTask::RewriteBullet,
+            name: "rewrite_bullet".to_string(),
+            content: "Rewrite:\n\n{{bullet}}\n".to_string(),
+            version: prompt_version("Rewrite:\n\n{{bullet}}\n"),
+        };
+        assert_eq!(render_prompt(&template, "Did a thing."), "Rewrite:\n\nDid a thing.");
+    }
+}
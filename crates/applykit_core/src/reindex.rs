@@ -0,0 +1,205 @@
+use crate::pipeline::{hash_jd, list_packets_from_fs};
+use crate::storage::{
+    index_job_resume, list_jobs, reindex_job_record, ReindexOutcome, Store, UpsertJobRecordInput,
+};
+use crate::types::Track;
+use anyhow::Context;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// `Meta.json` as written by `write_packet`. Packets from before `job_id`, `source`,
+/// `baseline` and `jd_hash` were recorded fall back to values derived from the other files.
+#[derive(Debug, Deserialize)]
+struct PacketMeta {
+    company: String,
+    role: String,
+    date: NaiveDate,
+    track: Track,
+    fit_total: i64,
+    #[serde(default)]
+    job_id: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    baseline: Option<String>,
+    #[serde(default)]
+    jd_hash: Option<String>,
+}
+
+/// A packet directory that could not be indexed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedPacket {
+    pub packet_dir: PathBuf,
+    pub reason: String,
+}
+
+/// A tracker row whose `output_dir` is gone. Reindexing reports these but never deletes them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingPacket {
+    pub job_id: String,
+    pub output_dir: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReindexReport {
+    pub scanned: usize,
+    pub inserted: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: usize,
+    /// Older packets for a job that also has a newer packet on disk.
+    pub superseded: Vec<PathBuf>,
+    pub skipped: Vec<SkippedPacket>,
+    pub missing: Vec<MissingPacket>,
+}
+
+struct PacketRecord {
+    job_id: String,
+    meta: PacketMeta,
+    packet_dir: PathBuf,
+    source: String,
+    baseline: String,
+    jd_text: String,
+    jd_hash: String,
+    resume_text: Option<String>,
+}
+
+fn read_packet(
+    packet_dir: &Path,
+    known_ids: &BTreeMap<String, String>,
+) -> anyhow::Result<PacketRecord> {
+    let meta_path = packet_dir.join("Meta.json");
+    let raw = std::fs::read_to_string(&meta_path)
+        .with_context(|| format!("reading {}", meta_path.display()))?;
+    let meta: PacketMeta =
+        serde_json::from_str(&raw).with_context(|| format!("parsing {}", meta_path.display()))?;
+    let jd_path = packet_dir.join("JD.txt");
+    let jd_text = std::fs::read_to_string(&jd_path)
+        .with_context(|| format!("reading {}", jd_path.display()))?;
+
+    // ReviewData.json is optional; it supplies the source and resumes for older packets.
+    let review = std::fs::read_to_string(packet_dir.join("ReviewData.json"))
+        .ok()
+        .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok());
+    let review_str = |pointer: &str| {
+        review
+            .as_ref()
+            .and_then(|v| v.pointer(pointer))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    let resume_2pg = review_str("/resume_2pg")
+        .or_else(|| std::fs::read_to_string(packet_dir.join("Resume_2pg_Tailored.md")).ok());
+    let resume_1pg = review_str("/resume_1pg")
+        .or_else(|| std::fs::read_to_string(packet_dir.join("Resume_1pg_Tailored.md")).ok());
+    let resume_text = match (resume_1pg, &resume_2pg) {
+        (Some(one), Some(two)) => Some(format!("{one}\n\n{two}")),
+        (one, two) => one.or_else(|| two.clone()),
+    };
+
+    let output_dir = packet_dir.to_string_lossy().to_string();
+    let jd_hash = meta.jd_hash.clone().unwrap_or_else(|| hash_jd(&jd_text));
+    let job_id =
+        known_ids.get(&output_dir).cloned().or_else(|| meta.job_id.clone()).unwrap_or_else(|| {
+            format!("{}:{}:{}:{}", meta.company, meta.role, meta.date.format("%Y-%m-%d"), jd_hash)
+        });
+    Ok(PacketRecord {
+        job_id,
+        packet_dir: packet_dir.to_path_buf(),
+        source: meta
+            .source
+            .clone()
+            .or_else(|| review_str("/tracker_row/source"))
+            .unwrap_or_else(|| "unknown".to_string()),
+        baseline: meta
+            .baseline
+            .clone()
+            .unwrap_or_else(|| if resume_2pg.is_some() { "2pg" } else { "1pg" }.to_string()),
+        jd_text,
+        jd_hash,
+        resume_text,
+        meta,
+    })
+}
+
+/// Rebuilds the tracker in `store` from the packet directories under `output_base`.
+/// Missing jobs are restored and stale rows refreshed; tracker edits are left alone, so
+/// running it against a healthy database only reports `unchanged` packets.
+pub fn reindex_packets(store: &Store, output_base: &Path) -> anyhow::Result<ReindexReport> {
+    let jobs = list_jobs(store)?;
+    let known_ids = jobs
+        .iter()
+        .filter_map(|job| job.output_dir.clone().map(|dir| (dir, job.id.clone())))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut report = ReindexReport::default();
+    let mut latest: BTreeMap<String, PacketRecord> = BTreeMap::new();
+    for packet_dir in list_packets_from_fs(output_base)? {
+        report.scanned += 1;
+        let packet = match read_packet(&packet_dir, &known_ids) {
+            Ok(packet) => packet,
+            Err(err) => {
+                report.skipped.push(SkippedPacket { packet_dir, reason: format!("{err:#}") });
+                continue;
+            }
+        };
+        // Regenerating into a job leaves one packet per run; the newest one is the job's.
+        match latest.remove(&packet.job_id) {
+            Some(current)
+                if (current.meta.date, &current.packet_dir)
+                    > (packet.meta.date, &packet.packet_dir) =>
+            {
+                report.superseded.push(packet.packet_dir);
+                latest.insert(current.job_id.clone(), current);
+            }
+            Some(current) => {
+                report.superseded.push(current.packet_dir);
+                latest.insert(packet.job_id.clone(), packet);
+            }
+            None => {
+                latest.insert(packet.job_id.clone(), packet);
+            }
+        }
+    }
+
+    for packet in latest.values() {
+        let output_dir = packet.packet_dir.to_string_lossy().to_string();
+        let track = packet.meta.track.to_string();
+        let outcome = reindex_job_record(
+            store,
+            UpsertJobRecordInput {
+                id: &packet.job_id,
+                company: &packet.meta.company,
+                role: &packet.meta.role,
+                source: &packet.source,
+                baseline: &packet.baseline,
+                jd_text: &packet.jd_text,
+                jd_hash: &packet.jd_hash,
+                track: Some(&track),
+                fit_total: Some(packet.meta.fit_total),
+                output_dir: Some(&output_dir),
+            },
+            packet.meta.date,
+        )
+        .with_context(|| format!("reindexing {}", packet.packet_dir.display()))?;
+        if let Some(resume) = &packet.resume_text {
+            index_job_resume(store, &packet.job_id, resume)?;
+        }
+        match outcome {
+            ReindexOutcome::Inserted => report.inserted.push(packet.job_id.clone()),
+            ReindexOutcome::Updated => report.updated.push(packet.job_id.clone()),
+            ReindexOutcome::Unchanged => report.unchanged += 1,
+        }
+    }
+
+    report.missing = list_jobs(store)?
+        .into_iter()
+        .filter_map(|job| {
+            let output_dir = job.output_dir?;
+            (!Path::new(&output_dir).is_dir())
+                .then_some(MissingPacket { job_id: job.id, output_dir })
+        })
+        .collect();
+    Ok(report)
+}
@@ -0,0 +1,158 @@
+use crate::storage::JobRecord;
+use crate::workflow::StatusWorkflow;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderKind {
+    /// The job's next action has an explicit due date.
+    Scheduled,
+    /// No due date was set; suggested from the workflow's `follow_up_days`.
+    FollowUp,
+}
+
+impl ReminderKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::FollowUp => "follow_up",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub job_id: String,
+    pub company: String,
+    pub role: String,
+    pub status: String,
+    pub action: String,
+    pub due: NaiveDate,
+    pub kind: ReminderKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueReport {
+    pub today: NaiveDate,
+    pub overdue: Vec<Reminder>,
+    /// Due today or within the horizon.
+    pub upcoming: Vec<Reminder>,
+}
+
+fn reminder_for(job: &JobRecord, workflow: &StatusWorkflow) -> Option<Reminder> {
+    if workflow.is_closed(&job.status) {
+        return None;
+    }
+    let (action, due, kind) = match job.next_action_due.as_deref() {
+        Some(due) => (
+            job.next_action
+                .clone()
+                .filter(|a| !a.trim().is_empty())
+                .unwrap_or_else(|| "Next action".to_string()),
+            NaiveDate::parse_from_str(due, "%Y-%m-%d").ok()?,
+            ReminderKind::Scheduled,
+        ),
+        None => {
+            let days = *workflow.follow_up_days.get(&job.status)?;
+            let entered = DateTime::parse_from_rfc3339(job.status_entered_at.as_deref()?).ok()?;
+            (
+                format!("Follow up: no change {days} days after {}", job.status),
+                entered.date_naive() + Duration::days(i64::from(days)),
+                ReminderKind::FollowUp,
+            )
+        }
+    };
+    Some(Reminder {
+        job_id: job.id.clone(),
+        company: job.company.clone(),
+        role: job.role.clone(),
+        status: job.status.clone(),
+        action,
+        due,
+        kind,
+    })
+}
+
+/// Splits open jobs' reminders into overdue and those due within `horizon_days` of `today`.
+pub fn collect_due(
+    jobs: &[JobRecord],
+    workflow: &StatusWorkflow,
+    today: NaiveDate,
+    horizon_days: i64,
+) -> DueReport {
+    let mut reminders =
+        jobs.iter().filter_map(|job| reminder_for(job, workflow)).collect::<Vec<_>>();
+    reminders.sort_by(|a, b| {
+        a.due
+            .cmp(&b.due)
+            .then_with(|| a.company.cmp(&b.company))
+            .then_with(|| a.job_id.cmp(&b.job_id))
+    });
+    let horizon = today + Duration::days(horizon_days);
+    let (overdue, rest): (Vec<_>, Vec<_>) = reminders.into_iter().partition(|r| r.due < today);
+    let upcoming = rest.into_iter().filter(|r| r.due <= horizon).collect();
+    DueReport { today, overdue, upcoming }
+}
+
+fn ics_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line at 75 octets without splitting UTF-8 sequences (RFC 5545 §3.1).
+fn ics_fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += ch.len_utf8();
+    }
+    out
+}
+
+/// Renders reminders as all-day events. `stamp` is passed in so output is reproducible.
+pub fn reminders_to_ics(reminders: &[Reminder], stamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//ApplyKit//Reminders//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for reminder in reminders {
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!(
+                "UID:{}-{}-{}@applykit",
+                ics_escape(&reminder.job_id),
+                reminder.kind.as_str(),
+                reminder.due.format("%Y%m%d")
+            ),
+            format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")),
+            format!("DTSTART;VALUE=DATE:{}", reminder.due.format("%Y%m%d")),
+            format!("DTEND;VALUE=DATE:{}", (reminder.due + Duration::days(1)).format("%Y%m%d")),
+            format!(
+                "SUMMARY:{}",
+                ics_escape(&format!(
+                    "{} ({} - {})",
+                    reminder.action, reminder.company, reminder.role
+                ))
+            ),
+            format!(
+                "DESCRIPTION:{}",
+                ics_escape(&format!("Job {} is {}.", reminder.job_id, reminder.status))
+            ),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| ics_fold(line) + "\r\n").collect()
+}
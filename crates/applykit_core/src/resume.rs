@@ -83,22 +83,66 @@ fn find_section_range(lines: &[String], section_anchor: &str) -> Option<(usize,
     Some((start_idx + 1, end_idx))
 }
 
-pub fn tailor_resume(
-    template: &str,
+/// Bank bullets in the order `tailor_resume` picks them for a JD: most relevant first,
+/// ties broken by id. Unapproved bullets are left out unless `allow_unapproved`, and bullets
+/// naming a tool whose skill is not approved are always left out (see [`pending_tool_bullets`]).
+pub fn rank_bullets<'a>(
+    banks: &'a Banks,
     extracted: &ExtractedJd,
     track: Track,
-    banks: &Banks,
-    cfg: &ApplykitConfig,
     allow_unapproved: bool,
-) -> anyhow::Result<(String, TailorPlan, Vec<String>, Vec<BulletCandidate>)> {
-    let mut candidates =
-        banks.bullets.iter().filter(|b| allow_unapproved || b.approved).collect::<Vec<_>>();
+) -> Vec<&'a Bullet> {
+    let eligible = banks
+        .bullets
+        .iter()
+        .filter(|b| allow_unapproved || b.approved)
+        .filter(|b| banks.unapproved_tools(b).is_empty());
+    rank_by_relevance(eligible, extracted, track)
+}
 
-    candidates.sort_by(|a, b| {
+/// Every bank bullet in [`rank_bullets`] order, as if it and its tools were approved.
+pub fn rank_all_bullets<'a>(
+    banks: &'a Banks,
+    extracted: &ExtractedJd,
+    track: Track,
+) -> Vec<&'a Bullet> {
+    rank_by_relevance(banks.bullets.iter(), extracted, track)
+}
+
+fn rank_by_relevance<'a>(
+    bullets: impl Iterator<Item = &'a Bullet>,
+    extracted: &ExtractedJd,
+    track: Track,
+) -> Vec<&'a Bullet> {
+    let mut ranked = bullets.collect::<Vec<_>>();
+    ranked.sort_by(|a, b| {
         let a_score = relevance_score(a, extracted, track);
         let b_score = relevance_score(b, extracted, track);
         cmp_score_desc_id_asc(a_score, &a.id, b_score, &b.id)
     });
+    ranked
+}
+
+/// Ids of the bullets [`rank_bullets`] skips only because a tool's skill is not approved.
+pub fn pending_tool_bullets(banks: &Banks, allow_unapproved: bool) -> Vec<String> {
+    banks
+        .bullets
+        .iter()
+        .filter(|b| allow_unapproved || b.approved)
+        .filter(|b| !banks.unapproved_tools(b).is_empty())
+        .map(|b| b.id.clone())
+        .collect()
+}
+
+pub fn tailor_resume(
+    template: &str,
+    extracted: &ExtractedJd,
+    track: Track,
+    banks: &Banks,
+    cfg: &ApplykitConfig,
+    allow_unapproved: bool,
+) -> anyhow::Result<(String, TailorPlan, Vec<String>, Vec<BulletCandidate>)> {
+    let candidates = rank_bullets(banks, extracted, track, allow_unapproved);
 
     let ranked_candidates = candidates
         .iter()
@@ -184,6 +228,7 @@ pub fn tailor_resume(
         edits,
         max_resume_edits: cfg.determinism.max_resume_edits,
         max_bullet_swaps: cfg.determinism.max_bullet_swaps,
+        pending_tool_bullets: pending_tool_bullets(banks, allow_unapproved),
     };
     Ok((tailored, plan, provenance_ids, ranked_candidates))
 }
@@ -1,9 +1,14 @@
 use crate::banks::{
     add_bullet, add_skill, load_banks, save_bullet_text, set_bullet_approved, set_skill_approved,
-    set_skill_level, BulletRecord,
+    set_skill_level, BulletRecord, ToolCheck,
 };
 use crate::config::atomic_write_text;
+use crate::prompts::{
+    named_prompt_path, prompt_path, prompt_placeholder, SUMMARIZE_JD_REPAIR_PLACEHOLDERS,
+    SUMMARIZE_JD_REPAIR_PROMPT,
+};
 use anyhow::Context;
+use applykit_llm::LlmTask;
 use chrono::Utc;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -27,6 +32,10 @@ pub struct TemplatesPreview {
     pub recruiter_template: String,
     pub hiring_manager_template: String,
     pub cover_short_template: String,
+    pub summarize_jd_prompt: String,
+    pub summarize_jd_repair_prompt: String,
+    pub rewrite_message_prompt: String,
+    pub rewrite_bullet_prompt: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +46,26 @@ pub enum TemplateKey {
     Recruiter,
     HiringManager,
     CoverShort,
+    SummarizeJdPrompt,
+    SummarizeJdRepairPrompt,
+    RewriteMessagePrompt,
+    RewriteBulletPrompt,
+}
+
+impl TemplateKey {
+    /// Placeholders a prompt template must contain (and the only ones it may use).
+    fn prompt_placeholders(&self) -> Option<Vec<&'static str>> {
+        let task = match self {
+            Self::SummarizeJdPrompt => LlmTask::SummarizeJd,
+            Self::SummarizeJdRepairPrompt => {
+                return Some(SUMMARIZE_JD_REPAIR_PLACEHOLDERS.to_vec())
+            }
+            Self::RewriteMessagePrompt => LlmTask::RewriteMessage,
+            Self::RewriteBulletPrompt => LlmTask::RewriteBullet,
+            _ => return None,
+        };
+        Some(vec![prompt_placeholder(task)])
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +73,8 @@ pub struct MutationResponse {
     pub ok: bool,
     pub message: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 fn now_iso() -> String {
@@ -75,16 +106,29 @@ pub fn load_banks_preview(repo_root: &Path) -> anyhow::Result<BanksPreview> {
 }
 
 fn response_ok(message: impl Into<String>) -> MutationResponse {
-    MutationResponse { ok: true, message: message.into(), updated_at: now_iso() }
+    MutationResponse {
+        ok: true,
+        message: message.into(),
+        updated_at: now_iso(),
+        warnings: Vec::new(),
+    }
+}
+
+fn response_with_tools(message: impl Into<String>, check: &ToolCheck) -> MutationResponse {
+    MutationResponse { warnings: check.warnings(), ..response_ok(message) }
 }
 
 pub fn set_bullet_approved_value(
     repo_root: &Path,
     id: &str,
     approved: bool,
+    create_pending_skills: bool,
 ) -> anyhow::Result<MutationResponse> {
-    set_bullet_approved(repo_root, id, approved)?;
-    Ok(response_ok(format!("bullet {id} {}", if approved { "approved" } else { "unapproved" })))
+    let check = set_bullet_approved(repo_root, id, approved, create_pending_skills)?;
+    Ok(response_with_tools(
+        format!("bullet {id} {}", if approved { "approved" } else { "unapproved" }),
+        &check,
+    ))
 }
 
 pub fn set_skill_approved_value(
@@ -92,8 +136,16 @@ pub fn set_skill_approved_value(
     name: &str,
     approved: bool,
 ) -> anyhow::Result<MutationResponse> {
-    set_skill_approved(repo_root, name, approved)?;
-    Ok(response_ok(format!("skill {name} {}", if approved { "approved" } else { "unapproved" })))
+    let skipped = set_skill_approved(repo_root, name, approved)?;
+    let mut resp =
+        response_ok(format!("skill {name} {}", if approved { "approved" } else { "unapproved" }));
+    if !skipped.is_empty() {
+        resp.warnings.push(format!(
+            "generation skips these bullets until {name} is approved: {}",
+            skipped.join(", ")
+        ));
+    }
+    Ok(resp)
 }
 
 pub fn set_skill_level_value(
@@ -129,13 +181,16 @@ pub struct CreateBulletInput {
     pub tools: Vec<String>,
     #[serde(default)]
     pub approved: bool,
+    /// Add tools missing from the skills bank as pending skills instead of rejecting them.
+    #[serde(default)]
+    pub create_pending_skills: bool,
 }
 
 pub fn create_bullet_value(
     repo_root: &Path,
     input: CreateBulletInput,
 ) -> anyhow::Result<MutationResponse> {
-    add_bullet(
+    let check = add_bullet(
         repo_root,
         BulletRecord {
             id: input.id,
@@ -148,8 +203,9 @@ pub fn create_bullet_value(
             claim_level: input.claim_level,
             text: input.text,
         },
+        input.create_pending_skills,
     )?;
-    Ok(response_ok("bullet created"))
+    Ok(response_with_tools("bullet created", &check))
 }
 
 pub fn create_skill_value(
@@ -177,6 +233,12 @@ fn template_path(repo_root: &Path, key: &TemplateKey) -> std::path::PathBuf {
         TemplateKey::CoverShort => {
             repo_root.join("templates").join("messages").join("cover_short.md")
         }
+        TemplateKey::SummarizeJdPrompt => prompt_path(repo_root, LlmTask::SummarizeJd),
+        TemplateKey::SummarizeJdRepairPrompt => {
+            named_prompt_path(repo_root, SUMMARIZE_JD_REPAIR_PROMPT)
+        }
+        TemplateKey::RewriteMessagePrompt => prompt_path(repo_root, LlmTask::RewriteMessage),
+        TemplateKey::RewriteBulletPrompt => prompt_path(repo_root, LlmTask::RewriteBullet),
     }
 }
 
@@ -219,6 +281,20 @@ fn placeholder_set(content: &str) -> BTreeSet<String> {
 }
 
 fn validate_template(key: &TemplateKey, content: &str) -> anyhow::Result<()> {
+    if let Some(required) = key.prompt_placeholders() {
+        let placeholders = placeholder_set(content);
+        if let Some(missing) = required.iter().find(|name| !placeholders.contains(**name)) {
+            anyhow::bail!("prompt missing required placeholder: {missing}");
+        }
+        let unknown = placeholders
+            .into_iter()
+            .filter(|name| !required.contains(&name.as_str()))
+            .collect::<Vec<_>>();
+        if !unknown.is_empty() {
+            anyhow::bail!("prompt has unsupported placeholders: {}", unknown.join(", "));
+        }
+        return Ok(());
+    }
     match key {
         TemplateKey::Resume1pgBase | TemplateKey::Resume2pgBase => {
             let missing = required_resume_anchors()
@@ -262,6 +338,10 @@ pub fn load_templates_preview(repo_root: &Path) -> anyhow::Result<TemplatesPrevi
     let recruiter_path = repo_root.join("templates").join("messages").join("recruiter.md");
     let hiring_path = repo_root.join("templates").join("messages").join("hiring_manager.md");
     let cover_path = repo_root.join("templates").join("messages").join("cover_short.md");
+    let read_prompt = |task: LlmTask| {
+        let path = prompt_path(repo_root, task);
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+    };
 
     Ok(TemplatesPreview {
         resume_1pg_base: std::fs::read_to_string(&resume_1pg_path)
@@ -274,6 +354,13 @@ pub fn load_templates_preview(repo_root: &Path) -> anyhow::Result<TemplatesPrevi
             .with_context(|| format!("reading {}", hiring_path.display()))?,
         cover_short_template: std::fs::read_to_string(&cover_path)
             .with_context(|| format!("reading {}", cover_path.display()))?,
+        summarize_jd_prompt: read_prompt(LlmTask::SummarizeJd)?,
+        summarize_jd_repair_prompt: {
+            let path = named_prompt_path(repo_root, SUMMARIZE_JD_REPAIR_PROMPT);
+            std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?
+        },
+        rewrite_message_prompt: read_prompt(LlmTask::RewriteMessage)?,
+        rewrite_bullet_prompt: read_prompt(LlmTask::RewriteBullet)?,
     })
 }
 
@@ -327,8 +414,8 @@ mod tests {
     #[test]
     fn rejects_unknown_bullet_id() {
         let tmp = prepare_temp_repo();
-        let err =
-            set_bullet_approved_value(tmp.path(), "missing", true).expect_err("unknown bullet");
+        let err = set_bullet_approved_value(tmp.path(), "missing", true, false)
+            .expect_err("unknown bullet");
         assert!(err.to_string().contains("bullet not found"));
     }
 
@@ -349,6 +436,41 @@ mod tests {
         assert!(err.to_string().contains("required placeholders"));
     }
 
+    #[test]
+    fn prompt_template_requires_task_placeholder() {
+        let tmp = prepare_temp_repo();
+        let err = save_template_value(
+            tmp.path(),
+            &TemplateKey::RewriteBulletPrompt,
+            "Rewrite {{message}} concisely.",
+        )
+        .expect_err("wrong placeholder");
+        assert!(err.to_string().contains("required placeholder: bullet"));
+
+        save_template_value(
+            tmp.path(),
+            &TemplateKey::RewriteBulletPrompt,
+            "Tighten this bullet only.\n\n{{bullet}}",
+        )
+        .expect("valid prompt");
+        let preview = load_templates_preview(tmp.path()).expect("preview");
+        assert_eq!(preview.rewrite_bullet_prompt, "Tighten this bullet only.\n\n{{bullet}}\n");
+
+        let err = save_template_value(
+            tmp.path(),
+            &TemplateKey::SummarizeJdRepairPrompt,
+            "Fix {{previous_output}} ({{error}}) to match {{schema}}.",
+        )
+        .expect_err("repair prompt without the JD");
+        assert!(err.to_string().contains("required placeholder: jd_text"));
+        save_template_value(
+            tmp.path(),
+            &TemplateKey::SummarizeJdRepairPrompt,
+            "Fix {{previous_output}} ({{error}}) for {{jd_text}} to match {{schema}}.",
+        )
+        .expect("valid repair prompt");
+    }
+
     #[test]
     fn rejects_duplicate_skill_create() {
         let tmp = prepare_temp_repo();
@@ -371,6 +493,7 @@ mod tests {
                 tags: vec!["rollout".to_string()],
                 tools: vec!["Jira".to_string()],
                 approved: false,
+                create_pending_skills: false,
             },
         )
         .expect("create bullet");
@@ -379,4 +502,54 @@ mod tests {
         let banks = load_banks(tmp.path()).expect("banks");
         assert!(banks.bullets.iter().any(|b| b.id == "custom_new_001"));
     }
+
+    #[test]
+    fn bullet_tools_must_be_known_skills_once_approved() {
+        let tmp = prepare_temp_repo();
+        let input = |id: &str, approved: bool, create_pending_skills: bool| CreateBulletInput {
+            id: id.to_string(),
+            scope: "Custom".to_string(),
+            claim_level: "owned".to_string(),
+            text: "Ran the Nomad cluster behind Okta SSO.".to_string(),
+            seniority: "senior".to_string(),
+            category: vec!["ops".to_string()],
+            tags: vec!["platform".to_string()],
+            tools: vec!["Okta".to_string(), "Nomad".to_string()],
+            approved,
+            create_pending_skills,
+        };
+
+        let err = create_bullet_value(tmp.path(), input("nomad_001", true, false))
+            .expect_err("unknown tool on approved bullet");
+        assert!(err.to_string().contains("Nomad"));
+
+        let draft = create_bullet_value(tmp.path(), input("nomad_draft", false, false))
+            .expect("draft bullet");
+        assert_eq!(draft.warnings, vec!["tools not in skills bank: Nomad"]);
+        let err = set_bullet_approved_value(tmp.path(), "nomad_draft", true, false)
+            .expect_err("approving draft with unknown tool");
+        assert!(err.to_string().contains("Nomad"));
+        assert!(!load_banks(tmp.path()).expect("banks").skills.contains_key("Nomad"));
+
+        let resp = set_bullet_approved_value(tmp.path(), "nomad_draft", true, true)
+            .expect("approve with pending skills");
+        assert_eq!(
+            resp.warnings,
+            vec![
+                "created pending skills: Nomad",
+                "generation skips this bullet until these skills are approved: Nomad",
+            ]
+        );
+        let banks = load_banks(tmp.path()).expect("banks");
+        let nomad = &banks.skills["Nomad"];
+        assert!(!nomad.approved);
+        assert_eq!(nomad.level, crate::types::SkillLevel::Familiar);
+        assert!(banks.bullets.iter().any(|b| b.id == "nomad_draft" && b.approved));
+
+        let resp = set_skill_approved_value(tmp.path(), "Okta", false).expect("unapprove Okta");
+        assert!(resp.warnings[0].starts_with("generation skips these bullets until Okta"));
+        assert!(resp.warnings[0].contains("nomad_draft"));
+        let resp = set_skill_approved_value(tmp.path(), "Okta", true).expect("approve Okta");
+        assert!(resp.warnings.is_empty());
+    }
 }
@@ -1,8 +1,13 @@
+use crate::workflow::StatusWorkflow;
 use anyhow::{bail, Context};
-use chrono::Utc;
-use rusqlite::{params, Connection};
+use chrono::{NaiveDate, Utc};
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, TransactionBehavior};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
 
 const MIGRATIONS: &[(i64, &str)] = &[
     (
@@ -42,8 +47,140 @@ const MIGRATIONS: &[(i64, &str)] = &[
         CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status);
         ",
     ),
+    (
+        3,
+        "
+        CREATE INDEX IF NOT EXISTS idx_job_events_job ON job_events(job_id, created_at, id);
+        ",
+    ),
+    (
+        4,
+        "
+        ALTER TABLE jobs ADD COLUMN status_entered_at TEXT;
+        ALTER TABLE jobs ADD COLUMN closed_reason TEXT;
+        UPDATE jobs SET status_entered_at = updated_at WHERE status_entered_at IS NULL;
+
+        CREATE TABLE IF NOT EXISTS job_status_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            job_id TEXT NOT NULL,
+            status TEXT NOT NULL,
+            entered_at TEXT NOT NULL,
+            closed_reason TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_job_status_history_job ON job_status_history(job_id, id);
+        INSERT INTO job_status_history (job_id, status, entered_at)
+        SELECT id, status, status_entered_at FROM jobs;
+        ",
+    ),
+    (
+        5,
+        "
+        ALTER TABLE jobs ADD COLUMN next_action_due TEXT;
+        CREATE INDEX IF NOT EXISTS idx_jobs_next_action_due ON jobs(next_action_due);
+        ",
+    ),
+    (
+        6,
+        "
+        CREATE TABLE IF NOT EXISTS contacts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            job_id TEXT NOT NULL,
+            role TEXT NOT NULL,
+            name TEXT NOT NULL,
+            email TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_contacts_job ON contacts(job_id, id);
+
+        CREATE TABLE IF NOT EXISTS interviews (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            job_id TEXT NOT NULL,
+            scheduled_at TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            interviewers_json TEXT NOT NULL DEFAULT '[]',
+            outcome TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_interviews_job ON interviews(job_id, scheduled_at, id);
+        ",
+    ),
+    (
+        7,
+        "
+        CREATE VIRTUAL TABLE IF NOT EXISTS job_search USING fts5(
+            job_id UNINDEXED,
+            company,
+            role,
+            jd_text,
+            notes,
+            resume,
+            tokenize = 'porter unicode61'
+        );
+        INSERT INTO job_search (job_id, company, role, jd_text, notes, resume)
+        SELECT id, company, role, jd_text, COALESCE(notes, ''), '' FROM jobs;
+        CREATE TRIGGER IF NOT EXISTS jobs_search_insert AFTER INSERT ON jobs BEGIN
+            INSERT INTO job_search (job_id, company, role, jd_text, notes, resume)
+            VALUES (new.id, new.company, new.role, new.jd_text, COALESCE(new.notes, ''), '');
+        END;
+        CREATE TRIGGER IF NOT EXISTS jobs_search_update
+        AFTER UPDATE OF company, role, jd_text, notes ON jobs BEGIN
+            UPDATE job_search
+            SET company = new.company,
+                role = new.role,
+                jd_text = new.jd_text,
+                notes = COALESCE(new.notes, '')
+            WHERE job_id = new.id;
+        END;
+        CREATE TRIGGER IF NOT EXISTS jobs_search_delete AFTER DELETE ON jobs BEGIN
+            DELETE FROM job_search WHERE job_id = old.id;
+        END;
+        ",
+    ),
+    (
+        8,
+        "
+        CREATE INDEX IF NOT EXISTS idx_jobs_created_at ON jobs(created_at DESC);
+        CREATE INDEX IF NOT EXISTS idx_jobs_company ON jobs(company COLLATE NOCASE);
+        CREATE INDEX IF NOT EXISTS idx_jobs_fit_total ON jobs(fit_total);
+        ",
+    ),
+    (
+        9,
+        "
+        ALTER TABLE jobs ADD COLUMN match_key TEXT;
+        CREATE INDEX IF NOT EXISTS idx_jobs_jd_hash ON jobs(jd_hash);
+        CREATE INDEX IF NOT EXISTS idx_jobs_match_key ON jobs(match_key);
+        ",
+    ),
 ];
 
+const JOB_COLUMNS: &str = "id, company, role, source, baseline, track, fit_total, status, next_action, notes, output_dir, created_at, updated_at, status_entered_at, closed_reason, next_action_due";
+
+fn job_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<JobRecord> {
+    Ok(JobRecord {
+        id: row.get(0)?,
+        company: row.get(1)?,
+        role: row.get(2)?,
+        source: row.get(3)?,
+        baseline: row.get(4)?,
+        track: row.get(5)?,
+        fit_total: row.get(6)?,
+        status: row.get(7)?,
+        next_action: row.get(8)?,
+        notes: row.get(9)?,
+        output_dir: row.get(10)?,
+        created_at: row.get(11)?,
+        updated_at: row.get(12)?,
+        status_entered_at: row.get(13)?,
+        closed_reason: row.get(14)?,
+        next_action_due: row.get(15)?,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobRecord {
     pub id: String,
@@ -59,6 +196,128 @@ pub struct JobRecord {
     pub output_dir: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    pub status_entered_at: Option<String>,
+    pub closed_reason: Option<String>,
+    /// `YYYY-MM-DD` date the next action is due.
+    pub next_action_due: Option<String>,
+}
+
+/// One entry in a job's status history; re-entering a status adds another entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEntry {
+    pub status: String,
+    pub entered_at: String,
+    pub closed_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobEventType {
+    Generated,
+    Regenerated,
+    StatusChanged,
+    NextActionChanged,
+    NotesEdited,
+    Exported,
+    /// Restored or refreshed from a packet directory by `applykit db reindex`.
+    Reindexed,
+    /// Created from a spreadsheet row by `applykit tracker import`.
+    Imported,
+}
+
+impl JobEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Generated => "generated",
+            Self::Regenerated => "regenerated",
+            Self::StatusChanged => "status_changed",
+            Self::NextActionChanged => "next_action_changed",
+            Self::NotesEdited => "notes_edited",
+            Self::Exported => "exported",
+            Self::Reindexed => "reindexed",
+            Self::Imported => "imported",
+        }
+    }
+}
+
+impl std::str::FromStr for JobEventType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generated" => Ok(Self::Generated),
+            "regenerated" => Ok(Self::Regenerated),
+            "status_changed" => Ok(Self::StatusChanged),
+            "next_action_changed" => Ok(Self::NextActionChanged),
+            "notes_edited" => Ok(Self::NotesEdited),
+            "exported" => Ok(Self::Exported),
+            "reindexed" => Ok(Self::Reindexed),
+            "imported" => Ok(Self::Imported),
+            other => Err(format!("unknown job event type: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobEvent {
+    pub id: String,
+    pub job_id: String,
+    pub event_type: JobEventType,
+    pub payload: serde_json::Value,
+    pub created_at: String,
+}
+
+/// Appends an event using a per-job sequence id, so events sort stably within one timestamp.
+fn insert_job_event(
+    conn: &Connection,
+    job_id: &str,
+    event_type: JobEventType,
+    payload: &serde_json::Value,
+    created_at: &str,
+) -> anyhow::Result<()> {
+    // Ids are `<job id>#<sequence>`; continue after the highest so pruned events never
+    // free up an id. Callers hold an IMMEDIATE transaction, so the read cannot race.
+    let seq = conn.query_row(
+        "
+        SELECT COALESCE(MAX(CAST(substr(id, length(?1) + 2) AS INTEGER)), 0)
+        FROM job_events WHERE job_id = ?1
+        ",
+        params![job_id],
+        |row| row.get::<_, i64>(0),
+    )?;
+    conn.execute(
+        "
+        INSERT INTO job_events (id, job_id, event_type, payload_json, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ",
+        params![
+            format!("{job_id}#{:06}", seq + 1),
+            job_id,
+            event_type.as_str(),
+            payload.to_string(),
+            created_at
+        ],
+    )
+    .with_context(|| format!("recording {} event", event_type.as_str()))?;
+    Ok(())
+}
+
+fn insert_status_entry(
+    conn: &Connection,
+    job_id: &str,
+    status: &str,
+    closed_reason: Option<&str>,
+    entered_at: &str,
+) -> anyhow::Result<()> {
+    conn.execute(
+        "
+        INSERT INTO job_status_history (job_id, status, entered_at, closed_reason)
+        VALUES (?1, ?2, ?3, ?4)
+        ",
+        params![job_id, status, entered_at, closed_reason],
+    )
+    .context("recording status history")?;
+    Ok(())
 }
 
 pub struct UpsertJobRecordInput<'a> {
@@ -74,13 +333,67 @@ pub struct UpsertJobRecordInput<'a> {
     pub output_dir: Option<&'a str>,
 }
 
-pub fn init_db(db_path: &Path) -> anyhow::Result<()> {
-    if let Some(parent) = db_path.parent() {
-        std::fs::create_dir_all(parent)
-            .with_context(|| format!("creating {}", parent.display()))?;
+/// Schema version this build migrates databases to.
+pub fn latest_schema_version() -> i64 {
+    MIGRATIONS.last().map(|(version, _)| *version).unwrap_or(0)
+}
+
+/// How long a write waits for another connection (a CLI batch, a second window) to release
+/// its lock before failing with `SQLITE_BUSY`.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An open tracker database. Opening creates the file if needed, switches it to WAL mode with
+/// a busy timeout and applies pending migrations, so a `Store` is opened once and shared
+/// (it is `Sync`) rather than reopened for every query.
+pub struct Store {
+    path: PathBuf,
+    conn: Mutex<Connection>,
+}
+
+impl std::fmt::Debug for Store {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Store").field("path", &self.path).finish_non_exhaustive()
+    }
+}
+
+impl Store {
+    pub fn open(db_path: &Path) -> anyhow::Result<Self> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating {}", parent.display()))?;
+        }
+        let mut conn =
+            Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+        conn.busy_timeout(BUSY_TIMEOUT).context("setting busy timeout")?;
+        conn.pragma_update(None, "journal_mode", "WAL").context("enabling WAL mode")?;
+        // Our transactions read before they write; taking the write lock up front lets a
+        // contended writer wait out the busy timeout instead of failing on lock upgrade.
+        conn.set_transaction_behavior(TransactionBehavior::Immediate);
+        migrate(&conn)?;
+        Ok(Self { path: db_path.to_path_buf(), conn: Mutex::new(conn) })
+    }
+
+    /// Like [`Store::open`], but returns `None` instead of creating a missing database, for
+    /// read-only callers.
+    pub fn open_existing(db_path: &Path) -> anyhow::Result<Option<Self>> {
+        if !db_path.is_file() {
+            return Ok(None);
+        }
+        Self::open(db_path).map(Some)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The shared connection. A panic while it was held cannot leave SQLite mid-transaction
+    /// (uncommitted transactions roll back on drop), so a poisoned lock is still usable.
+    pub(crate) fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
     }
-    let conn =
-        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+}
+
+fn migrate(conn: &Connection) -> anyhow::Result<()> {
     conn.execute_batch(
         "
         CREATE TABLE IF NOT EXISTS schema_migrations (
@@ -111,22 +424,48 @@ pub fn init_db(db_path: &Path) -> anyhow::Result<()> {
         tx.commit().context("committing migration")?;
     }
 
-    Ok(())
+    backfill_match_keys(conn)
+}
+
+/// Fills `jobs.match_key` for rows written before migration 9 added it; the key is
+/// computed in Rust, so the migration itself cannot.
+fn backfill_match_keys(conn: &Connection) -> anyhow::Result<()> {
+    let rows = conn
+        .prepare("SELECT id, company, role FROM jobs WHERE match_key IS NULL")?
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let tx = conn.unchecked_transaction().context("starting match key backfill")?;
+    for (id, company, role) in rows {
+        tx.execute(
+            "UPDATE jobs SET match_key = ?2 WHERE id = ?1",
+            params![id, job_match_key(&company, &role)],
+        )?;
+    }
+    tx.commit().context("committing match key backfill")
 }
 
-pub fn upsert_job_record(db_path: &Path, input: UpsertJobRecordInput<'_>) -> anyhow::Result<()> {
-    init_db(db_path)?;
-    let conn =
-        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+pub fn upsert_job_record(store: &Store, input: UpsertJobRecordInput<'_>) -> anyhow::Result<()> {
+    let conn = store.conn();
     let now = Utc::now().to_rfc3339();
+    let tx = conn.unchecked_transaction().context("starting job upsert transaction")?;
+    let existed =
+        tx.query_row("SELECT COUNT(*) FROM jobs WHERE id = ?1", params![input.id], |row| {
+            row.get::<_, i64>(0)
+        })? > 0;
 
-    conn.execute(
+    tx.execute(
         "
-        INSERT INTO jobs (id, company, role, source, baseline, jd_text, jd_hash, track, fit_total, status, output_dir, created_at, updated_at)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 'new', ?10, ?11, ?11)
+        INSERT INTO jobs (id, company, role, source, baseline, jd_text, jd_hash, track, fit_total, status, output_dir, created_at, updated_at, status_entered_at, match_key)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 'new', ?10, ?11, ?11, ?11, ?12)
         ON CONFLICT(id) DO UPDATE SET
             company=excluded.company,
             role=excluded.role,
+            match_key=excluded.match_key,
             source=excluded.source,
             baseline=excluded.baseline,
             jd_text=excluded.jd_text,
@@ -147,134 +486,1135 @@ pub fn upsert_job_record(db_path: &Path, input: UpsertJobRecordInput<'_>) -> any
             input.track,
             input.fit_total,
             input.output_dir,
-            now
+            now,
+            job_match_key(input.company, input.role)
         ],
     )
     .context("upserting jobs row")?;
+    if !existed {
+        insert_status_entry(&tx, input.id, "new", None, &now)?;
+    }
+
+    insert_job_event(
+        &tx,
+        input.id,
+        if existed { JobEventType::Regenerated } else { JobEventType::Generated },
+        &serde_json::json!({
+            "output_dir": input.output_dir,
+            "track": input.track,
+            "fit_total": input.fit_total,
+            "jd_hash": input.jd_hash,
+        }),
+        &now,
+    )?;
+    tx.commit().context("committing job upsert")?;
 
     Ok(())
 }
 
-pub fn list_jobs(db_path: &Path) -> anyhow::Result<Vec<JobRecord>> {
-    if !db_path.exists() {
-        return Ok(Vec::new());
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReindexOutcome {
+    Inserted,
+    Updated,
+    Unchanged,
+}
+
+/// Restores a job from its packet without touching tracker fields (status, notes, contacts).
+/// New rows are dated `packet_date`; existing rows are updated only when the packet's output
+/// dir, track or fit score differ. Either change is logged as a `reindexed` event.
+pub fn reindex_job_record(
+    store: &Store,
+    input: UpsertJobRecordInput<'_>,
+    packet_date: NaiveDate,
+) -> anyhow::Result<ReindexOutcome> {
+    let conn = store.conn();
+    let tx = conn.unchecked_transaction().context("starting reindex transaction")?;
+    let existing = tx
+        .query_row(
+            "SELECT output_dir, track, fit_total FROM jobs WHERE id = ?1",
+            params![input.id],
+            |row| {
+                Ok((
+                    row.get::<_, Option<String>>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                ))
+            },
+        )
+        .optional()?;
+    let now = Utc::now().to_rfc3339();
+    let outcome = match existing {
+        None => {
+            let created_at = format!("{}T00:00:00+00:00", packet_date.format("%Y-%m-%d"));
+            tx.execute(
+                "
+                INSERT INTO jobs (id, company, role, source, baseline, jd_text, jd_hash, track, fit_total, status, output_dir, created_at, updated_at, status_entered_at, match_key)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 'new', ?10, ?11, ?11, ?11, ?12)
+                ",
+                params![
+                    input.id,
+                    input.company,
+                    input.role,
+                    input.source,
+                    input.baseline,
+                    input.jd_text,
+                    input.jd_hash,
+                    input.track,
+                    input.fit_total,
+                    input.output_dir,
+                    created_at,
+                    job_match_key(input.company, input.role)
+                ],
+            )
+            .context("restoring jobs row")?;
+            insert_status_entry(&tx, input.id, "new", None, &created_at)?;
+            ReindexOutcome::Inserted
+        }
+        Some((output_dir, track, fit_total))
+            if output_dir.as_deref() != input.output_dir
+                || track.as_deref() != input.track
+                || fit_total != input.fit_total =>
+        {
+            tx.execute(
+                "
+                UPDATE jobs
+                SET output_dir = ?2, track = ?3, fit_total = ?4, updated_at = ?5
+                WHERE id = ?1
+                ",
+                params![input.id, input.output_dir, input.track, input.fit_total, now],
+            )
+            .context("refreshing jobs row")?;
+            ReindexOutcome::Updated
+        }
+        Some(_) => return Ok(ReindexOutcome::Unchanged),
+    };
+    insert_job_event(
+        &tx,
+        input.id,
+        JobEventType::Reindexed,
+        &serde_json::json!({
+            "outcome": outcome,
+            "output_dir": input.output_dir,
+            "track": input.track,
+            "fit_total": input.fit_total,
+        }),
+        &now,
+    )?;
+    tx.commit().context("committing reindex")?;
+    Ok(outcome)
+}
+
+/// A tracker row from an external spreadsheet; there is no packet behind it.
+pub struct ImportedJob<'a> {
+    pub id: &'a str,
+    pub company: &'a str,
+    pub role: &'a str,
+    pub source: &'a str,
+    pub jd_text: &'a str,
+    pub jd_hash: &'a str,
+    pub track: Option<&'a str>,
+    pub fit_total: Option<i64>,
+    pub status: &'a str,
+    pub next_action: Option<&'a str>,
+    pub next_action_due: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    /// RFC 3339; also used as the status entry time.
+    pub created_at: &'a str,
+}
+
+/// Inserts an imported job as-is, without workflow checks. Returns `false` if the id exists.
+pub fn import_job_record(store: &Store, job: ImportedJob<'_>) -> anyhow::Result<bool> {
+    let conn = store.conn();
+    let tx = conn.unchecked_transaction().context("starting import transaction")?;
+    let inserted = tx
+        .execute(
+            "
+            INSERT OR IGNORE INTO jobs (id, company, role, source, baseline, jd_text, jd_hash, track, fit_total, status, next_action, next_action_due, notes, created_at, updated_at, status_entered_at, match_key)
+            VALUES (?1, ?2, ?3, ?4, '1pg', ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?13, ?13, ?14)
+            ",
+            params![
+                job.id,
+                job.company,
+                job.role,
+                job.source,
+                job.jd_text,
+                job.jd_hash,
+                job.track,
+                job.fit_total,
+                job.status,
+                job.next_action,
+                job.next_action_due,
+                job.notes,
+                job.created_at,
+                job_match_key(job.company, job.role)
+            ],
+        )
+        .context("importing jobs row")?
+        > 0;
+    if inserted {
+        insert_status_entry(&tx, job.id, job.status, None, job.created_at)?;
+        insert_job_event(
+            &tx,
+            job.id,
+            JobEventType::Imported,
+            &serde_json::json!({ "source": job.source, "status": job.status }),
+            &Utc::now().to_rfc3339(),
+        )?;
+    }
+    tx.commit().context("committing import")?;
+    Ok(inserted)
+}
+
+pub fn list_jobs(store: &Store) -> anyhow::Result<Vec<JobRecord>> {
+    Ok(query_jobs(store, &JobQuery::default())?.jobs)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobSortKey {
+    #[default]
+    UpdatedAt,
+    CreatedAt,
+    Company,
+    Role,
+    FitTotal,
+    Status,
+}
+
+impl JobSortKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UpdatedAt => "updated_at",
+            Self::CreatedAt => "created_at",
+            Self::Company => "company",
+            Self::Role => "role",
+            Self::FitTotal => "fit_total",
+            Self::Status => "status",
+        }
+    }
+
+    fn order_expr(&self) -> &'static str {
+        match self {
+            Self::UpdatedAt => "updated_at",
+            Self::CreatedAt => "created_at",
+            Self::Company => "company COLLATE NOCASE",
+            Self::Role => "role COLLATE NOCASE",
+            Self::FitTotal => "fit_total",
+            Self::Status => "status",
+        }
+    }
+}
+
+impl std::str::FromStr for JobSortKey {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "updated_at" | "updated" => Ok(Self::UpdatedAt),
+            "created_at" | "created" => Ok(Self::CreatedAt),
+            "company" => Ok(Self::Company),
+            "role" => Ok(Self::Role),
+            "fit_total" | "fit" => Ok(Self::FitTotal),
+            "status" => Ok(Self::Status),
+            other => Err(format!("unknown sort key: {other}")),
+        }
+    }
+}
+
+/// Filters, sort and page for [`query_jobs`]. The default lists every job, newest update first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobQuery {
+    /// Any of these statuses; empty means all.
+    pub statuses: Vec<String>,
+    pub track: Option<String>,
+    pub source: Option<String>,
+    /// Case-insensitive substring of the company name.
+    pub company: Option<String>,
+    pub fit_min: Option<i64>,
+    pub fit_max: Option<i64>,
+    /// Inclusive range over the day of the last update.
+    pub updated_from: Option<NaiveDate>,
+    pub updated_to: Option<NaiveDate>,
+    pub sort: JobSortKey,
+    pub ascending: bool,
+    /// `None` returns every match after `offset`.
+    pub limit: Option<usize>,
+    pub offset: usize,
+}
+
+impl JobQuery {
+    /// True when no filter narrows the result; sort and paging do not count.
+    pub fn is_unfiltered(&self) -> bool {
+        self.statuses.is_empty()
+            && self.track.is_none()
+            && self.source.is_none()
+            && self.company.is_none()
+            && self.fit_min.is_none()
+            && self.fit_max.is_none()
+            && self.updated_from.is_none()
+            && self.updated_to.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobPage {
+    pub jobs: Vec<JobRecord>,
+    /// Matches before paging.
+    pub total: usize,
+    pub offset: usize,
+    /// Offset of the following page, if any rows remain.
+    pub next_offset: Option<usize>,
+}
+
+fn like_pattern(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+/// Whether the tracker holds any job at all, regardless of filters.
+pub fn has_jobs(store: &Store) -> anyhow::Result<bool> {
+    let conn = store.conn();
+    Ok(conn.query_row("SELECT EXISTS(SELECT 1 FROM jobs)", [], |row| row.get(0))?)
+}
+
+/// Filtered, sorted and paged job listing; filtering happens in SQL so large trackers stay fast.
+pub fn query_jobs(store: &Store, query: &JobQuery) -> anyhow::Result<JobPage> {
+    let empty = JobPage { jobs: Vec::new(), total: 0, offset: query.offset, next_offset: None };
+    let conn = store.conn();
+
+    let mut clauses = Vec::new();
+    let mut values: Vec<Value> = Vec::new();
+    if !query.statuses.is_empty() {
+        let marks = vec!["?"; query.statuses.len()].join(", ");
+        clauses.push(format!("LOWER(status) IN ({marks})"));
+        values.extend(query.statuses.iter().map(|s| Value::Text(s.trim().to_ascii_lowercase())));
+    }
+    for (column, value) in [("track", &query.track), ("source", &query.source)] {
+        if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            clauses.push(format!("{column} = ? COLLATE NOCASE"));
+            values.push(Value::Text(value.to_string()));
+        }
+    }
+    if let Some(company) = query.company.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        clauses.push("company LIKE ? ESCAPE '\\'".to_string());
+        values.push(Value::Text(like_pattern(company)));
+    }
+    if let Some(min) = query.fit_min {
+        clauses.push("fit_total >= ?".to_string());
+        values.push(Value::Integer(min));
+    }
+    if let Some(max) = query.fit_max {
+        clauses.push("fit_total <= ?".to_string());
+        values.push(Value::Integer(max));
+    }
+    // Timestamps are RFC 3339 in UTC, so whole-day bounds compare lexically.
+    if let Some(from) = query.updated_from {
+        clauses.push("updated_at >= ?".to_string());
+        values.push(Value::Text(from.format("%Y-%m-%d").to_string()));
+    }
+    if let Some(to) = query.updated_to.and_then(|to| to.succ_opt()) {
+        clauses.push("updated_at < ?".to_string());
+        values.push(Value::Text(to.format("%Y-%m-%d").to_string()));
+    }
+    let where_sql =
+        if clauses.is_empty() { String::new() } else { format!("WHERE {}", clauses.join(" AND ")) };
+
+    let total = conn.query_row(
+        &format!("SELECT COUNT(*) FROM jobs {where_sql}"),
+        params_from_iter(values.iter()),
+        |row| row.get::<_, i64>(0),
+    )? as usize;
+    if total == 0 {
+        return Ok(JobPage { total, ..empty });
+    }
+
+    let direction = if query.ascending { "ASC" } else { "DESC" };
+    let limit = query.limit.map(|l| l as i64).unwrap_or(-1);
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {JOB_COLUMNS} FROM jobs {where_sql}
+         ORDER BY {} IS NULL, {} {direction}, id ASC
+         LIMIT ? OFFSET ?",
+        query.sort.as_str(),
+        query.sort.order_expr()
+    ))?;
+    values.push(Value::Integer(limit));
+    values.push(Value::Integer(query.offset as i64));
+    let jobs = stmt
+        .query_map(params_from_iter(values.iter()), job_from_row)?
+        .collect::<Result<Vec<_>, _>>()
+        .context("querying jobs")?;
+    let end = query.offset + jobs.len();
+    Ok(JobPage {
+        next_offset: (end < total && !jobs.is_empty()).then_some(end),
+        jobs,
+        total,
+        offset: query.offset,
+    })
+}
+
+pub fn get_job_by_id(store: &Store, id: &str) -> anyhow::Result<Option<JobRecord>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(&format!("SELECT {JOB_COLUMNS} FROM jobs WHERE id = ?1"))?;
+    Ok(stmt.query_row(params![id], job_from_row).optional()?)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateReason {
+    /// Identical job description text.
+    SameJd,
+    /// Same company and role after normalizing case and punctuation.
+    SameCompanyRole,
+}
+
+impl DuplicateReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SameJd => "same_jd",
+            Self::SameCompanyRole => "same_company_role",
+        }
     }
-    let conn =
-        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+}
+
+/// An existing job that looks like the one being generated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateJob {
+    pub job_id: String,
+    pub company: String,
+    pub role: String,
+    pub status: String,
+    pub created_at: String,
+    pub reason: DuplicateReason,
+}
+
+/// Lowercases and collapses punctuation and whitespace, so "Acme, Inc." matches "acme inc".
+fn normalize_match_key(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `jobs.match_key`: normalized company and role, so reposts under a slightly different
+/// name ("Acme, Inc." / "acme inc") are found by an indexed lookup.
+fn job_match_key(company: &str, role: &str) -> String {
+    format!("{}|{}", normalize_match_key(company), normalize_match_key(role))
+}
+
+/// Finds jobs with the same JD hash or normalized company+role, oldest first with JD matches
+/// ahead of company+role matches. `exclude_id` skips the job being regenerated.
+pub fn find_duplicate_jobs(
+    store: &Store,
+    jd_hash: &str,
+    company: &str,
+    role: &str,
+    exclude_id: Option<&str>,
+) -> anyhow::Result<Vec<DuplicateJob>> {
+    let conn = store.conn();
     let mut stmt = conn.prepare(
         "
-        SELECT id, company, role, source, baseline, track, fit_total, status, next_action, notes, output_dir, created_at, updated_at
+        SELECT id, company, role, status, created_at, jd_hash = ?1
         FROM jobs
-        ORDER BY updated_at DESC, id ASC
+        WHERE (jd_hash = ?1 OR match_key = ?2) AND (?3 IS NULL OR id <> ?3)
+        ORDER BY jd_hash <> ?1, created_at, id
         ",
     )?;
+    let rows =
+        stmt.query_map(params![jd_hash, job_match_key(company, role), exclude_id], |row| {
+            Ok(DuplicateJob {
+                job_id: row.get(0)?,
+                company: row.get(1)?,
+                role: row.get(2)?,
+                status: row.get(3)?,
+                created_at: row.get(4)?,
+                reason: if row.get::<_, bool>(5)? {
+                    DuplicateReason::SameJd
+                } else {
+                    DuplicateReason::SameCompanyRole
+                },
+            })
+        })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
 
-    let rows = stmt.query_map([], |row| {
-        Ok(JobRecord {
-            id: row.get(0)?,
-            company: row.get(1)?,
-            role: row.get(2)?,
-            source: row.get(3)?,
-            baseline: row.get(4)?,
-            track: row.get(5)?,
-            fit_total: row.get(6)?,
-            status: row.get(7)?,
-            next_action: row.get(8)?,
-            notes: row.get(9)?,
-            output_dir: row.get(10)?,
-            created_at: row.get(11)?,
-            updated_at: row.get(12)?,
-        })
-    })?;
+/// A ranked full-text match; `snippet` marks matched terms with `[` and `]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub job_id: String,
+    pub company: String,
+    pub role: String,
+    pub status: String,
+    /// Higher is more relevant (negated BM25).
+    pub score: f64,
+    pub snippet: String,
+}
 
-    let mut out = Vec::new();
-    for row in rows {
-        out.push(row?);
-    }
-    Ok(out)
+/// Stores the generated resume text searched alongside the job's own columns.
+pub fn index_job_resume(store: &Store, job_id: &str, resume_text: &str) -> anyhow::Result<()> {
+    let conn = store.conn();
+    conn.execute(
+        "UPDATE job_search SET resume = ?2 WHERE job_id = ?1",
+        params![job_id, resume_text],
+    )
+    .context("indexing resume text")?;
+    Ok(())
 }
 
-pub fn get_job_by_id(db_path: &Path, id: &str) -> anyhow::Result<Option<JobRecord>> {
-    if !db_path.exists() {
-        return Ok(None);
-    }
-    let conn =
-        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+/// Turns free text into an FTS5 query: every word must match, each as a prefix, so
+/// punctuation such as `c++` or `"` in user input cannot produce a syntax error.
+fn fts_query(query: &str) -> Option<String> {
+    let terms = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{term}\"*"))
+        .collect::<Vec<_>>();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Searches company, role, JD text, notes and generated resumes, best matches first.
+/// Company and role hits outrank body text.
+pub fn search_jobs(store: &Store, query: &str, limit: usize) -> anyhow::Result<Vec<SearchHit>> {
+    let Some(match_query) = fts_query(query) else {
+        bail!("search query must not be empty");
+    };
+    let conn = store.conn();
     let mut stmt = conn.prepare(
         "
-        SELECT id, company, role, source, baseline, track, fit_total, status, next_action, notes, output_dir, created_at, updated_at
-        FROM jobs
-        WHERE id = ?1
+        SELECT jobs.id, jobs.company, jobs.role, jobs.status,
+               -bm25(job_search, 0.0, 10.0, 8.0, 1.0, 2.0, 1.0) AS score,
+               snippet(job_search, -1, '[', ']', '...', 12)
+        FROM job_search
+        JOIN jobs ON jobs.id = job_search.job_id
+        WHERE job_search MATCH ?1
+        ORDER BY score DESC, jobs.updated_at DESC, jobs.id
+        LIMIT ?2
         ",
     )?;
+    let hits = stmt
+        .query_map(params![match_query, limit as i64], |row| {
+            Ok(SearchHit {
+                job_id: row.get(0)?,
+                company: row.get(1)?,
+                role: row.get(2)?,
+                status: row.get(3)?,
+                score: row.get(4)?,
+                snippet: row.get(5)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .context("searching jobs")?;
+    Ok(hits)
+}
 
-    let mut rows = stmt.query(params![id])?;
-    if let Some(row) = rows.next()? {
-        return Ok(Some(JobRecord {
-            id: row.get(0)?,
-            company: row.get(1)?,
-            role: row.get(2)?,
-            source: row.get(3)?,
-            baseline: row.get(4)?,
-            track: row.get(5)?,
-            fit_total: row.get(6)?,
-            status: row.get(7)?,
-            next_action: row.get(8)?,
-            notes: row.get(9)?,
-            output_dir: row.get(10)?,
-            created_at: row.get(11)?,
-            updated_at: row.get(12)?,
-        }));
-    }
-    Ok(None)
+pub struct JobStatusUpdate<'a> {
+    pub id: &'a str,
+    pub status: &'a str,
+    pub next_action: Option<&'a str>,
+    /// `YYYY-MM-DD`; `None` clears the due date.
+    pub next_action_due: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    /// Stored only when `status` is one of the workflow's closed statuses.
+    pub closed_reason: Option<&'a str>,
 }
 
+/// Applies a tracker edit, rejecting unknown statuses and moves the workflow does not allow.
 pub fn update_job_status(
-    db_path: &Path,
-    id: &str,
-    status: &str,
-    next_action: Option<&str>,
-    notes: Option<&str>,
+    store: &Store,
+    workflow: &StatusWorkflow,
+    update: JobStatusUpdate<'_>,
 ) -> anyhow::Result<()> {
-    let status_lower = status.to_ascii_lowercase();
-    let allowed = ["new", "applied", "reply", "interview", "closed"];
-    if !allowed.iter().any(|s| *s == status_lower) {
-        bail!("invalid status: {status}");
+    let id = update.id;
+    let status_lower = update.status.to_ascii_lowercase();
+    if !workflow.is_known(&status_lower) {
+        bail!("invalid status: {}", update.status);
+    }
+    let (next_action, notes) = (update.next_action, update.notes);
+    let next_action_due = update.next_action_due.map(str::trim).filter(|d| !d.is_empty());
+    if let Some(due) = next_action_due {
+        NaiveDate::parse_from_str(due, "%Y-%m-%d")
+            .with_context(|| format!("invalid due date: {due} (expected YYYY-MM-DD)"))?;
     }
+    let closed_reason = if workflow.is_closed(&status_lower) {
+        update.closed_reason.map(str::trim).filter(|r| !r.is_empty())
+    } else {
+        None
+    };
 
-    init_db(db_path)?;
-    let conn =
-        Connection::open(db_path).with_context(|| format!("opening {}", db_path.display()))?;
+    let conn = store.conn();
     let now = Utc::now().to_rfc3339();
-    let updated = conn
+    let tx = conn.unchecked_transaction().context("starting status update transaction")?;
+    let previous = tx
+        .query_row(
+            "SELECT status, next_action, notes, closed_reason, next_action_due FROM jobs WHERE id = ?1",
+            params![id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                ))
+            },
+        )
+        .optional()?;
+    let Some((prev_status, prev_next_action, prev_notes, prev_closed_reason, prev_due)) = previous
+    else {
+        bail!("job not found: {id}");
+    };
+    workflow.check_transition(&prev_status, &status_lower)?;
+    let status_changed = prev_status != status_lower;
+    // Saving a closed job without a reason keeps the one captured when it was closed.
+    let closed_reason = if status_changed {
+        closed_reason.map(str::to_string)
+    } else {
+        closed_reason.map(str::to_string).or(prev_closed_reason)
+    };
+
+    let updated = tx
         .execute(
             "
         UPDATE jobs
         SET status = ?2,
             next_action = ?3,
             notes = ?4,
-            updated_at = ?5
+            updated_at = ?5,
+            status_entered_at = CASE WHEN ?6 THEN ?5 ELSE status_entered_at END,
+            closed_reason = ?7,
+            next_action_due = ?8
         WHERE id = ?1
         ",
-            params![id, status_lower, next_action, notes, now],
+            params![
+                id,
+                status_lower,
+                next_action,
+                notes,
+                now,
+                status_changed,
+                closed_reason,
+                next_action_due
+            ],
         )
         .context("updating job status")?;
     if updated == 0 {
         bail!("job not found: {id}");
     }
+
+    if status_changed {
+        insert_status_entry(&tx, id, &status_lower, closed_reason.as_deref(), &now)?;
+        let mut payload = serde_json::json!({ "from": prev_status, "to": status_lower });
+        if let Some(reason) = &closed_reason {
+            payload["closed_reason"] = serde_json::json!(reason);
+        }
+        insert_job_event(&tx, id, JobEventType::StatusChanged, &payload, &now)?;
+    }
+    if prev_next_action.as_deref() != next_action || prev_due.as_deref() != next_action_due {
+        insert_job_event(
+            &tx,
+            id,
+            JobEventType::NextActionChanged,
+            &serde_json::json!({
+                "from": prev_next_action,
+                "to": next_action,
+                "due_from": prev_due,
+                "due_to": next_action_due,
+            }),
+            &now,
+        )?;
+    }
+    if prev_notes.as_deref() != notes {
+        insert_job_event(
+            &tx,
+            id,
+            JobEventType::NotesEdited,
+            &serde_json::json!({ "from": prev_notes, "to": notes }),
+            &now,
+        )?;
+    }
+    tx.commit().context("committing status update")?;
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Returns every status a job has entered, oldest first.
+pub fn list_status_history(store: &Store, job_id: &str) -> anyhow::Result<Vec<StatusEntry>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(
+        "
+        SELECT status, entered_at, closed_reason
+        FROM job_status_history
+        WHERE job_id = ?1
+        ORDER BY id ASC
+        ",
+    )?;
+    let rows = stmt.query_map(params![job_id], |row| {
+        Ok(StatusEntry { status: row.get(0)?, entered_at: row.get(1)?, closed_reason: row.get(2)? })
+    })?;
 
-    #[test]
-    fn migrations_apply_and_version_rows_exist() {
-        let dir = tempfile::tempdir().expect("temp");
-        let db_path = dir.path().join("applykit.db");
-        init_db(&db_path).expect("init");
-        init_db(&db_path).expect("re-init");
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row?);
+    }
+    Ok(out)
+}
+
+/// Returns the status history of every job, keyed by job id, each oldest first.
+pub fn list_all_status_history(
+    store: &Store,
+) -> anyhow::Result<BTreeMap<String, Vec<StatusEntry>>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(
+        "
+        SELECT job_id, status, entered_at, closed_reason
+        FROM job_status_history
+        ORDER BY job_id ASC, id ASC
+        ",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            StatusEntry {
+                status: row.get(1)?,
+                entered_at: row.get(2)?,
+                closed_reason: row.get(3)?,
+            },
+        ))
+    })?;
+
+    let mut out = BTreeMap::<String, Vec<StatusEntry>>::new();
+    for row in rows {
+        let (job_id, entry) = row?;
+        out.entry(job_id).or_default().push(entry);
+    }
+    Ok(out)
+}
+
+/// Records an export of the packet in `packet_dir` against the job that produced it.
+/// Packets from earlier runs of a regenerated job are matched through the job's
+/// generation events. Returns `false` when no tracked job owns the packet (e.g. packets
+/// made before tracking).
+pub fn record_export_event(
+    store: &Store,
+    packet_dir: &Path,
+    format: &str,
+    output_path: &Path,
+) -> anyhow::Result<bool> {
+    let given = packet_dir.to_string_lossy().to_string();
+    let canonical = packet_dir
+        .canonicalize()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .unwrap_or_else(|_| given.clone());
+
+    let conn = store.conn();
+    let current = conn
+        .query_row(
+            "SELECT id FROM jobs WHERE output_dir IN (?1, ?2) ORDER BY updated_at DESC LIMIT 1",
+            params![given, canonical],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()?;
+    let job_id = match current {
+        Some(job_id) => Some(job_id),
+        None => conn
+            .query_row(
+                "
+                SELECT job_id FROM job_events
+                WHERE event_type IN ('generated', 'regenerated')
+                  AND json_extract(payload_json, '$.output_dir') IN (?1, ?2)
+                ORDER BY created_at DESC, id DESC
+                LIMIT 1
+                ",
+                params![given, canonical],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?,
+    };
+    let Some(job_id) = job_id else {
+        return Ok(false);
+    };
+
+    let tx = conn.unchecked_transaction().context("starting export event transaction")?;
+    insert_job_event(
+        &tx,
+        &job_id,
+        JobEventType::Exported,
+        &serde_json::json!({ "format": format, "output_path": output_path.display().to_string() }),
+        &Utc::now().to_rfc3339(),
+    )?;
+    tx.commit().context("committing export event")?;
+    Ok(true)
+}
+
+/// Returns a job's events oldest first.
+pub fn list_job_events(store: &Store, job_id: &str) -> anyhow::Result<Vec<JobEvent>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(
+        "
+        SELECT id, job_id, event_type, payload_json, created_at
+        FROM job_events
+        WHERE job_id = ?1
+        ORDER BY created_at ASC, id ASC
+        ",
+    )?;
+    let rows = stmt.query_map(params![job_id], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, String>(4)?,
+        ))
+    })?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        let (id, job_id, event_type, payload_json, created_at) = row?;
+        out.push(JobEvent {
+            event_type: event_type.parse().map_err(anyhow::Error::msg)?,
+            payload: serde_json::from_str(&payload_json)
+                .with_context(|| format!("parsing payload of event {id}"))?,
+            id,
+            job_id,
+            created_at,
+        });
+    }
+    Ok(out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactRole {
+    Recruiter,
+    HiringManager,
+    Referrer,
+}
+
+impl ContactRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Recruiter => "recruiter",
+            Self::HiringManager => "hiring_manager",
+            Self::Referrer => "referrer",
+        }
+    }
+}
+
+impl std::str::FromStr for ContactRole {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+            "recruiter" => Ok(Self::Recruiter),
+            "hiring_manager" => Ok(Self::HiringManager),
+            "referrer" => Ok(Self::Referrer),
+            _ => Err(format!("unknown contact role: {value}")),
+        }
+    }
+}
+
+impl rusqlite::types::FromSql for ContactRole {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map_err(|err: String| rusqlite::types::FromSqlError::Other(err.into()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: i64,
+    pub job_id: String,
+    pub role: ContactRole,
+    pub name: String,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub struct ContactInput<'a> {
+    pub job_id: &'a str,
+    pub role: ContactRole,
+    pub name: &'a str,
+    pub email: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interview {
+    pub id: i64,
+    pub job_id: String,
+    /// `YYYY-MM-DD`, optionally followed by a time.
+    pub scheduled_at: String,
+    /// Round type, e.g. `phone_screen`, `technical`, `onsite`.
+    pub kind: String,
+    pub interviewers: Vec<String>,
+    pub outcome: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub struct InterviewInput<'a> {
+    pub job_id: &'a str,
+    pub scheduled_at: &'a str,
+    pub kind: &'a str,
+    pub interviewers: &'a [String],
+    pub outcome: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+fn ensure_job_exists(conn: &Connection, job_id: &str) -> anyhow::Result<()> {
+    let exists =
+        conn.query_row("SELECT COUNT(*) FROM jobs WHERE id = ?1", params![job_id], |row| {
+            row.get::<_, i64>(0)
+        })? > 0;
+    if !exists {
+        bail!("job not found: {job_id}");
+    }
+    Ok(())
+}
+
+fn required_text<'a>(value: &'a str, field: &str) -> anyhow::Result<&'a str> {
+    let value = value.trim();
+    if value.is_empty() {
+        bail!("{field} must not be empty");
+    }
+    Ok(value)
+}
+
+const CONTACT_COLUMNS: &str = "id, job_id, role, name, email, notes, created_at, updated_at";
+
+fn contact_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Contact> {
+    Ok(Contact {
+        id: row.get(0)?,
+        job_id: row.get(1)?,
+        role: row.get(2)?,
+        name: row.get(3)?,
+        email: row.get(4)?,
+        notes: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+fn get_contact(conn: &Connection, id: i64) -> anyhow::Result<Option<Contact>> {
+    conn.query_row(
+        &format!("SELECT {CONTACT_COLUMNS} FROM contacts WHERE id = ?1"),
+        params![id],
+        contact_from_row,
+    )
+    .optional()
+    .context("reading contact")
+}
+
+pub fn add_contact(store: &Store, input: ContactInput<'_>) -> anyhow::Result<Contact> {
+    let name = required_text(input.name, "contact name")?;
+    let conn = store.conn();
+    ensure_job_exists(&conn, input.job_id)?;
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "
+        INSERT INTO contacts (job_id, role, name, email, notes, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
+        ",
+        params![input.job_id, input.role.as_str(), name, input.email, input.notes, now],
+    )
+    .context("inserting contact")?;
+    get_contact(&conn, conn.last_insert_rowid())?.context("reading inserted contact")
+}
+
+/// Replaces every field of contact `id`, which must belong to `input.job_id`.
+pub fn update_contact(store: &Store, id: i64, input: ContactInput<'_>) -> anyhow::Result<Contact> {
+    let name = required_text(input.name, "contact name")?;
+    let conn = store.conn();
+    let updated = conn
+        .execute(
+            "
+            UPDATE contacts
+            SET role = ?3, name = ?4, email = ?5, notes = ?6, updated_at = ?7
+            WHERE id = ?1 AND job_id = ?2
+            ",
+            params![
+                id,
+                input.job_id,
+                input.role.as_str(),
+                name,
+                input.email,
+                input.notes,
+                Utc::now().to_rfc3339()
+            ],
+        )
+        .context("updating contact")?;
+    if updated == 0 {
+        bail!("contact not found: {id}");
+    }
+    get_contact(&conn, id)?.with_context(|| format!("contact not found: {id}"))
+}
+
+/// Returns `false` when no contact has that id.
+pub fn delete_contact(store: &Store, id: i64) -> anyhow::Result<bool> {
+    let conn = store.conn();
+    Ok(conn.execute("DELETE FROM contacts WHERE id = ?1", params![id])? > 0)
+}
+
+/// Returns a job's contacts in the order they were added.
+pub fn list_contacts(store: &Store, job_id: &str) -> anyhow::Result<Vec<Contact>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {CONTACT_COLUMNS} FROM contacts WHERE job_id = ?1 ORDER BY id ASC"
+    ))?;
+    let rows = stmt.query_map(params![job_id], contact_from_row)?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row?);
+    }
+    Ok(out)
+}
+
+const INTERVIEW_COLUMNS: &str =
+    "id, job_id, scheduled_at, kind, interviewers_json, outcome, notes, created_at, updated_at";
+
+fn interview_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Interview> {
+    let interviewers_json = row.get::<_, String>(4)?;
+    Ok(Interview {
+        id: row.get(0)?,
+        job_id: row.get(1)?,
+        scheduled_at: row.get(2)?,
+        kind: row.get(3)?,
+        interviewers: serde_json::from_str(&interviewers_json).map_err(|err| {
+            rusqlite::Error::FromSqlConversionFailure(4, rusqlite::types::Type::Text, Box::new(err))
+        })?,
+        outcome: row.get(5)?,
+        notes: row.get(6)?,
+        created_at: row.get(7)?,
+        updated_at: row.get(8)?,
+    })
+}
+
+fn get_interview(conn: &Connection, id: i64) -> anyhow::Result<Option<Interview>> {
+    conn.query_row(
+        &format!("SELECT {INTERVIEW_COLUMNS} FROM interviews WHERE id = ?1"),
+        params![id],
+        interview_from_row,
+    )
+    .optional()
+    .context("reading interview")
+}
+
+/// Validates the round and returns its trimmed kind and interviewer list as JSON.
+fn interview_fields(input: &InterviewInput<'_>) -> anyhow::Result<(String, String)> {
+    let date = input.scheduled_at.trim().get(..10).unwrap_or_default();
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").with_context(|| {
+        format!("invalid interview date: {} (expected YYYY-MM-DD[ HH:MM])", input.scheduled_at)
+    })?;
+    let kind = required_text(input.kind, "interview type")?.to_string();
+    let interviewers = input
+        .interviewers
+        .iter()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+    Ok((kind, serde_json::to_string(&interviewers)?))
+}
+
+pub fn add_interview(store: &Store, input: InterviewInput<'_>) -> anyhow::Result<Interview> {
+    let (kind, interviewers_json) = interview_fields(&input)?;
+    let conn = store.conn();
+    ensure_job_exists(&conn, input.job_id)?;
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "
+        INSERT INTO interviews (job_id, scheduled_at, kind, interviewers_json, outcome, notes, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+        ",
+        params![
+            input.job_id,
+            input.scheduled_at.trim(),
+            kind,
+            interviewers_json,
+            input.outcome,
+            input.notes,
+            now
+        ],
+    )
+    .context("inserting interview")?;
+    get_interview(&conn, conn.last_insert_rowid())?.context("reading inserted interview")
+}
+
+/// Replaces every field of interview `id`, which must belong to `input.job_id`.
+pub fn update_interview(
+    store: &Store,
+    id: i64,
+    input: InterviewInput<'_>,
+) -> anyhow::Result<Interview> {
+    let (kind, interviewers_json) = interview_fields(&input)?;
+    let conn = store.conn();
+    let updated = conn
+        .execute(
+            "
+            UPDATE interviews
+            SET scheduled_at = ?3, kind = ?4, interviewers_json = ?5, outcome = ?6, notes = ?7, updated_at = ?8
+            WHERE id = ?1 AND job_id = ?2
+            ",
+            params![
+                id,
+                input.job_id,
+                input.scheduled_at.trim(),
+                kind,
+                interviewers_json,
+                input.outcome,
+                input.notes,
+                Utc::now().to_rfc3339()
+            ],
+        )
+        .context("updating interview")?;
+    if updated == 0 {
+        bail!("interview not found: {id}");
+    }
+    get_interview(&conn, id)?.with_context(|| format!("interview not found: {id}"))
+}
+
+/// Returns `false` when no interview has that id.
+pub fn delete_interview(store: &Store, id: i64) -> anyhow::Result<bool> {
+    let conn = store.conn();
+    Ok(conn.execute("DELETE FROM interviews WHERE id = ?1", params![id])? > 0)
+}
+
+/// Returns a job's interview rounds by scheduled date.
+pub fn list_interviews(store: &Store, job_id: &str) -> anyhow::Result<Vec<Interview>> {
+    let conn = store.conn();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {INTERVIEW_COLUMNS} FROM interviews WHERE job_id = ?1 ORDER BY scheduled_at ASC, id ASC"
+    ))?;
+    let rows = stmt.query_map(params![job_id], interview_from_row)?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrations_apply_and_version_rows_exist() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        assert!(Store::open_existing(&db_path).expect("probe").is_none());
+        assert!(!db_path.exists());
+        Store::open(&db_path).expect("open");
+        Store::open(&db_path).expect("re-open");
+        assert!(Store::open_existing(&db_path).expect("existing").is_some());
 
         let conn = Connection::open(&db_path).expect("open");
         let count: i64 = conn
@@ -283,12 +1623,79 @@ mod tests {
         assert!(count >= 2);
     }
 
+    #[test]
+    fn store_uses_wal_and_serves_concurrent_writers() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = std::sync::Arc::new(Store::open(&db_path).expect("store"));
+        let mode: String = store
+            .conn()
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .expect("journal mode");
+        assert_eq!(mode, "wal");
+
+        // Threads share one handle while a second handle (another window or CLI run) writes
+        // to the same file; the busy timeout makes them wait instead of failing.
+        let other = Store::open(&db_path).expect("second store");
+        let handles = (0..4)
+            .map(|worker| {
+                let store = std::sync::Arc::clone(&store);
+                std::thread::spawn(move || {
+                    for n in 0..10 {
+                        let id = format!("job-{worker}-{n}");
+                        upsert_job_record(
+                            &store,
+                            UpsertJobRecordInput {
+                                id: &id,
+                                company: "Acme",
+                                role: "Role",
+                                source: "manual",
+                                baseline: "1pg",
+                                jd_text: "jd",
+                                jd_hash: &id,
+                                track: None,
+                                fit_total: None,
+                                output_dir: None,
+                            },
+                        )
+                        .expect("upsert");
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for n in 0..10 {
+            let id = format!("other-{n}");
+            upsert_job_record(
+                &other,
+                UpsertJobRecordInput {
+                    id: &id,
+                    company: "Globex",
+                    role: "Role",
+                    source: "manual",
+                    baseline: "1pg",
+                    jd_text: "jd",
+                    jd_hash: &id,
+                    track: None,
+                    fit_total: None,
+                    output_dir: None,
+                },
+            )
+            .expect("upsert from second handle");
+        }
+        for handle in handles {
+            handle.join().expect("worker");
+        }
+        assert_eq!(list_jobs(&store).expect("jobs").len(), 50);
+        assert_eq!(list_jobs(&other).expect("jobs").len(), 50);
+    }
+
     #[test]
     fn upsert_and_update_status_round_trip() {
         let dir = tempfile::tempdir().expect("temp");
         let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
         upsert_job_record(
-            &db_path,
+            &store,
             UpsertJobRecordInput {
                 id: "job-1",
                 company: "Acme",
@@ -304,10 +1711,21 @@ mod tests {
         )
         .expect("upsert");
 
-        update_job_status(&db_path, "job-1", "reply", Some("send follow-up"), Some("note"))
-            .expect("update");
+        update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "job-1",
+                status: "reply",
+                next_action: Some("send follow-up"),
+                next_action_due: None,
+                notes: Some("note"),
+                closed_reason: None,
+            },
+        )
+        .expect("update");
 
-        let jobs = list_jobs(&db_path).expect("list");
+        let jobs = list_jobs(&store).expect("list");
         assert_eq!(jobs.len(), 1);
         assert_eq!(jobs[0].status, "reply");
         assert_eq!(jobs[0].next_action.as_deref(), Some("send follow-up"));
@@ -318,8 +1736,9 @@ mod tests {
     fn update_status_fails_for_unknown_job() {
         let dir = tempfile::tempdir().expect("temp");
         let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
         upsert_job_record(
-            &db_path,
+            &store,
             UpsertJobRecordInput {
                 id: "job-1",
                 company: "Acme",
@@ -335,7 +1754,19 @@ mod tests {
         )
         .expect("upsert");
 
-        let err = update_job_status(&db_path, "missing", "reply", None, None).expect_err("missing");
+        let err = update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "missing",
+                status: "reply",
+                next_action: None,
+                next_action_due: None,
+                notes: None,
+                closed_reason: None,
+            },
+        )
+        .expect_err("missing");
         assert!(err.to_string().contains("job not found"));
     }
 
@@ -343,8 +1774,9 @@ mod tests {
     fn update_status_rejects_invalid_value() {
         let dir = tempfile::tempdir().expect("temp");
         let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
         upsert_job_record(
-            &db_path,
+            &store,
             UpsertJobRecordInput {
                 id: "job-1",
                 company: "Acme",
@@ -360,7 +1792,722 @@ mod tests {
         )
         .expect("upsert");
 
-        let err = update_job_status(&db_path, "job-1", "foo", None, None).expect_err("invalid");
+        let err = update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "job-1",
+                status: "foo",
+                next_action: None,
+                next_action_due: None,
+                notes: None,
+                closed_reason: None,
+            },
+        )
+        .expect_err("invalid");
         assert!(err.to_string().contains("invalid status"));
     }
+
+    #[test]
+    fn job_timeline_records_generation_status_notes_and_export() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        let packet_dir = dir.path().join("packet");
+        std::fs::create_dir_all(&packet_dir).expect("packet dir");
+        let packet_dir_text = packet_dir.display().to_string();
+        let input = || UpsertJobRecordInput {
+            id: "job-1",
+            company: "Acme",
+            role: "Role",
+            source: "manual",
+            baseline: "1pg",
+            jd_text: "jd",
+            jd_hash: "hash",
+            track: Some("Support/Ops Core"),
+            fit_total: Some(60),
+            output_dir: Some(&packet_dir_text),
+        };
+        upsert_job_record(&store, input()).expect("generate");
+        upsert_job_record(&store, input()).expect("regenerate");
+        update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "job-1",
+                status: "applied",
+                next_action: None,
+                next_action_due: None,
+                notes: None,
+                closed_reason: None,
+            },
+        )
+        .expect("status");
+        update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "job-1",
+                status: "applied",
+                next_action: None,
+                next_action_due: None,
+                notes: Some("called recruiter"),
+                closed_reason: None,
+            },
+        )
+        .expect("notes");
+        assert!(record_export_event(&store, &packet_dir, "pdf", &dir.path().join("p.pdf"))
+            .expect("export"));
+        let newer_dir = dir.path().join("packet-2");
+        let newer_dir_text = newer_dir.display().to_string();
+        upsert_job_record(
+            &store,
+            UpsertJobRecordInput { output_dir: Some(&newer_dir_text), ..input() },
+        )
+        .expect("regenerate into a new packet");
+        assert!(record_export_event(&store, &packet_dir, "docx", &dir.path().join("p.docx"))
+            .expect("export earlier packet"));
+        assert!(!record_export_event(
+            &store,
+            &dir.path().join("untracked"),
+            "pdf",
+            &dir.path().join("u.pdf")
+        )
+        .expect("untracked export"));
+
+        let events = list_job_events(&store, "job-1").expect("timeline");
+        let types = events.iter().map(|e| e.event_type).collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                JobEventType::Generated,
+                JobEventType::Regenerated,
+                JobEventType::StatusChanged,
+                JobEventType::NotesEdited,
+                JobEventType::Exported,
+                JobEventType::Regenerated,
+                JobEventType::Exported,
+            ]
+        );
+        assert_eq!(events[2].payload, serde_json::json!({ "from": "new", "to": "applied" }));
+        assert_eq!(events[3].payload["to"], "called recruiter");
+        assert_eq!(events[4].payload["format"], "pdf");
+        assert_eq!(events[6].payload["format"], "docx");
+
+        // Pruning an event must not hand its successor's id to the next insert.
+        store
+            .conn()
+            .execute("DELETE FROM job_events WHERE id = ?1", params![events[2].id])
+            .expect("prune");
+        assert!(record_export_event(&store, &packet_dir, "md", &dir.path().join("p.zip"))
+            .expect("export after prune"));
+        let events = list_job_events(&store, "job-1").expect("timeline");
+        assert_eq!(events.len(), 7);
+        assert_eq!(events[6].id, "job-1#000008");
+        assert!(list_job_events(&store, "missing").expect("empty").is_empty());
+    }
+
+    #[test]
+    fn status_workflow_enforces_transitions_and_records_history() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        let workflow = StatusWorkflow::default();
+        upsert_job_record(
+            &store,
+            UpsertJobRecordInput {
+                id: "job-1",
+                company: "Acme",
+                role: "Role",
+                source: "manual",
+                baseline: "1pg",
+                jd_text: "jd",
+                jd_hash: "hash",
+                track: Some("Support/Ops Core"),
+                fit_total: Some(60),
+                output_dir: Some("/tmp/packet"),
+            },
+        )
+        .expect("upsert");
+        let update = |status, closed_reason| {
+            update_job_status(
+                &store,
+                &workflow,
+                JobStatusUpdate {
+                    id: "job-1",
+                    status,
+                    next_action: None,
+                    next_action_due: None,
+                    notes: None,
+                    closed_reason,
+                },
+            )
+        };
+
+        let err = update("offer", None).expect_err("skip ahead");
+        assert!(err.to_string().contains("invalid status transition: new -> offer"));
+        update("applied", Some("ignored while open")).expect("applied");
+        update("screening", None).expect("screening");
+        update("rejected", Some("  role filled internally ")).expect("rejected");
+        update("rejected", None).expect("re-save keeps reason");
+        let err = update("applied", None).expect_err("terminal");
+        assert!(err.to_string().contains("rejected -> applied"));
+
+        let job = get_job_by_id(&store, "job-1").expect("get").expect("job");
+        assert_eq!(job.status, "rejected");
+        assert_eq!(job.closed_reason.as_deref(), Some("role filled internally"));
+
+        let history = list_status_history(&store, "job-1").expect("history");
+        let statuses = history.iter().map(|e| e.status.as_str()).collect::<Vec<_>>();
+        assert_eq!(statuses, vec!["new", "applied", "screening", "rejected"]);
+        assert_eq!(history[1].closed_reason, None);
+        assert_eq!(history[3].closed_reason.as_deref(), Some("role filled internally"));
+        assert_eq!(job.status_entered_at.as_deref(), Some(history[3].entered_at.as_str()));
+
+        let events = list_job_events(&store, "job-1").expect("timeline");
+        assert_eq!(
+            events.last().expect("event").payload["closed_reason"],
+            "role filled internally"
+        );
+    }
+
+    #[test]
+    fn next_action_due_is_validated_stored_and_logged() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        upsert_job_record(
+            &store,
+            UpsertJobRecordInput {
+                id: "job-1",
+                company: "Acme",
+                role: "Role",
+                source: "manual",
+                baseline: "1pg",
+                jd_text: "jd",
+                jd_hash: "hash",
+                track: None,
+                fit_total: None,
+                output_dir: None,
+            },
+        )
+        .expect("upsert");
+        let update = |due| {
+            update_job_status(
+                &store,
+                &StatusWorkflow::default(),
+                JobStatusUpdate {
+                    id: "job-1",
+                    status: "applied",
+                    next_action: Some("ping recruiter"),
+                    next_action_due: due,
+                    notes: None,
+                    closed_reason: None,
+                },
+            )
+        };
+
+        let err = update(Some("next friday")).expect_err("bad date");
+        assert!(err.to_string().contains("invalid due date"));
+        update(Some("2026-03-12")).expect("due");
+        let job = get_job_by_id(&store, "job-1").expect("get").expect("job");
+        assert_eq!(job.next_action_due.as_deref(), Some("2026-03-12"));
+        update(Some(" ")).expect("clear");
+        assert_eq!(
+            get_job_by_id(&store, "job-1").expect("get").expect("job").next_action_due,
+            None
+        );
+
+        let events = list_job_events(&store, "job-1").expect("timeline");
+        let due_changes = events
+            .iter()
+            .filter(|e| e.event_type == JobEventType::NextActionChanged)
+            .map(|e| e.payload["due_to"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(due_changes, vec![serde_json::json!("2026-03-12"), serde_json::Value::Null]);
+    }
+
+    #[test]
+    fn contacts_and_interviews_crud_is_scoped_to_jobs() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        upsert_job_record(
+            &store,
+            UpsertJobRecordInput {
+                id: "job-1",
+                company: "Acme",
+                role: "Role",
+                source: "manual",
+                baseline: "1pg",
+                jd_text: "jd",
+                jd_hash: "hash",
+                track: None,
+                fit_total: None,
+                output_dir: None,
+            },
+        )
+        .expect("upsert");
+
+        let contact = add_contact(
+            &store,
+            ContactInput {
+                job_id: "job-1",
+                role: "hiring manager".parse().expect("role"),
+                name: " Dana Lee ",
+                email: Some("dana@acme.test"),
+                notes: None,
+            },
+        )
+        .expect("add contact");
+        assert_eq!(contact.role, ContactRole::HiringManager);
+        assert_eq!(contact.name, "Dana Lee");
+        let err = add_contact(
+            &store,
+            ContactInput {
+                job_id: "missing",
+                role: ContactRole::Recruiter,
+                name: "Sam",
+                email: None,
+                notes: None,
+            },
+        )
+        .expect_err("unknown job");
+        assert!(err.to_string().contains("job not found"));
+        let updated = update_contact(
+            &store,
+            contact.id,
+            ContactInput {
+                job_id: "job-1",
+                role: ContactRole::Recruiter,
+                name: "Dana Lee",
+                email: None,
+                notes: Some("prefers email"),
+            },
+        )
+        .expect("update contact");
+        assert_eq!(updated.role, ContactRole::Recruiter);
+        assert_eq!(updated.email, None);
+
+        let interviewers = vec!["Ana".to_string(), " ".to_string(), "Bo".to_string()];
+        let round = |scheduled_at| InterviewInput {
+            job_id: "job-1",
+            scheduled_at,
+            kind: "technical",
+            interviewers: &interviewers,
+            outcome: None,
+            notes: None,
+        };
+        assert!(add_interview(&store, round("soon")).is_err());
+        let late = add_interview(&store, round("2026-03-20 14:00")).expect("late round");
+        let early = add_interview(&store, round("2026-03-12")).expect("early round");
+        assert_eq!(late.interviewers, vec!["Ana", "Bo"]);
+        let passed = update_interview(
+            &store,
+            early.id,
+            InterviewInput { outcome: Some("advanced"), ..round("2026-03-12") },
+        )
+        .expect("update round");
+        assert_eq!(passed.outcome.as_deref(), Some("advanced"));
+        let rounds = list_interviews(&store, "job-1").expect("rounds");
+        assert_eq!(rounds.iter().map(|r| r.id).collect::<Vec<_>>(), vec![early.id, late.id]);
+
+        assert!(delete_contact(&store, contact.id).expect("delete contact"));
+        assert!(!delete_contact(&store, contact.id).expect("already gone"));
+        assert!(delete_interview(&store, late.id).expect("delete round"));
+        assert!(list_contacts(&store, "job-1").expect("contacts").is_empty());
+        assert_eq!(list_interviews(&store, "job-1").expect("rounds").len(), 1);
+    }
+
+    #[test]
+    fn status_migration_backfills_existing_jobs() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let conn = Connection::open(&db_path).expect("open");
+        conn.execute_batch(
+            "CREATE TABLE schema_migrations (version INTEGER PRIMARY KEY, applied_at TEXT NOT NULL);",
+        )
+        .expect("schema table");
+        for (version, sql) in MIGRATIONS.iter().filter(|(v, _)| *v < 4) {
+            conn.execute_batch(sql).expect("old migration");
+            conn.execute(
+                "INSERT INTO schema_migrations (version, applied_at) VALUES (?1, 'then')",
+                params![version],
+            )
+            .expect("version row");
+        }
+        conn.execute(
+            "
+            INSERT INTO jobs (id, company, role, source, baseline, jd_text, jd_hash, status, created_at, updated_at)
+            VALUES ('old', 'Acme', 'Role', 'manual', '1pg', 'jd', 'hash', 'applied', '2026-01-01', '2026-01-05')
+            ",
+            [],
+        )
+        .expect("old job");
+        drop(conn);
+
+        let store = Store::open(&db_path).expect("store");
+        let job = get_job_by_id(&store, "old").expect("get").expect("job");
+        assert_eq!(job.status_entered_at.as_deref(), Some("2026-01-05"));
+        let history = list_status_history(&store, "old").expect("history");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, "applied");
+        let found = find_duplicate_jobs(&store, "other", "ACME", "role", None).expect("find");
+        assert_eq!(found.len(), 1, "match key backfilled for rows older than migration 9");
+    }
+
+    #[test]
+    fn duplicate_lookup_matches_jd_hash_and_normalized_company_role() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        for (id, company, role, hash) in [
+            ("job-1", "Acme, Inc.", "Senior  Support Engineer", "hash-a"),
+            ("job-2", "Other", "Analyst", "hash-b"),
+            ("job-3", "Acme Labs", "Support Engineer", "hash-c"),
+        ] {
+            upsert_job_record(
+                &store,
+                UpsertJobRecordInput {
+                    id,
+                    company,
+                    role,
+                    source: "manual",
+                    baseline: "1pg",
+                    jd_text: "jd",
+                    jd_hash: hash,
+                    track: None,
+                    fit_total: None,
+                    output_dir: None,
+                },
+            )
+            .expect("upsert");
+        }
+
+        let found =
+            find_duplicate_jobs(&store, "hash-b", "acme inc", "senior support engineer", None)
+                .expect("find");
+        let matches = found.iter().map(|d| (d.job_id.as_str(), d.reason)).collect::<Vec<_>>();
+        assert_eq!(
+            matches,
+            vec![("job-2", DuplicateReason::SameJd), ("job-1", DuplicateReason::SameCompanyRole)]
+        );
+
+        let excluded =
+            find_duplicate_jobs(&store, "hash-b", "Other", "Analyst", Some("job-2")).expect("find");
+        assert!(excluded.is_empty());
+        let plan = store
+            .conn()
+            .prepare("EXPLAIN QUERY PLAN SELECT id FROM jobs WHERE jd_hash = ?1 OR match_key = ?2")
+            .expect("plan")
+            .query_map(params!["hash-a", "acme|role"], |row| row.get::<_, String>(3))
+            .expect("plan rows")
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .expect("plan detail")
+            .join("\n");
+        assert!(plan.contains("idx_jobs_jd_hash") && plan.contains("idx_jobs_match_key"), "{plan}");
+        assert!(find_duplicate_jobs(
+            &Store::open(&dir.path().join("empty.db")).expect("store"),
+            "hash-a",
+            "Acme",
+            "Role",
+            None
+        )
+        .expect("empty db")
+        .is_empty());
+    }
+
+    #[test]
+    fn search_ranks_jobs_across_jd_notes_and_resume_text() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        for (id, company, role, jd_text) in [
+            ("job-1", "Acme", "Support Engineer", "Own Kubernetes incident response."),
+            ("job-2", "Globex", "Kubernetes Platform Engineer", "Build internal tooling."),
+            ("job-3", "Initech", "Analyst", "Spreadsheets and reporting."),
+        ] {
+            upsert_job_record(
+                &store,
+                UpsertJobRecordInput {
+                    id,
+                    company,
+                    role,
+                    source: "manual",
+                    baseline: "1pg",
+                    jd_text,
+                    jd_hash: id,
+                    track: None,
+                    fit_total: None,
+                    output_dir: None,
+                },
+            )
+            .expect("upsert");
+        }
+        update_job_status(
+            &store,
+            &StatusWorkflow::default(),
+            JobStatusUpdate {
+                id: "job-3",
+                status: "new",
+                next_action: None,
+                next_action_due: None,
+                notes: Some("Referral from the kubernetes meetup"),
+                closed_reason: None,
+            },
+        )
+        .expect("notes");
+        index_job_resume(&store, "job-1", "Led Terraform migrations").expect("resume");
+
+        let hits = search_jobs(&store, "kubernetes", 10).expect("search");
+        let ids = hits.iter().map(|h| h.job_id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0], "job-2", "role matches outrank body text");
+        assert!(hits.iter().all(|h| h.snippet.to_lowercase().contains("[kubernetes]")));
+
+        let resume_hits = search_jobs(&store, "terraform", 10).expect("resume search");
+        assert_eq!(resume_hits.len(), 1);
+        assert_eq!(resume_hits[0].job_id, "job-1");
+        assert_eq!(search_jobs(&store, "kube c++", 1).expect("prefix").len(), 0);
+        assert_eq!(search_jobs(&store, "kube", 1).expect("prefix").len(), 1);
+        assert!(search_jobs(&store, " \"* ", 10).is_err());
+    }
+
+    #[test]
+    fn search_quotes_user_input_and_orders_by_bm25() {
+        let dir = tempfile::tempdir().expect("temp");
+        let store = Store::open(&dir.path().join("applykit.db")).expect("store");
+        for (id, role, jd_text) in [
+            ("job-1", "Engineer", "Some C++ work alongside Python services."),
+            ("job-2", "C++ Engineer", "Modern C++ toolchains, C++ code review and C++ profiling."),
+            ("job-3", "Analyst", "Reporting AND dashboards - NOT engineering."),
+        ] {
+            upsert_job_record(
+                &store,
+                UpsertJobRecordInput {
+                    id,
+                    company: "Acme",
+                    role,
+                    source: "manual",
+                    baseline: "1pg",
+                    jd_text,
+                    jd_hash: id,
+                    track: None,
+                    fit_total: None,
+                    output_dir: None,
+                },
+            )
+            .expect("upsert");
+        }
+
+        assert_eq!(fts_query("c++ \"AND\" -x"), Some("\"c\"* \"AND\"* \"x\"*".to_string()));
+        for raw in ["c++", "AND", "NOT engineering", "role:analyst", "NEAR(a b)", "dash*"] {
+            search_jobs(&store, raw, 10).unwrap_or_else(|err| panic!("{raw}: {err}"));
+        }
+        for raw in ["\"", "-", "*"] {
+            let err = search_jobs(&store, raw, 10).expect_err("no terms");
+            assert!(err.to_string().contains("must not be empty"), "{raw}: {err}");
+        }
+
+        let hits = search_jobs(&store, "c++", 10).expect("c++");
+        let ids = hits.iter().map(|h| h.job_id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["job-2", "job-1"], "more and stronger matches rank first");
+        assert!(hits[0].score > hits[1].score);
+        assert!(hits[1].snippet.contains("[C]++"), "{}", hits[1].snippet);
+
+        // Operators are searched as words, not applied.
+        let not = search_jobs(&store, "NOT engineering", 10).expect("not");
+        assert_eq!(not.iter().map(|h| h.job_id.as_str()).collect::<Vec<_>>(), vec!["job-3"]);
+        assert_eq!(search_jobs(&store, "reporting AND", 10).expect("and").len(), 1);
+        assert!(search_jobs(&store, "role:analyst", 10).expect("column syntax").is_empty());
+    }
+
+    #[test]
+    fn job_query_filters_sorts_and_pages_in_sql() {
+        let dir = tempfile::tempdir().expect("temp");
+        let db_path = dir.path().join("applykit.db");
+        let store = Store::open(&db_path).expect("store");
+        assert!(!has_jobs(&store).expect("empty"));
+        for (id, company, track, fit) in [
+            ("job-1", "Acme", "support", 82),
+            ("job-2", "Acme Labs", "platform", 64),
+            ("job-3", "Globex", "support", 71),
+            ("job-4", "100%_Remote", "support", 90),
+        ] {
+            upsert_job_record(
+                &store,
+                UpsertJobRecordInput {
+                    id,
+                    company,
+                    role: "Engineer",
+                    source: if id == "job-3" { "referral" } else { "manual" },
+                    baseline: "1pg",
+                    jd_text: "jd",
+                    jd_hash: id,
+                    track: Some(track),
+                    fit_total: Some(fit),
+                    output_dir: None,
+                },
+            )
+            .expect("upsert");
+        }
+        let conn = Connection::open(&db_path).expect("open");
+        conn.execute("UPDATE jobs SET status = 'applied' WHERE id IN ('job-1', 'job-3')", [])
+            .expect("status");
+        conn.execute(
+            "UPDATE jobs SET updated_at = '2026-01-10T08:00:00+00:00' WHERE id = 'job-2'",
+            [],
+        )
+        .expect("date");
+        drop(conn);
+
+        let ids = |page: &JobPage| page.jobs.iter().map(|j| j.id.clone()).collect::<Vec<_>>();
+        let page = query_jobs(
+            &store,
+            &JobQuery {
+                track: Some("SUPPORT".to_string()),
+                fit_min: Some(70),
+                sort: JobSortKey::FitTotal,
+                limit: Some(2),
+                ..JobQuery::default()
+            },
+        )
+        .expect("query");
+        assert_eq!(ids(&page), vec!["job-4", "job-1"]);
+        assert_eq!((page.total, page.next_offset), (3, Some(2)));
+        let last = query_jobs(
+            &store,
+            &JobQuery {
+                track: Some("support".to_string()),
+                fit_min: Some(70),
+                sort: JobSortKey::FitTotal,
+                limit: Some(2),
+                offset: 2,
+                ..JobQuery::default()
+            },
+        )
+        .expect("page two");
+        assert_eq!((ids(&last), last.next_offset), (vec!["job-3".to_string()], None));
+
+        let applied = query_jobs(
+            &store,
+            &JobQuery {
+                statuses: vec!["Applied".to_string()],
+                source: Some("manual".to_string()),
+                ..JobQuery::default()
+            },
+        )
+        .expect("status");
+        assert_eq!(ids(&applied), vec!["job-1"]);
+
+        let by_company = query_jobs(
+            &store,
+            &JobQuery {
+                company: Some("acme".to_string()),
+                sort: JobSortKey::Company,
+                ascending: true,
+                ..JobQuery::default()
+            },
+        )
+        .expect("company");
+        assert_eq!(ids(&by_company), vec!["job-1", "job-2"]);
+        let literal = query_jobs(
+            &store,
+            &JobQuery { company: Some("%_".to_string()), ..JobQuery::default() },
+        )
+        .expect("escaped");
+        assert_eq!(ids(&literal), vec!["job-4"]);
+        let nothing = JobQuery { company: Some("Initech".to_string()), ..JobQuery::default() };
+        assert_eq!(query_jobs(&store, &nothing).expect("no match").total, 0);
+        assert!(has_jobs(&store).expect("has jobs") && !nothing.is_unfiltered());
+        assert!(JobQuery { sort: JobSortKey::Company, ..JobQuery::default() }.is_unfiltered());
+
+        let january = NaiveDate::from_ymd_opt(2026, 1, 10).expect("date");
+        let dated = query_jobs(
+            &store,
+            &JobQuery {
+                updated_from: Some(january),
+                updated_to: Some(january),
+                ..JobQuery::default()
+            },
+        )
+        .expect("dates");
+        assert_eq!(ids(&dated), vec!["job-2"]);
+        assert_eq!("fit".parse::<JobSortKey>(), Ok(JobSortKey::FitTotal));
+    }
+
+    #[test]
+    fn job_query_pages_follow_next_offset_and_sort_nulls_last() {
+        let dir = tempfile::tempdir().expect("temp");
+        let store = Store::open(&dir.path().join("applykit.db")).expect("store");
+        for (id, fit) in [
+            ("job-a", Some(40)),
+            ("job-b", None),
+            ("job-c", Some(75)),
+            ("job-d", Some(75)),
+            ("job-e", Some(90)),
+        ] {
+            upsert_job_record(
+                &store,
+                UpsertJobRecordInput {
+                    id,
+                    company: "Acme",
+                    role: id,
+                    source: "manual",
+                    baseline: "1pg",
+                    jd_text: "jd",
+                    jd_hash: id,
+                    track: Some("support"),
+                    fit_total: fit,
+                    output_dir: None,
+                },
+            )
+            .expect("upsert");
+        }
+        store
+            .conn()
+            .execute("UPDATE jobs SET status = 'interview' WHERE id IN ('job-c', 'job-e')", [])
+            .expect("status");
+
+        let ids = |page: &JobPage| page.jobs.iter().map(|j| j.id.clone()).collect::<Vec<_>>();
+        let mut query =
+            JobQuery { sort: JobSortKey::FitTotal, limit: Some(2), ..JobQuery::default() };
+        let mut walked = Vec::new();
+        loop {
+            let page = query_jobs(&store, &query).expect("page");
+            assert_eq!((page.total, page.offset), (5, query.offset));
+            walked.extend(ids(&page));
+            match page.next_offset {
+                Some(next) => query.offset = next,
+                None => break,
+            }
+        }
+        // Equal scores fall back to id order; jobs without a score come last either way.
+        assert_eq!(walked, vec!["job-e", "job-c", "job-d", "job-a", "job-b"]);
+        query.ascending = true;
+        query.offset = 0;
+        query.limit = None;
+        assert_eq!(
+            ids(&query_jobs(&store, &query).expect("ascending")),
+            vec!["job-a", "job-c", "job-d", "job-e", "job-b"]
+        );
+        let past_end =
+            query_jobs(&store, &JobQuery { offset: 9, ..JobQuery::default() }).expect("past end");
+        assert!(past_end.jobs.is_empty() && past_end.next_offset.is_none());
+        assert_eq!(past_end.total, 5);
+
+        let narrowed = query_jobs(
+            &store,
+            &JobQuery {
+                statuses: vec!["new".to_string(), " INTERVIEW ".to_string()],
+                fit_min: Some(50),
+                fit_max: Some(80),
+                sort: JobSortKey::Status,
+                ascending: true,
+                ..JobQuery::default()
+            },
+        )
+        .expect("narrowed");
+        assert_eq!(ids(&narrowed), vec!["job-c", "job-d"]);
+    }
 }
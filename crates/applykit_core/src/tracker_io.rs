@@ -0,0 +1,502 @@
+use crate::packet::csv_escape;
+use crate::pipeline::hash_jd;
+use crate::storage::{
+    find_duplicate_jobs, get_job_by_id, import_job_record, list_job_events, list_jobs,
+    list_status_history, ImportedJob, JobEvent, JobRecord, StatusEntry, Store,
+};
+use crate::workflow::StatusWorkflow;
+use anyhow::bail;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackerExportJob {
+    #[serde(flatten)]
+    pub job: JobRecord,
+    pub status_history: Vec<StatusEntry>,
+    pub events: Vec<JobEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackerExport {
+    pub exported_at: String,
+    pub jobs: Vec<TrackerExportJob>,
+}
+
+pub fn collect_tracker_export(store: &Store) -> anyhow::Result<TrackerExport> {
+    let mut jobs = Vec::new();
+    for job in list_jobs(store)? {
+        let status_history = list_status_history(store, &job.id)?;
+        let events = list_job_events(store, &job.id)?;
+        jobs.push(TrackerExportJob { job, status_history, events });
+    }
+    Ok(TrackerExport { exported_at: Utc::now().to_rfc3339(), jobs })
+}
+
+const EXPORT_COLUMNS: &[&str] = &[
+    "id",
+    "company",
+    "role",
+    "source",
+    "baseline",
+    "track",
+    "fit_total",
+    "status",
+    "status_entered_at",
+    "closed_reason",
+    "next_action",
+    "next_action_due",
+    "notes",
+    "output_dir",
+    "created_at",
+    "updated_at",
+    "status_history",
+    "events",
+];
+
+/// One row per job. History columns hold one `timestamp value` entry per line, so the file
+/// opens cleanly in a spreadsheet. [`import_tracker_csv`] reads the job fields back; the
+/// status history and event columns are for reading only and are not imported.
+pub fn tracker_export_csv(export: &TrackerExport) -> String {
+    let mut out = EXPORT_COLUMNS.join(",");
+    out.push('\n');
+    for entry in &export.jobs {
+        let job = &entry.job;
+        let history = entry
+            .status_history
+            .iter()
+            .map(|s| match &s.closed_reason {
+                Some(reason) => format!("{} {} ({reason})", s.entered_at, s.status),
+                None => format!("{} {}", s.entered_at, s.status),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let events = entry
+            .events
+            .iter()
+            .map(|e| format!("{} {}", e.created_at, e.event_type.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let opt = |value: &Option<String>| value.clone().unwrap_or_default();
+        let cells = [
+            job.id.clone(),
+            job.company.clone(),
+            job.role.clone(),
+            job.source.clone(),
+            job.baseline.clone(),
+            opt(&job.track),
+            job.fit_total.map(|f| f.to_string()).unwrap_or_default(),
+            job.status.clone(),
+            opt(&job.status_entered_at),
+            opt(&job.closed_reason),
+            opt(&job.next_action),
+            opt(&job.next_action_due),
+            opt(&job.notes),
+            opt(&job.output_dir),
+            job.created_at.clone(),
+            job.updated_at.clone(),
+            history,
+            events,
+        ];
+        out.push_str(&cells.iter().map(|c| csv_escape(c)).collect::<Vec<_>>().join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Parses RFC 4180 CSV: quoted fields may contain commas, doubled quotes and newlines.
+fn parse_csv(text: &str) -> anyhow::Result<Vec<Vec<String>>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (in_quotes, ch) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => in_quotes = false,
+            (true, _) => field.push(ch),
+            (false, '"') if field.is_empty() => in_quotes = true,
+            (false, ',') => row.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') | (false, '\r') => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (false, _) => field.push(ch),
+        }
+    }
+    if in_quotes {
+        bail!("unterminated quoted field");
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|r| r.iter().any(|cell| !cell.trim().is_empty()));
+    Ok(rows)
+}
+
+/// Tracker fields an imported column can fill, with the spreadsheet headers recognised for each.
+const IMPORT_FIELDS: &[(&str, &[&str])] = &[
+    ("id", &["id", "job id"]),
+    ("company", &["company", "employer", "organization", "organisation"]),
+    ("role", &["role", "title", "job title", "position"]),
+    ("source", &["source", "via", "channel"]),
+    ("status", &["status", "stage"]),
+    ("date", &["date", "date applied", "applied", "applied on", "created at"]),
+    ("notes", &["notes", "comments"]),
+    ("next_action", &["next action", "next step"]),
+    ("next_action_due", &["next action due", "due", "follow up", "follow up date"]),
+    ("fit_total", &["fit total", "fit", "score"]),
+    ("track", &["track"]),
+    ("jd_text", &["jd text", "jd", "job description", "description"]),
+];
+
+fn header_key(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Matches each field to a column index, preferring explicit `overrides` (field -> header).
+fn map_columns(
+    headers: &[String],
+    overrides: &BTreeMap<String, String>,
+) -> anyhow::Result<BTreeMap<&'static str, usize>> {
+    let keys = headers.iter().map(|h| header_key(h)).collect::<Vec<_>>();
+    let mut mapping = BTreeMap::new();
+    for (field, header) in overrides {
+        let Some((known, _)) = IMPORT_FIELDS.iter().find(|(name, _)| name == field) else {
+            bail!("unknown import field: {field}");
+        };
+        let Some(index) = keys.iter().position(|k| *k == header_key(header)) else {
+            bail!("column not found for {field}: {header}");
+        };
+        mapping.insert(*known, index);
+    }
+    for (field, aliases) in IMPORT_FIELDS {
+        if mapping.contains_key(field) {
+            continue;
+        }
+        if let Some(index) = keys.iter().position(|k| aliases.contains(&k.as_str())) {
+            mapping.insert(*field, index);
+        }
+    }
+    for required in ["company", "role"] {
+        if !mapping.contains_key(required) {
+            bail!("no {required} column; map one with {required}=<header>");
+        }
+    }
+    Ok(mapping)
+}
+
+fn parse_import_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if let Ok(stamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(stamp.date_naive());
+    }
+    ["%Y-%m-%d", "%m/%d/%Y", "%Y/%m/%d", "%d %b %Y", "%b %d, %Y"]
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(value, fmt).ok())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TrackerImportOptions {
+    /// Field name (`company`, `role`, `date`, ...) to spreadsheet header.
+    pub column_overrides: BTreeMap<String, String>,
+    /// Report what would be imported without writing.
+    pub dry_run: bool,
+}
+
+/// A data row that was not imported; `line` is 1-based and counts the header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportIssue {
+    pub line: usize,
+    pub company: String,
+    pub role: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrackerImportReport {
+    pub rows: usize,
+    /// Column header used for each field.
+    pub mapping: BTreeMap<String, String>,
+    pub created: Vec<String>,
+    pub duplicates: Vec<ImportIssue>,
+    pub errors: Vec<ImportIssue>,
+    pub dry_run: bool,
+}
+
+/// Creates a job for each spreadsheet row. Rows matching an existing job (or an earlier row)
+/// by JD text, normalized company+role or id are reported as duplicates and skipped.
+pub fn import_tracker_csv(
+    store: &Store,
+    csv_text: &str,
+    workflow: &StatusWorkflow,
+    options: &TrackerImportOptions,
+) -> anyhow::Result<TrackerImportReport> {
+    let rows = parse_csv(csv_text)?;
+    let Some((headers, data)) = rows.split_first() else {
+        bail!("CSV has no header row");
+    };
+    let columns = map_columns(headers, &options.column_overrides)?;
+    let mut report = TrackerImportReport {
+        rows: data.len(),
+        mapping: columns.iter().map(|(f, i)| (f.to_string(), headers[*i].clone())).collect(),
+        dry_run: options.dry_run,
+        ..TrackerImportReport::default()
+    };
+    let today = Local::now().date_naive();
+    // Earlier rows of this file, keyed by JD hash and by normalized company+role, and the ids
+    // they were given; existing jobs are checked with indexed queries.
+    let mut seen_hashes = BTreeMap::<String, (String, String)>::new();
+    let mut seen_postings = BTreeMap::<(String, String), (String, String)>::new();
+    let mut seen_ids = BTreeSet::<String>::new();
+
+    for (index, row) in data.iter().enumerate() {
+        let cell = |field: &str| {
+            columns.get(field).and_then(|i| row.get(*i)).map(|v| v.trim()).filter(|v| !v.is_empty())
+        };
+        let company = cell("company").unwrap_or_default().to_string();
+        let role = cell("role").unwrap_or_default().to_string();
+        let issue = |reason: String| ImportIssue {
+            line: index + 2,
+            company: company.clone(),
+            role: role.clone(),
+            reason,
+        };
+        if company.is_empty() || role.is_empty() {
+            report.errors.push(issue("company and role are required".to_string()));
+            continue;
+        }
+        let date = match cell("date") {
+            Some(raw) => match parse_import_date(raw) {
+                Some(date) => date,
+                None => {
+                    report.errors.push(issue(format!("invalid date: {raw}")));
+                    continue;
+                }
+            },
+            None => today,
+        };
+        let status = cell("status").unwrap_or("new").to_ascii_lowercase();
+        if !workflow.is_known(&status) {
+            report.errors.push(issue(format!("invalid status: {status}")));
+            continue;
+        }
+        let due = match cell("next_action_due") {
+            Some(raw) => match parse_import_date(raw) {
+                Some(due) => Some(due.format("%Y-%m-%d").to_string()),
+                None => {
+                    report.errors.push(issue(format!("invalid due date: {raw}")));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        let fit_total = match cell("fit_total") {
+            Some(raw) => match raw.parse::<i64>() {
+                Ok(fit) => Some(fit),
+                Err(_) => {
+                    report.errors.push(issue(format!("invalid fit score: {raw}")));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        let jd_text = cell("jd_text").unwrap_or_default();
+        // Without a JD the hash would collide across rows, so key it on the posting instead.
+        let jd_hash = if jd_text.is_empty() {
+            hash_jd(&format!("import\n{company}\n{role}"))
+        } else {
+            hash_jd(jd_text)
+        };
+
+        let posting = (header_key(&company), header_key(&role));
+        let earlier = seen_hashes.get(&jd_hash).or_else(|| seen_postings.get(&posting));
+        if let Some((c, r)) = earlier {
+            report.duplicates.push(issue(format!("duplicate of an earlier row ({c} - {r})")));
+            continue;
+        }
+        if let Some(existing) = find_duplicate_jobs(store, &jd_hash, &company, &role, None)?.first()
+        {
+            report.duplicates.push(issue(format!("already tracked as {}", existing.job_id)));
+            continue;
+        }
+
+        // Re-importing our own export keeps the original ids.
+        let id = cell("id")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{company}:{role}:{}:{jd_hash}", date.format("%Y-%m-%d")));
+        if seen_ids.contains(&id) || get_job_by_id(store, &id)?.is_some() {
+            report.duplicates.push(issue(format!("id already tracked: {id}")));
+            continue;
+        }
+        seen_hashes.insert(jd_hash.clone(), (company.clone(), role.clone()));
+        seen_postings.insert(posting, (company.clone(), role.clone()));
+        seen_ids.insert(id.clone());
+
+        if !options.dry_run {
+            let created_at = format!("{}T00:00:00+00:00", date.format("%Y-%m-%d"));
+            let inserted = import_job_record(
+                store,
+                ImportedJob {
+                    id: &id,
+                    company: &company,
+                    role: &role,
+                    source: cell("source").unwrap_or("import"),
+                    jd_text,
+                    jd_hash: &jd_hash,
+                    track: cell("track"),
+                    fit_total,
+                    status: &status,
+                    next_action: cell("next_action"),
+                    next_action_due: due.as_deref(),
+                    notes: cell("notes"),
+                    created_at: &created_at,
+                },
+            )?;
+            if !inserted {
+                report.duplicates.push(issue(format!("id already tracked: {id}")));
+                continue;
+            }
+        }
+        report.created.push(id);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_reports_rows_whose_id_is_already_tracked() {
+        let dir = tempfile::tempdir().expect("temp");
+        let store = Store::open(&dir.path().join("applykit.db")).expect("store");
+        let workflow = StatusWorkflow::default();
+        import_tracker_csv(
+            &store,
+            "id,company,role
+1,Acme,Support
+2,Globex,Analyst
+",
+            &workflow,
+            &TrackerImportOptions::default(),
+        )
+        .expect("first sheet");
+
+        let sheet = "id,company,role
+1,Initech,Ops Lead
+3,Umbrella,SRE
+3,Hooli,Platform
+";
+        for dry_run in [true, false] {
+            let options = TrackerImportOptions { dry_run, ..TrackerImportOptions::default() };
+            let report = import_tracker_csv(&store, sheet, &workflow, &options).expect("import");
+            assert_eq!(report.created, vec!["3"], "dry_run={dry_run}");
+            let reasons =
+                report.duplicates.iter().map(|d| (d.line, d.reason.as_str())).collect::<Vec<_>>();
+            assert_eq!(
+                reasons,
+                vec![(2, "id already tracked: 1"), (4, "id already tracked: 3")],
+                "dry_run={dry_run}"
+            );
+        }
+        let jobs = list_jobs(&store).expect("jobs");
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(get_job_by_id(&store, "1").expect("get").expect("job").company, "Acme");
+    }
+
+    #[test]
+    fn parse_csv_handles_quotes_newlines_and_bom() {
+        let rows =
+            parse_csv("\u{feff}a,b\r\n\"x, y\",\"say \"\"hi\"\"\nthere\"\n\n").expect("parse");
+        assert_eq!(rows, vec![vec!["a", "b"], vec!["x, y", "say \"hi\"\nthere"]]);
+        assert!(parse_csv("a,\"b").is_err());
+    }
+
+    #[test]
+    fn spreadsheet_import_maps_columns_skips_duplicates_and_round_trips_export() {
+        let dir = tempfile::tempdir().expect("temp");
+        let store = Store::open(&dir.path().join("applykit.db")).expect("store");
+        let workflow = StatusWorkflow::default();
+        let sheet = "Employer,Job Title,Stage,Date Applied,Comments,Link\n\
+            \"Acme, Inc.\",Support Engineer,Applied,03/02/2026,\"Referred by Sam\",https://acme\n\
+            acme inc,support engineer,new,2026-03-03,,\n\
+            Globex,Analyst,daydreaming,2026-03-04,,\n\
+            Initech,Ops Lead,interview,someday,,\n\
+            Umbrella,SRE,new,2026-03-05,,\n";
+        let options = TrackerImportOptions {
+            column_overrides: BTreeMap::from([("source".to_string(), "Link".to_string())]),
+            dry_run: false,
+        };
+
+        let dry = import_tracker_csv(
+            &store,
+            sheet,
+            &workflow,
+            &TrackerImportOptions { dry_run: true, ..options.clone() },
+        )
+        .expect("dry run");
+        assert_eq!(dry.created.len(), 2);
+        assert!(list_jobs(&store).expect("jobs").is_empty());
+
+        let report = import_tracker_csv(&store, sheet, &workflow, &options).expect("import");
+        assert_eq!(report.rows, 5);
+        assert_eq!(report.mapping["company"], "Employer");
+        assert_eq!(report.mapping["source"], "Link");
+        assert_eq!(report.created.len(), 2);
+        assert_eq!(report.duplicates.iter().map(|d| d.line).collect::<Vec<_>>(), vec![3]);
+        let errors = report.errors.iter().map(|e| e.reason.as_str()).collect::<Vec<_>>();
+        assert_eq!(errors, vec!["invalid status: daydreaming", "invalid date: someday"]);
+
+        let jobs = list_jobs(&store).expect("jobs");
+        let acme = jobs.iter().find(|j| j.company == "Acme, Inc.").expect("acme");
+        assert_eq!(acme.status, "applied");
+        assert_eq!(acme.source, "https://acme");
+        assert_eq!(acme.notes.as_deref(), Some("Referred by Sam"));
+        assert!(acme.created_at.starts_with("2026-03-02"));
+        let umbrella = jobs.iter().find(|j| j.company == "Umbrella").expect("umbrella");
+        assert_eq!(umbrella.source, "import");
+
+        let again = import_tracker_csv(&store, sheet, &workflow, &options).expect("re-import");
+        assert!(again.created.is_empty());
+        assert_eq!(again.duplicates.len(), 3);
+
+        let export = collect_tracker_export(&store).expect("export");
+        assert_eq!(export.jobs.len(), 2);
+        assert_eq!(export.jobs[0].events[0].event_type.as_str(), "imported");
+        let json = serde_json::to_value(&export).expect("json");
+        assert!(json["jobs"][0]["status_history"].is_array());
+        assert!(json["jobs"][0]["company"].is_string());
+
+        let csv = tracker_export_csv(&export);
+        let restored_db = Store::open(&dir.path().join("restored.db")).expect("store");
+        let restored =
+            import_tracker_csv(&restored_db, &csv, &workflow, &TrackerImportOptions::default())
+                .expect("round trip");
+        assert!(restored.errors.is_empty(), "{:?}", restored.errors);
+        let mut original_ids = jobs.iter().map(|j| j.id.clone()).collect::<Vec<_>>();
+        let mut restored_ids = restored.created.clone();
+        original_ids.sort();
+        restored_ids.sort();
+        assert_eq!(original_ids, restored_ids);
+        let restored_acme = crate::storage::get_job_by_id(&restored_db, &acme.id)
+            .expect("get")
+            .expect("acme restored");
+        assert_eq!(restored_acme.status, "applied");
+        assert_eq!(restored_acme.notes.as_deref(), Some("Referred by Sam"));
+    }
+}
@@ -9,6 +9,8 @@ fn normalize_token(input: &str) -> String {
         .replace(|ch: char| !ch.is_ascii_alphanumeric() && ch != '+' && ch != '/' && ch != '.', "")
 }
 
+/// Tools a packet may name: approved skills only. Bullet tools are not trusted on their own;
+/// bank edits keep them in step with `skills_bank.json` instead.
 fn approved_tool_set(banks: &Banks) -> BTreeSet<String> {
     let mut set = BTreeSet::new();
     for (skill, record) in &banks.skills {
@@ -30,23 +32,6 @@ fn approved_tool_set(banks: &Banks) -> BTreeSet<String> {
             }
         }
     }
-    for bullet in &banks.bullets {
-        if !bullet.approved {
-            continue;
-        }
-        for tool in &bullet.tools {
-            let normalized = normalize_token(tool);
-            if !normalized.is_empty() {
-                set.insert(normalized.clone());
-            }
-            for piece in tool.split([' ', '/', '+', '-', '.']) {
-                let token = normalize_token(piece);
-                if token.len() > 2 {
-                    set.insert(token);
-                }
-            }
-        }
-    }
     set
 }
 
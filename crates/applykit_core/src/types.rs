@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
@@ -165,9 +166,14 @@ pub struct ExtractionDiagnostics {
     #[serde(default)]
     pub summarize_attempted: bool,
     #[serde(default)]
+    pub summarize_repair_attempted: bool,
+    #[serde(default)]
     pub summarize_merged: bool,
     #[serde(default)]
     pub summarize_fallback_reasons: Vec<String>,
+    /// JD lines withheld from LLM prompts because they read as injected instructions.
+    #[serde(default)]
+    pub injection_warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -208,6 +214,9 @@ pub struct TailorPlan {
     pub edits: Vec<TailorEdit>,
     pub max_resume_edits: usize,
     pub max_bullet_swaps: usize,
+    /// Bullets left out because a tool's skill is not approved yet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_tool_bullets: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -257,6 +266,8 @@ pub struct GenerateResultData {
     pub extraction_source: ExtractionSource,
     #[serde(default)]
     pub extraction_diagnostics: ExtractionDiagnostics,
+    #[serde(default)]
+    pub prompt_versions: BTreeMap<String, String>,
     pub track: TrackSelection,
     pub fit: FitScore,
     pub tailor_plan: TailorPlan,
@@ -271,6 +282,9 @@ pub struct GenerateResultData {
     pub truth_report: TruthValidationReport,
     pub packet_dir: PathBuf,
     pub files_written: Vec<PathBuf>,
+    /// Other jobs with the same JD or company+role found when this packet was generated.
+    #[serde(default)]
+    pub duplicate_jobs: Vec<crate::storage::DuplicateJob>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -281,6 +295,8 @@ pub struct PacketDetail {
     pub extraction_source: ExtractionSource,
     #[serde(default)]
     pub extraction_diagnostics: ExtractionDiagnostics,
+    #[serde(default)]
+    pub prompt_versions: BTreeMap<String, String>,
     pub fit: FitScore,
     pub track: TrackSelection,
     pub tailor_plan: TailorPlan,
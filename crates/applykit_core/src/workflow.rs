@@ -0,0 +1,159 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Outcome funnel stages, in order. Reaching a stage implies reaching every earlier one.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum FunnelStage {
+    Applied,
+    Reply,
+    Interview,
+    Offer,
+}
+
+/// Application status state machine, configured under `[tracker]` in `applykit.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct StatusWorkflow {
+    /// Every status a job may hold, in pipeline order.
+    pub statuses: Vec<String>,
+    /// Terminal statuses; entering one of these records the closed reason.
+    pub closed_statuses: Vec<String>,
+    /// Allowed moves from each status. Statuses without an entry cannot be left.
+    pub transitions: BTreeMap<String, Vec<String>>,
+    /// Days a job may sit in a status before a follow-up is suggested.
+    pub follow_up_days: BTreeMap<String, u32>,
+    /// Furthest funnel stage each status represents, for insights. Unlisted statuses (such
+    /// as `new`) count towards no stage.
+    pub stages: BTreeMap<String, FunnelStage>,
+}
+
+const DEFAULT_TRANSITIONS: &[(&str, &[&str])] = &[
+    ("new", &["applied", "reply", "withdrawn", "closed"]),
+    ("applied", &["reply", "screening", "interview", "rejected", "withdrawn", "ghosted", "closed"]),
+    ("reply", &["screening", "interview", "rejected", "withdrawn", "ghosted", "closed"]),
+    ("screening", &["interview", "onsite", "rejected", "withdrawn", "ghosted", "closed"]),
+    ("interview", &["onsite", "offer", "rejected", "withdrawn", "ghosted", "closed"]),
+    ("onsite", &["offer", "rejected", "withdrawn", "ghosted", "closed"]),
+    ("offer", &["accepted", "rejected", "withdrawn", "closed"]),
+    ("ghosted", &["reply", "screening", "interview", "closed"]),
+];
+
+const DEFAULT_STAGES: &[(&str, FunnelStage)] = &[
+    ("applied", FunnelStage::Applied),
+    ("rejected", FunnelStage::Applied),
+    ("withdrawn", FunnelStage::Applied),
+    ("ghosted", FunnelStage::Applied),
+    ("closed", FunnelStage::Applied),
+    ("reply", FunnelStage::Reply),
+    ("screening", FunnelStage::Reply),
+    ("interview", FunnelStage::Interview),
+    ("onsite", FunnelStage::Interview),
+    ("offer", FunnelStage::Offer),
+    ("accepted", FunnelStage::Offer),
+];
+
+const DEFAULT_FOLLOW_UP_DAYS: &[(&str, u32)] = &[
+    ("applied", 7),
+    ("reply", 3),
+    ("screening", 5),
+    ("interview", 5),
+    ("onsite", 5),
+    ("offer", 3),
+];
+
+impl Default for StatusWorkflow {
+    fn default() -> Self {
+        let owned = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Self {
+            statuses: owned(&[
+                "new",
+                "applied",
+                "reply",
+                "screening",
+                "interview",
+                "onsite",
+                "offer",
+                "accepted",
+                "rejected",
+                "withdrawn",
+                "ghosted",
+                "closed",
+            ]),
+            closed_statuses: owned(&["accepted", "rejected", "withdrawn", "ghosted", "closed"]),
+            transitions: DEFAULT_TRANSITIONS
+                .iter()
+                .map(|(from, to)| (from.to_string(), owned(to)))
+                .collect(),
+            follow_up_days: DEFAULT_FOLLOW_UP_DAYS
+                .iter()
+                .map(|(status, days)| (status.to_string(), *days))
+                .collect(),
+            stages: DEFAULT_STAGES
+                .iter()
+                .map(|(status, stage)| (status.to_string(), *stage))
+                .collect(),
+        }
+    }
+}
+
+impl StatusWorkflow {
+    pub fn is_known(&self, status: &str) -> bool {
+        self.statuses.iter().any(|s| s == status)
+    }
+
+    pub fn is_closed(&self, status: &str) -> bool {
+        self.closed_statuses.iter().any(|s| s == status)
+    }
+
+    pub fn stage_of(&self, status: &str) -> Option<FunnelStage> {
+        self.stages.get(&status.to_ascii_lowercase()).copied()
+    }
+
+    /// Statuses reachable from `from` in one step, not including `from` itself.
+    pub fn next_statuses(&self, from: &str) -> &[String] {
+        self.transitions.get(from).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Checks that `to` is a known status reachable from `from`; staying put is always allowed.
+    pub fn check_transition(&self, from: &str, to: &str) -> anyhow::Result<()> {
+        if !self.is_known(to) {
+            bail!("invalid status: {to}");
+        }
+        if from != to && !self.next_statuses(from).iter().any(|s| s == to) {
+            bail!("invalid status transition: {from} -> {to}");
+        }
+        Ok(())
+    }
+
+    /// Rejects workflows that reference undeclared statuses or omit `new`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !self.is_known("new") {
+            bail!("tracker statuses must include `new`");
+        }
+        for status in &self.closed_statuses {
+            if !self.is_known(status) {
+                bail!("tracker closed status `{status}` is not a declared status");
+            }
+        }
+        for (from, targets) in &self.transitions {
+            for status in std::iter::once(from).chain(targets) {
+                if !self.is_known(status) {
+                    bail!("tracker transition uses undeclared status `{status}`");
+                }
+            }
+        }
+        for status in self.follow_up_days.keys() {
+            if !self.is_known(status) {
+                bail!("tracker follow-up uses undeclared status `{status}`");
+            }
+        }
+        for status in self.stages.keys() {
+            if !self.is_known(status) {
+                bail!("tracker stage uses undeclared status `{status}`");
+            }
+        }
+        Ok(())
+    }
+}
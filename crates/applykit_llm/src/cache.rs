@@ -0,0 +1,142 @@
+use crate::{LlmAdapter, LlmRequest, LlmResponse, LlmTask};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+pub(crate) fn sha256_hex(input: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Everything besides the prompt that changes what a provider would answer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CacheIdentity {
+    pub provider: String,
+    pub model: String,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    provider: String,
+    model: String,
+    task: LlmTask,
+    seed: Option<u64>,
+    prompt_hash: String,
+    response: LlmResponse,
+}
+
+/// On-disk store of successful responses, one JSON file per request key.
+#[derive(Debug, Clone)]
+pub struct LlmCache {
+    dir: PathBuf,
+}
+
+impl LlmCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Hex key over provider, model, task, seed, output constraint and prompt hash.
+    pub fn key(&self, identity: &CacheIdentity, req: &LlmRequest) -> String {
+        let material = serde_json::json!({
+            "provider": identity.provider,
+            "model": identity.model,
+            "task": req.task,
+            "seed": identity.seed,
+            "constraint": req.constraint,
+            "prompt_hash": sha256_hex(req.prompt.as_bytes()),
+        });
+        sha256_hex(material.to_string().as_bytes())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Returns the cached response for `key`; unreadable or corrupt entries count as misses.
+    pub fn get(&self, key: &str) -> Option<LlmResponse> {
+        let raw = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str::<CacheEntry>(&raw).ok().map(|entry| entry.response)
+    }
+
+    pub fn put(
+        &self,
+        key: &str,
+        identity: &CacheIdentity,
+        req: &LlmRequest,
+        response: &LlmResponse,
+    ) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("creating {}", self.dir.display()))?;
+        let entry = CacheEntry {
+            provider: identity.provider.clone(),
+            model: identity.model.clone(),
+            task: req.task,
+            seed: identity.seed,
+            prompt_hash: sha256_hex(req.prompt.as_bytes()),
+            response: response.clone(),
+        };
+        let path = self.entry_path(key);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(&entry)?)
+            .with_context(|| format!("writing {}", tmp.display()))?;
+        std::fs::rename(&tmp, &path).with_context(|| format!("writing {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Removes every cached entry and returns how many were deleted.
+    pub fn clear(&self) -> anyhow::Result<usize> {
+        if !self.dir.exists() {
+            return Ok(0);
+        }
+        let mut removed = 0;
+        for entry in std::fs::read_dir(&self.dir)
+            .with_context(|| format!("reading {}", self.dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("removing {}", path.display()))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// Wraps another adapter so identical requests replay the first successful response.
+pub struct CachedAdapter {
+    pub inner: Box<dyn LlmAdapter>,
+    pub cache: LlmCache,
+    pub identity: CacheIdentity,
+}
+
+impl LlmAdapter for CachedAdapter {
+    fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        self.rewrite_streaming(req, &mut |_| {})
+    }
+
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
+        let key = self.cache.key(&self.identity, req);
+        if let Some(hit) = self.cache.get(&key) {
+            on_chunk(&hit.output);
+            return Ok(hit);
+        }
+        let response = self.inner.rewrite_streaming(req, on_chunk)?;
+        // Best effort: a full disk or read-only output base must not fail a call that
+        // already succeeded; the next identical request just misses again.
+        let _ = self.cache.put(&key, &self.identity, req, &response);
+        Ok(response)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::LlmClientOptions;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// Result of probing a provider's model-listing endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HealthReport {
+    pub provider: String,
+    pub base_url: String,
+    pub reachable: bool,
+    pub latency_ms: Option<u64>,
+    pub models: Vec<String>,
+    pub model: String,
+    pub model_available: bool,
+    pub error: Option<String>,
+}
+
+/// Ollama tags omit `:latest` in configs but always include it in listings.
+fn model_matches(listed: &str, configured: &str) -> bool {
+    listed == configured || listed.strip_suffix(":latest") == Some(configured)
+}
+
+fn list_models(
+    provider: &str,
+    base_url: &str,
+    options: &LlmClientOptions,
+) -> anyhow::Result<Vec<String>> {
+    #[derive(Deserialize)]
+    struct OllamaTags {
+        #[serde(default)]
+        models: Vec<OllamaModel>,
+    }
+    #[derive(Deserialize)]
+    struct OllamaModel {
+        name: String,
+    }
+    #[derive(Deserialize)]
+    struct OpenAiModels {
+        #[serde(default)]
+        data: Vec<OpenAiModel>,
+    }
+    #[derive(Deserialize)]
+    struct OpenAiModel {
+        id: String,
+    }
+
+    options.ensure_not_cancelled()?;
+    let client = options.http_client()?;
+    let base = base_url.trim_end_matches('/');
+    let ollama = provider.eq_ignore_ascii_case("ollama");
+    let url = if ollama { format!("{base}/api/tags") } else { format!("{base}/v1/models") };
+    let resp = client.get(&url).send().with_context(|| format!("request to {url}"))?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("{url} returned {status}");
+    }
+    let mut models = if ollama {
+        resp.json::<OllamaTags>()
+            .context("ollama tags parse")?
+            .models
+            .into_iter()
+            .map(|m| m.name)
+            .collect::<Vec<_>>()
+    } else {
+        resp.json::<OpenAiModels>()
+            .context("model list parse")?
+            .data
+            .into_iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>()
+    };
+    models.sort();
+    models.dedup();
+    Ok(models)
+}
+
+/// Lists the provider's models and reports whether `model` is among them. Failures are
+/// captured in the report rather than returned, so callers can always render a result.
+pub fn check_health(
+    provider: &str,
+    base_url: &str,
+    model: &str,
+    options: &LlmClientOptions,
+) -> HealthReport {
+    let started = Instant::now();
+    let listed = list_models(provider, base_url, options);
+    let latency_ms = started.elapsed().as_millis() as u64;
+    let mut report = HealthReport {
+        provider: provider.to_string(),
+        base_url: base_url.to_string(),
+        reachable: false,
+        latency_ms: None,
+        models: Vec::new(),
+        model: model.to_string(),
+        model_available: false,
+        error: None,
+    };
+    match listed {
+        Ok(models) => {
+            report.reachable = true;
+            report.latency_ms = Some(latency_ms);
+            report.model_available = models.iter().any(|m| model_matches(m, model));
+            report.models = models;
+        }
+        Err(err) => report.error = Some(format!("{err:#}")),
+    }
+    report
+}
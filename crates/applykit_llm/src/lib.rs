@@ -1,9 +1,18 @@
+mod cache;
+mod health;
+mod replay;
+mod transport;
+
 use anyhow::Context;
-use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use transport::{for_each_line, is_event_stream, send, with_retries, AttemptError};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub use cache::{CacheIdentity, CachedAdapter, LlmCache};
+pub use health::{check_health, HealthReport};
+pub use replay::{replay_fixture_path, RecordingAdapter, ReplayAdapter};
+pub use transport::{is_cancelled_error, CancelToken, LlmCancelled, LlmClientOptions};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum LlmTask {
     SummarizeJd,
@@ -11,10 +20,32 @@ pub enum LlmTask {
     RewriteBullet,
 }
 
+impl LlmTask {
+    pub const ALL: [LlmTask; 3] = [Self::SummarizeJd, Self::RewriteMessage, Self::RewriteBullet];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SummarizeJd => "summarize_jd",
+            Self::RewriteMessage => "rewrite_message",
+            Self::RewriteBullet => "rewrite_bullet",
+        }
+    }
+}
+
+/// Restricts the shape of a completion; providers that cannot honor a constraint ignore it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputConstraint {
+    JsonSchema(serde_json::Value),
+    Grammar(String),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmRequest {
     pub task: LlmTask,
     pub prompt: String,
+    #[serde(default)]
+    pub constraint: Option<OutputConstraint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,48 +56,81 @@ pub struct LlmResponse {
 
 pub trait LlmAdapter {
     fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse>;
-}
 
-fn llm_http_client() -> anyhow::Result<Client> {
-    Client::builder()
-        .connect_timeout(Duration::from_secs(5))
-        .timeout(Duration::from_secs(20))
-        .build()
-        .context("building llm HTTP client")
+    /// Like [`LlmAdapter::rewrite`], but hands each fragment to `on_chunk` as it arrives.
+    /// If a stream fails part-way and is retried, fragments from the failed attempt have
+    /// already been delivered; the returned output only contains the successful attempt.
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
+        let resp = self.rewrite(req)?;
+        on_chunk(&resp.output);
+        Ok(resp)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct OllamaAdapter {
     pub base_url: String,
     pub model: String,
+    pub options: LlmClientOptions,
 }
 
 impl LlmAdapter for OllamaAdapter {
     fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        self.rewrite_streaming(req, &mut |_| {})
+    }
+
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
         #[derive(Serialize)]
         struct Body<'a> {
             model: &'a str,
             prompt: &'a str,
             stream: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            format: Option<&'a serde_json::Value>,
         }
         #[derive(Deserialize)]
-        struct Resp {
+        struct Chunk {
+            #[serde(default)]
             response: String,
+            #[serde(default)]
+            done: bool,
+            #[serde(default)]
+            error: Option<String>,
         }
 
-        let client = llm_http_client()?;
+        let client = self.options.http_client()?;
         let url = format!("{}/api/generate", self.base_url.trim_end_matches('/'));
-        let resp = client
-            .post(&url)
-            .json(&Body { model: &self.model, prompt: &req.prompt, stream: false })
-            .send()
-            .with_context(|| format!("request to {url}"))?
-            .error_for_status()
-            .context("ollama non-2xx")?
-            .json::<Resp>()
-            .context("ollama response parse")?;
-
-        Ok(LlmResponse { output: resp.response, provider: "ollama".to_string() })
+        // Ollama accepts a JSON schema as `format`; grammars are not supported and are dropped.
+        let format = match &req.constraint {
+            Some(OutputConstraint::JsonSchema(schema)) => Some(schema),
+            Some(OutputConstraint::Grammar(_)) | None => None,
+        };
+        let body =
+            Body { model: &self.model, prompt: &req.prompt, stream: self.options.stream, format };
+        let output = with_retries(&self.options, || {
+            let resp = send(client.post(&url).json(&body), &url)?;
+            let mut output = String::new();
+            for_each_line(resp, &self.options, |line| {
+                let chunk: Chunk = serde_json::from_str(line).context("ollama response parse")?;
+                if let Some(err) = chunk.error {
+                    anyhow::bail!("ollama error: {err}");
+                }
+                output.push_str(&chunk.response);
+                on_chunk(&chunk.response);
+                Ok(!chunk.done)
+            })?;
+            Ok(output)
+        })?;
+
+        Ok(LlmResponse { output, provider: "ollama".to_string() })
     }
 }
 
@@ -75,15 +139,27 @@ pub struct OpenAiCompatAdapter {
     pub provider_name: String,
     pub base_url: String,
     pub model: String,
+    pub options: LlmClientOptions,
 }
 
 impl LlmAdapter for OpenAiCompatAdapter {
     fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        self.rewrite_streaming(req, &mut |_| {})
+    }
+
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
         #[derive(Serialize)]
         struct ChatBody<'a> {
             model: &'a str,
             messages: Vec<Message<'a>>,
             temperature: f32,
+            stream: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            response_format: Option<serde_json::Value>,
         }
         #[derive(Serialize)]
         struct Message<'a> {
@@ -104,34 +180,168 @@ impl LlmAdapter for OpenAiCompatAdapter {
             content: String,
         }
 
-        let client = llm_http_client()?;
+        #[derive(Deserialize)]
+        struct StreamChunk {
+            choices: Vec<StreamChoice>,
+        }
+        #[derive(Deserialize)]
+        struct StreamChoice {
+            #[serde(default)]
+            delta: Delta,
+        }
+        #[derive(Deserialize, Default)]
+        struct Delta {
+            #[serde(default)]
+            content: Option<String>,
+        }
+
+        let client = self.options.http_client()?;
         let url = format!("{}/v1/chat/completions", self.base_url.trim_end_matches('/'));
-        let resp = client
-            .post(&url)
-            .json(&ChatBody {
-                model: &self.model,
-                messages: vec![Message { role: "user", content: &req.prompt }],
-                temperature: 0.0,
-            })
-            .send()
-            .with_context(|| format!("request to {url}"))?
-            .error_for_status()
-            .context("openai-compatible non-2xx")?
-            .json::<ChatResp>()
-            .context("openai-compatible response parse")?;
+        let body = ChatBody {
+            model: &self.model,
+            messages: vec![Message { role: "user", content: &req.prompt }],
+            temperature: 0.0,
+            stream: self.options.stream,
+            response_format: match &req.constraint {
+                Some(OutputConstraint::JsonSchema(schema)) => Some(serde_json::json!({
+                    "type": "json_schema",
+                    "json_schema": { "name": req.task.as_str(), "strict": true, "schema": schema },
+                })),
+                Some(OutputConstraint::Grammar(_)) | None => None,
+            },
+        };
+        let output = with_retries(&self.options, || {
+            let resp = send(client.post(&url).json(&body), &url)?;
+            if !is_event_stream(&resp) {
+                let parsed = resp
+                    .json::<ChatResp>()
+                    .context("openai-compatible response parse")
+                    .map_err(AttemptError::Fatal)?;
+                let output =
+                    parsed.choices.first().map(|c| c.message.content.clone()).unwrap_or_default();
+                on_chunk(&output);
+                return Ok(output);
+            }
 
-        let output = resp.choices.first().map(|c| c.message.content.clone()).unwrap_or_default();
+            let mut output = String::new();
+            for_each_line(resp, &self.options, |line| {
+                let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+                    return Ok(true);
+                };
+                if data == "[DONE]" {
+                    return Ok(false);
+                }
+                let chunk: StreamChunk =
+                    serde_json::from_str(data).context("openai-compatible stream parse")?;
+                if let Some(text) = chunk.choices.first().and_then(|c| c.delta.content.as_deref()) {
+                    output.push_str(text);
+                    on_chunk(text);
+                }
+                Ok(true)
+            })?;
+            Ok(output)
+        })?;
 
         Ok(LlmResponse { output, provider: self.provider_name.clone() })
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct LlamaCppAdapter {
+    pub base_url: String,
+    pub n_predict: i32,
+    pub seed: Option<u64>,
+    pub stop: Vec<String>,
+    pub options: LlmClientOptions,
+}
+
+impl LlmAdapter for LlamaCppAdapter {
+    fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        self.rewrite_streaming(req, &mut |_| {})
+    }
+
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            prompt: &'a str,
+            n_predict: i32,
+            temperature: f32,
+            stream: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            seed: Option<u64>,
+            #[serde(skip_serializing_if = "<[String]>::is_empty")]
+            stop: &'a [String],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            json_schema: Option<&'a serde_json::Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            grammar: Option<&'a str>,
+        }
+        #[derive(Deserialize)]
+        struct Completion {
+            #[serde(default)]
+            content: String,
+            #[serde(default)]
+            stop: bool,
+        }
+
+        let (json_schema, grammar) = match &req.constraint {
+            Some(OutputConstraint::JsonSchema(schema)) => (Some(schema), None),
+            Some(OutputConstraint::Grammar(grammar)) => (None, Some(grammar.as_str())),
+            None => (None, None),
+        };
+        let client = self.options.http_client()?;
+        let url = format!("{}/completion", self.base_url.trim_end_matches('/'));
+        let body = Body {
+            prompt: &req.prompt,
+            n_predict: self.n_predict,
+            temperature: 0.0,
+            stream: self.options.stream,
+            seed: self.seed,
+            stop: &self.stop,
+            json_schema,
+            grammar,
+        };
+        let output = with_retries(&self.options, || {
+            let resp = send(client.post(&url).json(&body), &url)?;
+            if !is_event_stream(&resp) {
+                let parsed = resp
+                    .json::<Completion>()
+                    .context("llama.cpp response parse")
+                    .map_err(AttemptError::Fatal)?;
+                on_chunk(&parsed.content);
+                return Ok(parsed.content);
+            }
+
+            let mut output = String::new();
+            for_each_line(resp, &self.options, |line| {
+                let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+                    return Ok(true);
+                };
+                let chunk: Completion =
+                    serde_json::from_str(data).context("llama.cpp stream parse")?;
+                output.push_str(&chunk.content);
+                on_chunk(&chunk.content);
+                Ok(!chunk.stop)
+            })?;
+            Ok(output)
+        })?;
+
+        Ok(LlmResponse { output, provider: "llamacpp".to_string() })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::{Read, Write};
     use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
     use std::thread;
+    use std::time::Duration;
 
     fn spawn_json_server(response_body: &'static str) -> String {
         let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
@@ -152,14 +362,79 @@ mod tests {
         format!("http://{}", addr)
     }
 
+    /// Reads one HTTP request and returns its body.
+    fn read_request_body(stream: &mut std::net::TcpStream) -> String {
+        let mut raw = Vec::new();
+        let mut buf = [0_u8; 4096];
+        while let Ok(n) = stream.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            raw.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&raw).to_string();
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())
+                            .flatten()
+                    })
+                    .unwrap_or(0);
+                if raw.len() >= header_end + 4 + content_length {
+                    return text[header_end + 4..].to_string();
+                }
+            }
+        }
+        String::new()
+    }
+
+    /// Serves one scripted `(status line, content type, body)` reply per connection, in order,
+    /// recording each request body.
+    fn spawn_scripted_server(
+        replies: Vec<(&'static str, &'static str, &'static str)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, content_type, body) in replies {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let request_body = read_request_body(&mut stream);
+                recorded.lock().expect("requests lock").push(request_body);
+                let header = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(body.as_bytes());
+            }
+        });
+        (format!("http://{}", addr), requests)
+    }
+
+    fn hello_request() -> LlmRequest {
+        LlmRequest { task: LlmTask::RewriteMessage, prompt: "hello".to_string(), constraint: None }
+    }
+
+    fn fast_retry_options() -> LlmClientOptions {
+        LlmClientOptions { retry_backoff: Duration::from_millis(1), ..LlmClientOptions::default() }
+    }
+
     #[test]
     fn ollama_adapter_parses_response() {
         let base = spawn_json_server(r#"{ "response": "rewritten text" }"#);
-        let adapter = OllamaAdapter { base_url: base, model: "llama3.2".to_string() };
+        let adapter = OllamaAdapter {
+            base_url: base,
+            model: "llama3.2".to_string(),
+            options: LlmClientOptions::default(),
+        };
 
-        let response = adapter
-            .rewrite(&LlmRequest { task: LlmTask::RewriteMessage, prompt: "hello".to_string() })
-            .expect("rewrite");
+        let response = adapter.rewrite(&hello_request()).expect("rewrite");
 
         assert_eq!(response.output, "rewritten text");
         assert_eq!(response.provider, "ollama");
@@ -174,13 +449,331 @@ mod tests {
             provider_name: "lm_studio".to_string(),
             base_url: base,
             model: "local".to_string(),
+            options: LlmClientOptions::default(),
+        };
+
+        let response = adapter.rewrite(&hello_request()).expect("rewrite");
+
+        assert_eq!(response.output, "rewritten text");
+        assert_eq!(response.provider, "lm_studio");
+    }
+
+    #[test]
+    fn ollama_adapter_assembles_streamed_chunks() {
+        let (base, _) = spawn_scripted_server(vec![(
+            "200 OK",
+            "application/x-ndjson",
+            "{\"response\":\"rewritten \",\"done\":false}\n{\"response\":\"text\",\"done\":false}\n{\"response\":\"\",\"done\":true}\n",
+        )]);
+        let adapter = OllamaAdapter {
+            base_url: base,
+            model: "llama3.2".to_string(),
+            options: LlmClientOptions::default(),
         };
 
+        let mut chunks = Vec::new();
         let response = adapter
-            .rewrite(&LlmRequest { task: LlmTask::RewriteMessage, prompt: "hello".to_string() })
+            .rewrite_streaming(&hello_request(), &mut |chunk| chunks.push(chunk.to_string()))
             .expect("rewrite");
 
         assert_eq!(response.output, "rewritten text");
-        assert_eq!(response.provider, "lm_studio");
+        assert_eq!(chunks, vec!["rewritten ", "text", ""]);
+    }
+
+    #[test]
+    fn openai_compat_assembles_event_stream() {
+        let (base, _) = spawn_scripted_server(vec![(
+            "200 OK",
+            "text/event-stream",
+            "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\"}}]}\n\ndata: {\"choices\":[{\"delta\":{\"content\":\"rewritten \"}}]}\n\ndata: {\"choices\":[{\"delta\":{\"content\":\"text\"}}]}\n\ndata: [DONE]\n\n",
+        )]);
+        let adapter = OpenAiCompatAdapter {
+            provider_name: "lm_studio".to_string(),
+            base_url: base,
+            model: "local".to_string(),
+            options: LlmClientOptions::default(),
+        };
+
+        let response = adapter.rewrite(&hello_request()).expect("rewrite");
+
+        assert_eq!(response.output, "rewritten text");
+    }
+
+    #[test]
+    fn retries_server_errors_then_succeeds() {
+        let (base, requests) = spawn_scripted_server(vec![
+            ("503 Service Unavailable", "text/plain", "loading model"),
+            ("200 OK", "application/json", r#"{ "response": "rewritten text" }"#),
+        ]);
+        let adapter = OllamaAdapter {
+            base_url: base,
+            model: "llama3.2".to_string(),
+            options: fast_retry_options(),
+        };
+
+        let response = adapter.rewrite(&hello_request()).expect("rewrite after retry");
+
+        assert_eq!(response.output, "rewritten text");
+        assert_eq!(requests.lock().expect("requests lock").len(), 2);
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let (base, requests) = spawn_scripted_server(vec![
+            ("404 Not Found", "text/plain", "model not found"),
+            ("200 OK", "application/json", r#"{ "response": "unexpected" }"#),
+        ]);
+        let adapter = OllamaAdapter {
+            base_url: base,
+            model: "missing".to_string(),
+            options: fast_retry_options(),
+        };
+
+        let err = adapter.rewrite(&hello_request()).expect_err("404 should fail");
+
+        assert!(err.to_string().contains("404"));
+        assert_eq!(requests.lock().expect("requests lock").len(), 1);
+    }
+
+    #[test]
+    fn cancelled_token_aborts_before_request() {
+        let (base, requests) = spawn_scripted_server(vec![(
+            "200 OK",
+            "application/json",
+            r#"{ "response": "unexpected" }"#,
+        )]);
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let adapter = OllamaAdapter {
+            base_url: base,
+            model: "llama3.2".to_string(),
+            options: LlmClientOptions { cancel: Some(cancel), ..fast_retry_options() },
+        };
+
+        let err = adapter.rewrite(&hello_request()).expect_err("cancelled");
+
+        assert!(is_cancelled_error(&err));
+        assert_eq!(requests.lock().expect("requests lock").len(), 0);
+    }
+
+    #[test]
+    fn llamacpp_sends_schema_and_sampling_options() {
+        let (base, requests) = spawn_scripted_server(vec![(
+            "200 OK",
+            "application/json",
+            r#"{ "content": "{\"keywords\":[]}", "stop": true }"#,
+        )]);
+        let adapter = LlamaCppAdapter {
+            base_url: base,
+            n_predict: 256,
+            seed: Some(42),
+            stop: vec!["</s>".to_string()],
+            options: LlmClientOptions { stream: false, ..LlmClientOptions::default() },
+        };
+        let schema = serde_json::json!({ "type": "object" });
+
+        let response = adapter
+            .rewrite(&LlmRequest {
+                task: LlmTask::SummarizeJd,
+                prompt: "summarize".to_string(),
+                constraint: Some(OutputConstraint::JsonSchema(schema.clone())),
+            })
+            .expect("completion");
+
+        assert_eq!(response.output, r#"{"keywords":[]}"#);
+        assert_eq!(response.provider, "llamacpp");
+        let sent: serde_json::Value =
+            serde_json::from_str(&requests.lock().expect("requests lock")[0]).expect("json body");
+        assert_eq!(sent["json_schema"], schema);
+        assert_eq!(sent["n_predict"], 256);
+        assert_eq!(sent["seed"], 42);
+        assert_eq!(sent["stop"], serde_json::json!(["</s>"]));
+        assert!(sent.get("grammar").is_none());
+    }
+
+    #[test]
+    fn llamacpp_assembles_event_stream() {
+        let (base, _) = spawn_scripted_server(vec![(
+            "200 OK",
+            "text/event-stream",
+            "data: {\"content\":\"rewritten \",\"stop\":false}\n\ndata: {\"content\":\"text\",\"stop\":false}\n\ndata: {\"content\":\"\",\"stop\":true}\n\n",
+        )]);
+        let adapter = LlamaCppAdapter {
+            base_url: base,
+            n_predict: 128,
+            seed: None,
+            stop: Vec::new(),
+            options: LlmClientOptions::default(),
+        };
+
+        let response = adapter.rewrite(&hello_request()).expect("completion");
+
+        assert_eq!(response.output, "rewritten text");
+    }
+
+    #[test]
+    fn json_schema_constraint_maps_to_provider_fields() {
+        let schema = serde_json::json!({ "type": "object" });
+        let request = LlmRequest {
+            task: LlmTask::SummarizeJd,
+            prompt: "summarize".to_string(),
+            constraint: Some(OutputConstraint::JsonSchema(schema.clone())),
+        };
+
+        let (ollama_base, ollama_requests) = spawn_scripted_server(vec![(
+            "200 OK",
+            "application/x-ndjson",
+            "{\"response\":\"{}\",\"done\":true}\n",
+        )]);
+        OllamaAdapter {
+            base_url: ollama_base,
+            model: "llama3.2".to_string(),
+            options: LlmClientOptions::default(),
+        }
+        .rewrite(&request)
+        .expect("ollama");
+        let sent: serde_json::Value =
+            serde_json::from_str(&ollama_requests.lock().expect("requests lock")[0])
+                .expect("ollama body");
+        assert_eq!(sent["format"], schema);
+
+        let (openai_base, openai_requests) = spawn_scripted_server(vec![(
+            "200 OK",
+            "application/json",
+            r#"{"choices":[{"message":{"content":"{}"}}]}"#,
+        )]);
+        OpenAiCompatAdapter {
+            provider_name: "lm_studio".to_string(),
+            base_url: openai_base,
+            model: "local-model".to_string(),
+            options: LlmClientOptions::default(),
+        }
+        .rewrite(&request)
+        .expect("openai");
+        let sent: serde_json::Value =
+            serde_json::from_str(&openai_requests.lock().expect("requests lock")[0])
+                .expect("openai body");
+        assert_eq!(sent["response_format"]["type"], "json_schema");
+        assert_eq!(sent["response_format"]["json_schema"]["name"], "summarize_jd");
+        assert_eq!(sent["response_format"]["json_schema"]["schema"], schema);
+    }
+
+    struct CountingAdapter {
+        calls: Arc<Mutex<usize>>,
+    }
+
+    impl LlmAdapter for CountingAdapter {
+        fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+            let mut calls = self.calls.lock().expect("calls lock");
+            *calls += 1;
+            Ok(LlmResponse {
+                output: format!("{} #{}", req.prompt, *calls),
+                provider: "counting".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn cached_adapter_replays_identical_requests() {
+        let dir = tempfile::tempdir().expect("cache dir");
+        let calls = Arc::new(Mutex::new(0));
+        let cached = |seed: Option<u64>| CachedAdapter {
+            inner: Box::new(CountingAdapter { calls: Arc::clone(&calls) }),
+            cache: LlmCache::new(dir.path()),
+            identity: CacheIdentity {
+                provider: "ollama".to_string(),
+                model: "llama3.2".to_string(),
+                seed,
+            },
+        };
+
+        let first = cached(Some(42)).rewrite(&hello_request()).expect("first");
+        let replayed = cached(Some(42)).rewrite(&hello_request()).expect("replayed");
+        assert_eq!(first.output, "hello #1");
+        assert_eq!(replayed.output, first.output);
+        assert_eq!(*calls.lock().expect("calls lock"), 1);
+
+        let reseeded = cached(Some(7)).rewrite(&hello_request()).expect("reseeded");
+        assert_eq!(reseeded.output, "hello #2");
+
+        assert_eq!(LlmCache::new(dir.path()).clear().expect("clear"), 2);
+        let refreshed = cached(Some(42)).rewrite(&hello_request()).expect("refreshed");
+        assert_eq!(refreshed.output, "hello #3");
+    }
+
+    #[test]
+    fn cached_adapter_returns_response_when_cache_write_fails() {
+        let dir = tempfile::tempdir().expect("cache dir");
+        // A file where the cache directory should be makes every write fail.
+        let blocked = dir.path().join("llm_cache");
+        std::fs::write(&blocked, "not a directory").expect("block");
+        let calls = Arc::new(Mutex::new(0));
+        let cached = CachedAdapter {
+            inner: Box::new(CountingAdapter { calls: Arc::clone(&calls) }),
+            cache: LlmCache::new(&blocked),
+            identity: CacheIdentity {
+                provider: "ollama".to_string(),
+                model: "llama3.2".to_string(),
+                seed: None,
+            },
+        };
+        assert_eq!(cached.rewrite(&hello_request()).expect("uncached").output, "hello #1");
+        assert_eq!(cached.rewrite(&hello_request()).expect("still uncached").output, "hello #2");
+    }
+
+    #[test]
+    fn recorded_responses_replay_by_prompt_with_task_fallback() {
+        let dir = tempfile::tempdir().expect("fixtures dir");
+        let calls = Arc::new(Mutex::new(0));
+        let recorder = RecordingAdapter {
+            inner: Box::new(CountingAdapter { calls: Arc::clone(&calls) }),
+            dir: dir.path().to_path_buf(),
+        };
+        let recorded = recorder.rewrite(&hello_request()).expect("record");
+        assert!(replay_fixture_path(dir.path(), &hello_request()).is_file());
+
+        let replay = ReplayAdapter { dir: dir.path().to_path_buf() };
+        let replayed = replay.rewrite(&hello_request()).expect("replay recorded");
+        assert_eq!(replayed.output, recorded.output);
+        assert_eq!(replayed.provider, "replay");
+
+        let other = LlmRequest { prompt: "something else".to_string(), ..hello_request() };
+        let missing = replay.rewrite(&other).expect_err("no fixture");
+        assert!(missing.to_string().contains("no replay fixture for task rewrite_message"));
+
+        std::fs::write(dir.path().join("rewrite_message.txt"), "canned").expect("fallback");
+        assert_eq!(replay.rewrite(&other).expect("fallback").output, "canned");
+        assert_eq!(*calls.lock().expect("calls lock"), 1);
+    }
+
+    #[test]
+    fn health_check_lists_models_and_matches_configured_model() {
+        let ollama_base =
+            spawn_json_server(r#"{"models":[{"name":"qwen2.5:7b"},{"name":"llama3.2:latest"}]}"#);
+        let report = check_health("ollama", &ollama_base, "llama3.2", &LlmClientOptions::default());
+        assert!(report.reachable);
+        assert!(report.latency_ms.is_some());
+        assert_eq!(report.models, vec!["llama3.2:latest".to_string(), "qwen2.5:7b".to_string()]);
+        assert!(report.model_available);
+
+        let openai_base = spawn_json_server(r#"{"data":[{"id":"local-model"}]}"#);
+        let report =
+            check_health("lm_studio", &openai_base, "other-model", &LlmClientOptions::default());
+        assert!(report.reachable);
+        assert_eq!(report.models, vec!["local-model".to_string()]);
+        assert!(!report.model_available);
+    }
+
+    #[test]
+    fn health_check_reports_unreachable_provider() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base = format!("http://{}", listener.local_addr().expect("addr"));
+        drop(listener);
+
+        let report = check_health("ollama", &base, "llama3.2", &LlmClientOptions::default());
+
+        assert!(!report.reachable);
+        assert!(report.latency_ms.is_none());
+        assert!(report.error.is_some());
     }
 }
@@ -0,0 +1,63 @@
+use crate::cache::sha256_hex;
+use crate::{LlmAdapter, LlmRequest, LlmResponse};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+/// Path of the prompt-specific fixture for `req` under `dir`: `<task>/<prompt sha256>.txt`.
+pub fn replay_fixture_path(dir: &Path, req: &LlmRequest) -> PathBuf {
+    dir.join(req.task.as_str()).join(format!("{}.txt", sha256_hex(req.prompt.as_bytes())))
+}
+
+/// Serves canned responses from a fixtures directory instead of calling a provider.
+///
+/// Lookup order: the prompt-specific fixture written by [`RecordingAdapter`], then a
+/// per-task fallback `<task>.txt` that answers every prompt for that task.
+#[derive(Debug, Clone)]
+pub struct ReplayAdapter {
+    pub dir: PathBuf,
+}
+
+impl LlmAdapter for ReplayAdapter {
+    fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        let exact = replay_fixture_path(&self.dir, req);
+        let fallback = self.dir.join(format!("{}.txt", req.task.as_str()));
+        let path = [exact, fallback].into_iter().find(|p| p.is_file()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "no replay fixture for task {} in {}",
+                req.task.as_str(),
+                self.dir.display()
+            )
+        })?;
+        let output = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        Ok(LlmResponse { output, provider: "replay".to_string() })
+    }
+}
+
+/// Forwards to a real adapter and saves each response as a prompt-specific replay fixture.
+pub struct RecordingAdapter {
+    pub inner: Box<dyn LlmAdapter>,
+    pub dir: PathBuf,
+}
+
+impl LlmAdapter for RecordingAdapter {
+    fn rewrite(&self, req: &LlmRequest) -> anyhow::Result<LlmResponse> {
+        self.rewrite_streaming(req, &mut |_| {})
+    }
+
+    fn rewrite_streaming(
+        &self,
+        req: &LlmRequest,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<LlmResponse> {
+        let response = self.inner.rewrite_streaming(req, on_chunk)?;
+        let path = replay_fixture_path(&self.dir, req);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating {}", parent.display()))?;
+        }
+        std::fs::write(&path, &response.output)
+            .with_context(|| format!("writing {}", path.display()))?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::Context;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::StatusCode;
+use std::io::{BufRead, BufReader};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Shared flag used to abort an in-flight generation from another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+/// Error returned when a request is aborted through a [`CancelToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LlmCancelled;
+
+impl std::fmt::Display for LlmCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "llm request cancelled")
+    }
+}
+
+impl std::error::Error for LlmCancelled {}
+
+pub fn is_cancelled_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| cause.downcast_ref::<LlmCancelled>().is_some())
+}
+
+#[derive(Debug, Clone)]
+pub struct LlmClientOptions {
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub max_retries: u32,
+    pub retry_backoff: Duration,
+    pub stream: bool,
+    pub cancel: Option<CancelToken>,
+}
+
+impl Default for LlmClientOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(120),
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(500),
+            stream: true,
+            cancel: None,
+        }
+    }
+}
+
+impl LlmClientOptions {
+    pub fn ensure_not_cancelled(&self) -> anyhow::Result<()> {
+        match &self.cancel {
+            Some(token) if token.is_cancelled() => Err(LlmCancelled.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn http_client(&self) -> anyhow::Result<Client> {
+        Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.request_timeout)
+            .build()
+            .context("building llm HTTP client")
+    }
+
+    /// Sleeps for the backoff of `attempt` (exponential), waking early on cancellation.
+    fn backoff(&self, attempt: u32) -> anyhow::Result<()> {
+        let delay = self.retry_backoff.saturating_mul(1 << attempt.min(6));
+        let deadline = Instant::now() + delay;
+        loop {
+            self.ensure_not_cancelled()?;
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            std::thread::sleep((deadline - now).min(Duration::from_millis(50)));
+        }
+    }
+}
+
+pub(crate) enum AttemptError {
+    Retryable(anyhow::Error),
+    Fatal(anyhow::Error),
+}
+
+/// Runs `attempt` up to `max_retries + 1` times, backing off between retryable failures.
+pub(crate) fn with_retries<T>(
+    options: &LlmClientOptions,
+    mut attempt: impl FnMut() -> Result<T, AttemptError>,
+) -> anyhow::Result<T> {
+    let mut tries = 0;
+    loop {
+        options.ensure_not_cancelled()?;
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(AttemptError::Fatal(err)) => return Err(err),
+            Err(AttemptError::Retryable(err)) => {
+                if tries >= options.max_retries {
+                    return Err(err.context(format!("giving up after {} attempts", tries + 1)));
+                }
+                options.backoff(tries)?;
+                tries += 1;
+            }
+        }
+    }
+}
+
+/// Sends the request and classifies transport and status failures as retryable or fatal.
+pub(crate) fn send(request: RequestBuilder, url: &str) -> Result<Response, AttemptError> {
+    let resp = request.send().map_err(|err| {
+        // A refused connection means the server is not running; retrying will not help.
+        let connect = err.is_connect();
+        let err = anyhow::Error::new(err).context(format!("request to {url}"));
+        if connect {
+            AttemptError::Fatal(err)
+        } else {
+            AttemptError::Retryable(err)
+        }
+    })?;
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+    let err = anyhow::anyhow!("{url} returned {status}");
+    if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        Err(AttemptError::Retryable(err))
+    } else {
+        Err(AttemptError::Fatal(err))
+    }
+}
+
+pub(crate) fn is_event_stream(resp: &Response) -> bool {
+    resp.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.starts_with("text/event-stream"))
+        .unwrap_or(false)
+}
+
+/// Reads a newline-delimited body, handing each non-empty line to `on_line` until it
+/// returns `false`. Cancellation is checked between lines.
+pub(crate) fn for_each_line(
+    resp: Response,
+    options: &LlmClientOptions,
+    mut on_line: impl FnMut(&str) -> anyhow::Result<bool>,
+) -> Result<(), AttemptError> {
+    let mut reader = BufReader::new(resp);
+    let mut line = String::new();
+    loop {
+        options.ensure_not_cancelled().map_err(AttemptError::Fatal)?;
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .context("reading streamed llm response")
+            .map_err(AttemptError::Retryable)?;
+        if read == 0 {
+            return Ok(());
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !on_line(trimmed).map_err(AttemptError::Fatal)? {
+            return Ok(());
+        }
+    }
+}
@@ -35,6 +35,7 @@ const checks = [
     file: ".codex/verify.commands",
     contains: [
       "node scripts/ci/check-ci-parity.mjs",
+      "cargo run -p applykit_cli -- bank lint",
       "pnpm -C ui audit --audit-level high",
       "pnpm -C ui test:e2e:a11y",
       "./.codex/scripts/run_coverage.sh",
@@ -1,24 +1,39 @@
+use applykit_core::banks::load_banks;
 use applykit_core::config::{
     load_config, load_runtime_settings, merge_config_with_runtime, resolve_output_base,
     save_runtime_settings, validate_local_llm_base_url, RuntimeSettings,
 };
-use applykit_core::insights::build_insights;
+use applykit_core::insights::{collect_insights, Funnel, GapKind, TermKind};
 use applykit_core::pipeline::{
-    list_packets_from_fs, read_packet_detail, read_packet_detail_by_job_id,
+    check_llm_health, clear_llm_cache, hash_jd, list_packets_from_fs, read_packet_detail,
+    read_packet_detail_by_job_id,
 };
 use applykit_core::source_preview::{
     create_bullet_value, create_skill_value, load_banks_preview, load_templates_preview,
     save_bullet_text_value, save_template_value, set_bullet_approved_value,
     set_skill_approved_value, set_skill_level_value,
 };
-use applykit_core::storage::{list_jobs, update_job_status};
+use applykit_core::storage::{
+    add_contact, add_interview, delete_contact, delete_interview, find_duplicate_jobs,
+    get_job_by_id, has_jobs, list_contacts, list_interviews, list_job_events, list_jobs,
+    query_jobs, record_export_event, search_jobs, update_contact, update_interview,
+    update_job_status, Contact, ContactInput, ContactRole, DuplicateJob, Interview, InterviewInput,
+    JobQuery, JobSortKey, JobStatusUpdate, Store,
+};
 use applykit_core::types::{Baseline, GenerateInput, Track};
-use applykit_core::{generate_packet, GenerateOptions};
+use applykit_core::{
+    backup_database, check_database, collect_due, collect_tracker_export, default_backup_path,
+    generate_packet, import_tracker_csv, reminders_to_ics, tracker_export_csv, CancelToken,
+    DueReport, GenerateOptions, ImportIssue, Reminder, TrackerImportOptions,
+};
 use applykit_export::{export_docx, export_markdown_bundle, export_pdf};
-use chrono::NaiveDate;
+use chrono::{Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -32,6 +47,93 @@ struct GeneratePacketInput {
     run_date: Option<String>,
     track_override: Option<String>,
     allow_unapproved: Option<bool>,
+    bypass_llm_cache: Option<bool>,
+    regenerate_job_id: Option<String>,
+    /// Caller-chosen id for this run, used to cancel it; one is assigned when omitted.
+    run_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelGenerationInput {
+    run_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportTrackerInput {
+    format: String,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DatabaseInput {
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MissingPacketResponse {
+    job_id: String,
+    output_dir: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DatabaseCheckResponse {
+    ok: bool,
+    integrity: Vec<String>,
+    schema_version: i64,
+    latest_version: i64,
+    orphaned: BTreeMap<String, i64>,
+    missing_packets: Vec<MissingPacketResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportTrackerInput {
+    csv_text: String,
+    mapping: Option<BTreeMap<String, String>>,
+    dry_run: Option<bool>,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportIssueResponse {
+    line: usize,
+    company: String,
+    role: String,
+    reason: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportTrackerResponse {
+    rows: usize,
+    mapping: BTreeMap<String, String>,
+    created: Vec<String>,
+    duplicates: Vec<ImportIssueResponse>,
+    errors: Vec<ImportIssueResponse>,
+    dry_run: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchJobsInput {
+    query: String,
+    limit: Option<usize>,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FindDuplicatesInput {
+    company: String,
+    role: String,
+    jd_text: String,
+    outdir: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -122,6 +224,7 @@ struct PacketDetailResponse {
     packet_dir: String,
     extraction_source: String,
     extraction_diagnostics: ExtractionDiagnosticsResponse,
+    prompt_versions: BTreeMap<String, String>,
     extracted_keywords: Vec<String>,
     extracted_tools: Vec<String>,
     extracted_requirements: Vec<String>,
@@ -142,19 +245,45 @@ struct PacketDetailResponse {
 #[serde(rename_all = "camelCase")]
 struct ExtractionDiagnosticsResponse {
     summarize_attempted: bool,
+    summarize_repair_attempted: bool,
     summarize_merged: bool,
     summarize_fallback_reasons: Vec<String>,
+    injection_warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GeneratePacketResponse {
+    run_id: String,
     packet_dir: String,
     fit_total: u8,
     track: String,
     files_written: Vec<String>,
     truth_passed: bool,
     packet_detail: PacketDetailResponse,
+    duplicate_jobs: Vec<DuplicateJobResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchHitResponse {
+    job_id: String,
+    company: String,
+    role: String,
+    status: String,
+    score: f64,
+    snippet: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateJobResponse {
+    job_id: String,
+    company: String,
+    role: String,
+    status: String,
+    created_at: String,
+    reason: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -172,18 +301,35 @@ struct JobSummary {
     notes: Option<String>,
     output_dir: Option<String>,
     updated_at: String,
+    status_entered_at: Option<String>,
+    closed_reason: Option<String>,
+    next_action_due: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ListJobsInput {
     outdir: Option<String>,
+    statuses: Option<Vec<String>>,
+    track: Option<String>,
+    source: Option<String>,
+    company: Option<String>,
+    fit_min: Option<i64>,
+    fit_max: Option<i64>,
+    updated_from: Option<String>,
+    updated_to: Option<String>,
+    sort: Option<String>,
+    ascending: Option<bool>,
+    limit: Option<usize>,
+    offset: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ListJobsResponse {
     jobs: Vec<JobSummary>,
+    total: usize,
+    next_offset: Option<usize>,
     fallback_packet_dirs: Vec<String>,
 }
 
@@ -193,10 +339,123 @@ struct UpdateJobStatusInput {
     id: String,
     status: String,
     next_action: Option<String>,
+    next_action_due: Option<String>,
     notes: Option<String>,
+    closed_reason: Option<String>,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DueActionsInput {
+    days: Option<i64>,
     outdir: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobTimelineInput {
+    id: String,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobEventResponse {
+    id: String,
+    job_id: String,
+    event_type: String,
+    payload: serde_json::Value,
+    created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobTimelineResponse {
+    events: Vec<JobEventResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobPeopleInput {
+    job_id: String,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveContactInput {
+    /// Updates this contact when set; otherwise adds a new one.
+    id: Option<i64>,
+    job_id: String,
+    role: String,
+    name: String,
+    email: Option<String>,
+    notes: Option<String>,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveInterviewInput {
+    /// Updates this round when set; otherwise adds a new one.
+    id: Option<i64>,
+    job_id: String,
+    scheduled_at: String,
+    kind: String,
+    #[serde(default)]
+    interviewers: Vec<String>,
+    outcome: Option<String>,
+    notes: Option<String>,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteRecordInput {
+    id: i64,
+    outdir: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContactResponse {
+    id: i64,
+    job_id: String,
+    role: String,
+    name: String,
+    email: Option<String>,
+    notes: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InterviewResponse {
+    id: i64,
+    job_id: String,
+    scheduled_at: String,
+    kind: String,
+    interviewers: Vec<String>,
+    outcome: Option<String>,
+    notes: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobPeopleResponse {
+    contacts: Vec<ContactResponse>,
+    interviews: Vec<InterviewResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteRecordResponse {
+    ok: bool,
+    removed: bool,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateJobStatusResponse {
@@ -205,14 +464,98 @@ struct UpdateJobStatusResponse {
     status: String,
     next_action: Option<String>,
     notes: Option<String>,
+    closed_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReminderResponse {
+    job_id: String,
+    company: String,
+    role: String,
+    status: String,
+    action: String,
+    due: String,
+    kind: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DueActionsResponse {
+    today: String,
+    overdue: Vec<ReminderResponse>,
+    upcoming: Vec<ReminderResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusWorkflowResponse {
+    statuses: Vec<String>,
+    closed_statuses: Vec<String>,
+    transitions: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FunnelResponse {
+    key: String,
+    applied: usize,
+    replied: usize,
+    interviewed: usize,
+    offered: usize,
+    reply_rate: f64,
+    interview_rate: f64,
+    offer_rate: f64,
+}
+
+impl From<Funnel> for FunnelResponse {
+    fn from(f: Funnel) -> Self {
+        Self {
+            key: f.key,
+            applied: f.applied,
+            replied: f.replied,
+            interviewed: f.interviewed,
+            offered: f.offered,
+            reply_rate: f.reply_rate,
+            interview_rate: f.interview_rate,
+            offer_rate: f.offer_rate,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TermOutcomeResponse {
+    term: String,
+    kind: TermKind,
+    jobs: usize,
+    positive: usize,
+    positive_rate: f64,
+    lift: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MissingSkillResponse {
+    skill: String,
+    kind: GapKind,
+    jobs: usize,
+    unapproved: bool,
+    example: String,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct InsightsResponse {
-    replies_by_track: Vec<(String, usize)>,
-    common_gaps: Vec<(String, usize)>,
-    keyword_correlations: Vec<(String, usize)>,
+    overall: FunnelResponse,
+    by_track: Vec<FunnelResponse>,
+    by_source: Vec<FunnelResponse>,
+    by_baseline: Vec<FunnelResponse>,
+    by_fit_bucket: Vec<FunnelResponse>,
+    median_days_to_reply: Option<f64>,
+    reply_samples: usize,
+    missing_skills: Vec<MissingSkillResponse>,
+    keyword_correlations: Vec<TermOutcomeResponse>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -272,6 +615,10 @@ struct TemplatesPreviewResponse {
     recruiter_template: String,
     hiring_manager_template: String,
     cover_short_template: String,
+    summarize_jd_prompt: String,
+    summarize_jd_repair_prompt: String,
+    rewrite_message_prompt: String,
+    rewrite_bullet_prompt: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -279,6 +626,7 @@ struct TemplatesPreviewResponse {
 struct SetBulletApprovedInput {
     id: String,
     approved: bool,
+    create_pending_skills: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -321,6 +669,7 @@ struct CreateBulletInput {
     tags: Option<Vec<String>>,
     tools: Option<Vec<String>>,
     approved: Option<bool>,
+    create_pending_skills: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -337,10 +686,16 @@ struct MutationResponse {
     ok: bool,
     message: String,
     updated_at: String,
+    warnings: Vec<String>,
 }
 
 fn to_mutation_response(resp: applykit_core::MutationResponse) -> MutationResponse {
-    MutationResponse { ok: resp.ok, message: resp.message, updated_at: resp.updated_at }
+    MutationResponse {
+        ok: resp.ok,
+        message: resp.message,
+        updated_at: resp.updated_at,
+        warnings: resp.warnings,
+    }
 }
 
 fn to_packet_detail_response(detail: applykit_core::types::PacketDetail) -> PacketDetailResponse {
@@ -352,9 +707,12 @@ fn to_packet_detail_response(detail: applykit_core::types::PacketDetail) -> Pack
         },
         extraction_diagnostics: ExtractionDiagnosticsResponse {
             summarize_attempted: detail.extraction_diagnostics.summarize_attempted,
+            summarize_repair_attempted: detail.extraction_diagnostics.summarize_repair_attempted,
             summarize_merged: detail.extraction_diagnostics.summarize_merged,
             summarize_fallback_reasons: detail.extraction_diagnostics.summarize_fallback_reasons,
+            injection_warnings: detail.extraction_diagnostics.injection_warnings,
         },
+        prompt_versions: detail.prompt_versions,
         extracted_keywords: detail.extracted.keywords,
         extracted_tools: detail.extracted.tools,
         extracted_requirements: detail.extracted.requirements,
@@ -578,8 +936,105 @@ fn ensure_non_empty_text(name: &str, value: &str) -> Result<(), String> {
     Ok(())
 }
 
-#[tauri::command]
-fn generate_packet_cmd(input: GeneratePacketInput) -> Result<GeneratePacketResponse, String> {
+/// Cancellation handles for generations in flight, keyed by run id so overlapping runs can be
+/// cancelled and cleaned up independently.
+#[derive(Default)]
+struct GenerationControl {
+    runs: Mutex<HashMap<String, CancelToken>>,
+    next_run: AtomicU64,
+}
+
+impl GenerationControl {
+    fn begin(&self, requested: Option<String>) -> Result<(String, CancelToken), String> {
+        let run_id = match requested.map(|id| id.trim().to_string()).filter(|id| !id.is_empty()) {
+            Some(id) => id,
+            None => format!("run-{}", self.next_run.fetch_add(1, Ordering::Relaxed) + 1),
+        };
+        let mut runs = self.runs.lock().map_err(|_| "generation registry poisoned".to_string())?;
+        if runs.contains_key(&run_id) {
+            return Err(format!("generation {run_id} is already running"));
+        }
+        let token = CancelToken::new();
+        runs.insert(run_id.clone(), token.clone());
+        Ok((run_id, token))
+    }
+
+    fn finish(&self, run_id: &str) {
+        if let Ok(mut runs) = self.runs.lock() {
+            runs.remove(run_id);
+        }
+    }
+
+    fn cancel(&self, run_id: &str) -> bool {
+        match self.runs.lock() {
+            Ok(runs) => match runs.get(run_id) {
+                Some(token) => {
+                    token.cancel();
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+/// Tracker handles by database path, opened on first use and shared by every command so each
+/// database is migrated once per session and UI actions reuse one connection.
+#[derive(Default)]
+struct Stores {
+    open: Mutex<HashMap<PathBuf, Arc<Store>>>,
+}
+
+impl Stores {
+    fn get(&self, db_path: PathBuf) -> Result<Arc<Store>, String> {
+        let mut open = self.open.lock().map_err(|_| "tracker registry poisoned".to_string())?;
+        if let Some(store) = open.get(&db_path) {
+            return Ok(Arc::clone(store));
+        }
+        let store = Arc::new(Store::open(&db_path).map_err(|e| e.to_string())?);
+        open.insert(db_path, Arc::clone(&store));
+        Ok(store)
+    }
+
+    fn scoped(&self, outdir: Option<String>) -> Result<Arc<Store>, String> {
+        self.get(scoped_db_path(outdir)?)
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelGenerationResponse {
+    ok: bool,
+    cancelled: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LlmHealthResponse {
+    provider: String,
+    base_url: String,
+    reachable: bool,
+    latency_ms: Option<u64>,
+    models: Vec<String>,
+    model: String,
+    model_available: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClearLlmCacheResponse {
+    ok: bool,
+    removed: usize,
+}
+
+#[tauri::command(async)]
+fn generate_packet_cmd(
+    control: tauri::State<'_, GenerationControl>,
+    stores: tauri::State<'_, Stores>,
+    input: GeneratePacketInput,
+) -> Result<GeneratePacketResponse, String> {
     let repo_root = repo_root()?;
     let company = required_trimmed_field("company", input.company)?;
     let role = required_trimmed_field("role", input.role)?;
@@ -600,11 +1055,13 @@ fn generate_packet_cmd(input: GeneratePacketInput) -> Result<GeneratePacketRespo
         Some(value) => Some(value.parse::<Track>().map_err(|e| format!("invalid track: {e}"))?),
         None => None,
     };
+    let store = stores.scoped(input.outdir.clone())?;
     let scoped_outdir = match input.outdir {
         Some(raw) => Some(resolve_scoped_output_base(&repo_root, Some(raw))?),
         None => None,
     };
 
+    let (run_id, cancel) = control.begin(input.run_id)?;
     let result = generate_packet(
         GenerateInput {
             company,
@@ -617,15 +1074,23 @@ fn generate_packet_cmd(input: GeneratePacketInput) -> Result<GeneratePacketRespo
             track_override,
             allow_unapproved: input.allow_unapproved.unwrap_or(false),
         },
-        GenerateOptions { repo_root },
-    )
-    .map_err(|e| format!("generation failed: {e:#}"))?;
+        GenerateOptions {
+            repo_root,
+            cancel: Some(cancel),
+            bypass_llm_cache: input.bypass_llm_cache.unwrap_or(false),
+            regenerate_job_id: input.regenerate_job_id.filter(|id| !id.trim().is_empty()),
+            store: Some(store),
+        },
+    );
+    control.finish(&run_id);
+    let result = result.map_err(|e| format!("generation failed: {e:#}"))?;
 
     let packet_detail = to_packet_detail_response(applykit_core::types::PacketDetail {
         packet_dir: result.packet_dir.clone(),
         extracted: result.extracted.clone(),
         extraction_source: result.extraction_source.clone(),
         extraction_diagnostics: result.extraction_diagnostics.clone(),
+        prompt_versions: result.prompt_versions.clone(),
         fit: result.fit.clone(),
         track: result.track.clone(),
         tailor_plan: result.tailor_plan.clone(),
@@ -643,22 +1108,113 @@ fn generate_packet_cmd(input: GeneratePacketInput) -> Result<GeneratePacketRespo
     });
 
     Ok(GeneratePacketResponse {
+        run_id,
         packet_dir: result.packet_dir.display().to_string(),
         fit_total: result.fit.total,
         track: result.track.selected.to_string(),
         files_written: result.files_written.iter().map(|path| path.display().to_string()).collect(),
         truth_passed: result.truth_report.passed,
         packet_detail,
+        duplicate_jobs: result.duplicate_jobs.into_iter().map(duplicate_job_response).collect(),
+    })
+}
+
+fn duplicate_job_response(job: DuplicateJob) -> DuplicateJobResponse {
+    DuplicateJobResponse {
+        job_id: job.job_id,
+        company: job.company,
+        role: job.role,
+        status: job.status,
+        created_at: job.created_at,
+        reason: job.reason.as_str().to_string(),
+    }
+}
+
+/// Ranked full-text hits over JD text, company, role, notes and generated resumes.
+#[tauri::command]
+fn search_jobs_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: SearchJobsInput,
+) -> Result<Vec<SearchHitResponse>, String> {
+    let store = stores.scoped(input.outdir)?;
+    let hits = search_jobs(&store, &input.query, input.limit.unwrap_or(20).clamp(1, 100))
+        .map_err(|e| e.to_string())?;
+    Ok(hits
+        .into_iter()
+        .map(|hit| SearchHitResponse {
+            job_id: hit.job_id,
+            company: hit.company,
+            role: hit.role,
+            status: hit.status,
+            score: hit.score,
+            snippet: hit.snippet,
+        })
+        .collect())
+}
+
+/// Existing jobs with the same JD or company+role, checked before generating.
+#[tauri::command]
+fn find_duplicate_jobs_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: FindDuplicatesInput,
+) -> Result<Vec<DuplicateJobResponse>, String> {
+    let store = stores.scoped(input.outdir)?;
+    let duplicates = find_duplicate_jobs(
+        &store,
+        &hash_jd(&input.jd_text),
+        input.company.trim(),
+        input.role.trim(),
+        None,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(duplicates.into_iter().map(duplicate_job_response).collect())
+}
+
+#[tauri::command]
+fn cancel_generation_cmd(
+    control: tauri::State<'_, GenerationControl>,
+    input: CancelGenerationInput,
+) -> Result<CancelGenerationResponse, String> {
+    Ok(CancelGenerationResponse { ok: true, cancelled: control.cancel(&input.run_id) })
+}
+
+#[tauri::command(async)]
+fn check_llm_health_cmd() -> Result<LlmHealthResponse, String> {
+    let repo_root = repo_root()?;
+    let report =
+        check_llm_health(&repo_root).map_err(|e| format!("llm health check failed: {e:#}"))?;
+    Ok(LlmHealthResponse {
+        provider: report.provider,
+        base_url: report.base_url,
+        reachable: report.reachable,
+        latency_ms: report.latency_ms,
+        models: report.models,
+        model: report.model,
+        model_available: report.model_available,
+        error: report.error,
     })
 }
 
 #[tauri::command]
-fn get_packet_detail_cmd(input: PacketDetailInput) -> Result<PacketDetailResponse, String> {
+fn clear_llm_cache_cmd() -> Result<ClearLlmCacheResponse, String> {
+    let repo_root = repo_root()?;
+    let base = resolve_scoped_output_base(&repo_root, None)?;
+    let removed =
+        clear_llm_cache(&base).map_err(|e| format!("clearing llm cache failed: {e:#}"))?;
+    Ok(ClearLlmCacheResponse { ok: true, removed })
+}
+
+#[tauri::command]
+fn get_packet_detail_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: PacketDetailInput,
+) -> Result<PacketDetailResponse, String> {
     let repo_root = repo_root()?;
     let base = resolve_scoped_output_base(&repo_root, input.outdir)?;
 
     let detail = if let Some(job_id) = input.job_id {
-        read_packet_detail_by_job_id(&base, &job_id).map_err(|e| e.to_string())?
+        read_packet_detail_by_job_id(&stores.get(base.join("applykit.db"))?, &job_id)
+            .map_err(|e| e.to_string())?
     } else if let Some(packet_dir) = input.packet_dir {
         let scoped_packet_dir = resolve_packet_dir(&repo_root, &packet_dir)?;
         read_packet_detail(&scoped_packet_dir).map_err(|e| e.to_string())?
@@ -669,14 +1225,45 @@ fn get_packet_detail_cmd(input: PacketDetailInput) -> Result<PacketDetailRespons
     Ok(to_packet_detail_response(detail))
 }
 
+fn parse_optional_day(field: &str, value: Option<String>) -> Result<Option<NaiveDate>, String> {
+    match value.filter(|v| !v.trim().is_empty()) {
+        Some(v) => NaiveDate::parse_from_str(v.trim(), "%Y-%m-%d")
+            .map(Some)
+            .map_err(|e| format!("invalid {field} (expected YYYY-MM-DD): {e}")),
+        None => Ok(None),
+    }
+}
+
 #[tauri::command]
-fn list_jobs_cmd(input: Option<ListJobsInput>) -> Result<ListJobsResponse, String> {
+fn list_jobs_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: Option<ListJobsInput>,
+) -> Result<ListJobsResponse, String> {
     let repo_root = repo_root()?;
-    let base = resolve_scoped_output_base(&repo_root, input.and_then(|i| i.outdir))?;
+    let input = input.unwrap_or_default();
+    let base = resolve_scoped_output_base(&repo_root, input.outdir)?;
+    let query = JobQuery {
+        statuses: input.statuses.unwrap_or_default(),
+        track: input.track,
+        source: input.source,
+        company: input.company,
+        fit_min: input.fit_min,
+        fit_max: input.fit_max,
+        updated_from: parse_optional_day("updatedFrom", input.updated_from)?,
+        updated_to: parse_optional_day("updatedTo", input.updated_to)?,
+        sort: match input.sort {
+            Some(sort) => sort.parse::<JobSortKey>()?,
+            None => JobSortKey::default(),
+        },
+        ascending: input.ascending.unwrap_or(false),
+        limit: input.limit,
+        offset: input.offset.unwrap_or(0),
+    };
 
-    let db_path = base.join("applykit.db");
-    let jobs = list_jobs(&db_path)
-        .map_err(|e| e.to_string())?
+    let store = stores.get(base.join("applykit.db"))?;
+    let page = query_jobs(&store, &query).map_err(|e| e.to_string())?;
+    let jobs = page
+        .jobs
         .into_iter()
         .map(|j| JobSummary {
             id: j.id,
@@ -691,33 +1278,59 @@ fn list_jobs_cmd(input: Option<ListJobsInput>) -> Result<ListJobsResponse, Strin
             notes: j.notes,
             output_dir: j.output_dir,
             updated_at: j.updated_at,
+            status_entered_at: j.status_entered_at,
+            closed_reason: j.closed_reason,
+            next_action_due: j.next_action_due,
         })
         .collect::<Vec<_>>();
 
-    let fallback_packet_dirs = list_packets_from_fs(&base)
-        .map_err(|e| e.to_string())?
-        .into_iter()
-        .map(|p| p.display().to_string())
-        .collect::<Vec<_>>();
+    // Packet folders are only worth scanning when the tracker is empty, not when a filter
+    // just matched nothing.
+    let tracker_empty = query.is_unfiltered() && !has_jobs(&store).map_err(|e| e.to_string())?;
+    let fallback_packet_dirs = if tracker_empty {
+        list_packets_from_fs(&base)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
 
-    Ok(ListJobsResponse { jobs, fallback_packet_dirs })
+    Ok(ListJobsResponse {
+        jobs,
+        total: page.total,
+        next_offset: page.next_offset,
+        fallback_packet_dirs,
+    })
 }
 
 #[tauri::command]
-fn update_job_status_cmd(input: UpdateJobStatusInput) -> Result<UpdateJobStatusResponse, String> {
+fn update_job_status_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: UpdateJobStatusInput,
+) -> Result<UpdateJobStatusResponse, String> {
     let repo_root = repo_root()?;
-    let base = resolve_scoped_output_base(&repo_root, input.outdir.clone())?;
-    let db_path = base.join("applykit.db");
+    let workflow = load_config(&repo_root).map_err(|e| e.to_string())?.tracker;
+    let store = stores.scoped(input.outdir.clone())?;
     let status = input.status.to_ascii_lowercase();
 
     update_job_status(
-        &db_path,
-        &input.id,
-        &status,
-        input.next_action.as_deref(),
-        input.notes.as_deref(),
+        &store,
+        &workflow,
+        JobStatusUpdate {
+            id: &input.id,
+            status: &status,
+            next_action: input.next_action.as_deref(),
+            next_action_due: input.next_action_due.as_deref(),
+            notes: input.notes.as_deref(),
+            closed_reason: input.closed_reason.as_deref(),
+        },
     )
     .map_err(|e| e.to_string())?;
+    let closed_reason = get_job_by_id(&store, &input.id)
+        .map_err(|e| e.to_string())?
+        .and_then(|job| job.closed_reason);
 
     Ok(UpdateJobStatusResponse {
         ok: true,
@@ -725,30 +1338,371 @@ fn update_job_status_cmd(input: UpdateJobStatusInput) -> Result<UpdateJobStatusR
         status,
         next_action: input.next_action,
         notes: input.notes,
+        closed_reason,
     })
 }
 
+fn due_report(stores: &Stores, input: Option<DueActionsInput>) -> Result<DueReport, String> {
+    let repo_root = repo_root()?;
+    let workflow = load_config(&repo_root).map_err(|e| e.to_string())?.tracker;
+    let (days, outdir) = input.map(|i| (i.days, i.outdir)).unwrap_or_default();
+    let jobs = list_jobs(&stores.scoped(outdir)?).map_err(|e| e.to_string())?;
+    Ok(collect_due(&jobs, &workflow, Local::now().date_naive(), days.unwrap_or(14)))
+}
+
+fn reminder_response(r: Reminder) -> ReminderResponse {
+    ReminderResponse {
+        job_id: r.job_id,
+        company: r.company,
+        role: r.role,
+        status: r.status,
+        action: r.action,
+        due: r.due.to_string(),
+        kind: r.kind.as_str().to_string(),
+    }
+}
+
+#[tauri::command]
+fn list_due_actions_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: Option<DueActionsInput>,
+) -> Result<DueActionsResponse, String> {
+    let report = due_report(&stores, input)?;
+    Ok(DueActionsResponse {
+        today: report.today.to_string(),
+        overdue: report.overdue.into_iter().map(reminder_response).collect(),
+        upcoming: report.upcoming.into_iter().map(reminder_response).collect(),
+    })
+}
+
+/// Writes overdue and upcoming reminders to `reminders.ics` in the output base.
 #[tauri::command]
-fn insights_cmd(input: Option<ListJobsInput>) -> Result<InsightsResponse, String> {
+fn export_reminders_ics_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: Option<DueActionsInput>,
+) -> Result<ExportResponse, String> {
     let repo_root = repo_root()?;
-    let base = resolve_scoped_output_base(&repo_root, input.and_then(|i| i.outdir))?;
+    let outdir = input.as_ref().and_then(|i| i.outdir.clone());
+    let base = resolve_scoped_output_base(&repo_root, outdir)?;
+    let report = due_report(&stores, input)?;
+    let reminders = report.overdue.into_iter().chain(report.upcoming).collect::<Vec<_>>();
+    let out_path = base.join("reminders.ics");
+    std::fs::create_dir_all(&base).map_err(|e| e.to_string())?;
+    std::fs::write(&out_path, reminders_to_ics(&reminders, Utc::now()))
+        .map_err(|e| e.to_string())?;
+    Ok(ExportResponse {
+        ok: true,
+        output_path: Some(out_path.display().to_string()),
+        message: format!("Exported {} reminders", reminders.len()),
+    })
+}
 
-    let db_path = base.join("applykit.db");
-    let jobs = list_jobs(&db_path).map_err(|e| e.to_string())?;
-    let insights = build_insights(&jobs);
+/// Writes the whole tracker to `tracker_export.csv` or `tracker_export.json` in the output base.
+#[tauri::command]
+fn export_tracker_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: ExportTrackerInput,
+) -> Result<ExportResponse, String> {
+    let repo_root = repo_root()?;
+    let base = resolve_scoped_output_base(&repo_root, input.outdir)?;
+    let export = collect_tracker_export(&stores.get(base.join("applykit.db"))?)
+        .map_err(|e| e.to_string())?;
+    let (file_name, body) = match input.format.to_ascii_lowercase().as_str() {
+        "csv" => ("tracker_export.csv", tracker_export_csv(&export)),
+        "json" => (
+            "tracker_export.json",
+            serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?,
+        ),
+        other => return Err(format!("unknown export format: {other}")),
+    };
+    let out_path = base.join(file_name);
+    std::fs::create_dir_all(&base).map_err(|e| e.to_string())?;
+    std::fs::write(&out_path, body).map_err(|e| e.to_string())?;
+    Ok(ExportResponse {
+        ok: true,
+        output_path: Some(out_path.display().to_string()),
+        message: format!("Exported {} jobs", export.jobs.len()),
+    })
+}
+
+/// Writes an online backup of `applykit.db` to `<output base>/backups/`.
+#[tauri::command]
+fn backup_database_cmd(input: DatabaseInput) -> Result<ExportResponse, String> {
+    let db_path = scoped_db_path(input.outdir)?;
+    let info = backup_database(&db_path, &default_backup_path(&db_path))
+        .map_err(|e| format!("backup failed: {e:#}"))?;
+    Ok(ExportResponse {
+        ok: true,
+        output_path: Some(info.path.display().to_string()),
+        message: format!("Backed up {} jobs (schema v{})", info.jobs, info.schema_version),
+    })
+}
+
+#[tauri::command]
+fn check_database_cmd(input: DatabaseInput) -> Result<DatabaseCheckResponse, String> {
+    let db_path = scoped_db_path(input.outdir)?;
+    let report = check_database(&db_path).map_err(|e| e.to_string())?;
+    Ok(DatabaseCheckResponse {
+        ok: report.is_ok(),
+        integrity: report.integrity,
+        schema_version: report.schema_version,
+        latest_version: report.latest_version,
+        orphaned: report.orphaned,
+        missing_packets: report
+            .missing_packets
+            .into_iter()
+            .map(|m| MissingPacketResponse { job_id: m.job_id, output_dir: m.output_dir })
+            .collect(),
+    })
+}
+
+#[tauri::command]
+fn import_tracker_csv_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: ImportTrackerInput,
+) -> Result<ImportTrackerResponse, String> {
+    let repo_root = repo_root()?;
+    let workflow = load_config(&repo_root).map_err(|e| e.to_string())?.tracker;
+    let store = stores.scoped(input.outdir)?;
+    let report = import_tracker_csv(
+        &store,
+        &input.csv_text,
+        &workflow,
+        &TrackerImportOptions {
+            column_overrides: input.mapping.unwrap_or_default(),
+            dry_run: input.dry_run.unwrap_or(false),
+        },
+    )
+    .map_err(|e| format!("import failed: {e:#}"))?;
+    let issues = |issues: Vec<ImportIssue>| {
+        issues
+            .into_iter()
+            .map(|i| ImportIssueResponse {
+                line: i.line,
+                company: i.company,
+                role: i.role,
+                reason: i.reason,
+            })
+            .collect::<Vec<_>>()
+    };
+    Ok(ImportTrackerResponse {
+        rows: report.rows,
+        mapping: report.mapping,
+        created: report.created,
+        duplicates: issues(report.duplicates),
+        errors: issues(report.errors),
+        dry_run: report.dry_run,
+    })
+}
+
+#[tauri::command]
+fn get_status_workflow_cmd() -> Result<StatusWorkflowResponse, String> {
+    let repo_root = repo_root()?;
+    let workflow = load_config(&repo_root).map_err(|e| e.to_string())?.tracker;
+    Ok(StatusWorkflowResponse {
+        statuses: workflow.statuses,
+        closed_statuses: workflow.closed_statuses,
+        transitions: workflow.transitions,
+    })
+}
+
+#[tauri::command]
+fn get_job_timeline_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: JobTimelineInput,
+) -> Result<JobTimelineResponse, String> {
+    let events = list_job_events(&stores.scoped(input.outdir)?, &input.id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|e| JobEventResponse {
+            id: e.id,
+            job_id: e.job_id,
+            event_type: e.event_type.as_str().to_string(),
+            payload: e.payload,
+            created_at: e.created_at,
+        })
+        .collect();
+    Ok(JobTimelineResponse { events })
+}
+
+fn contact_response(c: Contact) -> ContactResponse {
+    ContactResponse {
+        id: c.id,
+        job_id: c.job_id,
+        role: c.role.as_str().to_string(),
+        name: c.name,
+        email: c.email,
+        notes: c.notes,
+        updated_at: c.updated_at,
+    }
+}
+
+fn interview_response(i: Interview) -> InterviewResponse {
+    InterviewResponse {
+        id: i.id,
+        job_id: i.job_id,
+        scheduled_at: i.scheduled_at,
+        kind: i.kind,
+        interviewers: i.interviewers,
+        outcome: i.outcome,
+        notes: i.notes,
+        updated_at: i.updated_at,
+    }
+}
+
+fn scoped_db_path(outdir: Option<String>) -> Result<PathBuf, String> {
+    let repo_root = repo_root()?;
+    Ok(resolve_scoped_output_base(&repo_root, outdir)?.join("applykit.db"))
+}
+
+/// Contacts and interview rounds for one job.
+#[tauri::command]
+fn get_job_people_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: JobPeopleInput,
+) -> Result<JobPeopleResponse, String> {
+    let store = stores.scoped(input.outdir)?;
+    let contacts = list_contacts(&store, &input.job_id).map_err(|e| e.to_string())?;
+    let interviews = list_interviews(&store, &input.job_id).map_err(|e| e.to_string())?;
+    Ok(JobPeopleResponse {
+        contacts: contacts.into_iter().map(contact_response).collect(),
+        interviews: interviews.into_iter().map(interview_response).collect(),
+    })
+}
+
+#[tauri::command]
+fn save_contact_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: SaveContactInput,
+) -> Result<ContactResponse, String> {
+    let store = stores.scoped(input.outdir.clone())?;
+    let contact = ContactInput {
+        job_id: &input.job_id,
+        role: input.role.parse::<ContactRole>()?,
+        name: &input.name,
+        email: input.email.as_deref(),
+        notes: input.notes.as_deref(),
+    };
+    let saved = match input.id {
+        Some(id) => update_contact(&store, id, contact),
+        None => add_contact(&store, contact),
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(contact_response(saved))
+}
+
+#[tauri::command]
+fn delete_contact_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: DeleteRecordInput,
+) -> Result<DeleteRecordResponse, String> {
+    let removed =
+        delete_contact(&stores.scoped(input.outdir)?, input.id).map_err(|e| e.to_string())?;
+    Ok(DeleteRecordResponse { ok: true, removed })
+}
+
+#[tauri::command]
+fn save_interview_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: SaveInterviewInput,
+) -> Result<InterviewResponse, String> {
+    let store = stores.scoped(input.outdir.clone())?;
+    let interview = InterviewInput {
+        job_id: &input.job_id,
+        scheduled_at: &input.scheduled_at,
+        kind: &input.kind,
+        interviewers: &input.interviewers,
+        outcome: input.outcome.as_deref(),
+        notes: input.notes.as_deref(),
+    };
+    let saved = match input.id {
+        Some(id) => update_interview(&store, id, interview),
+        None => add_interview(&store, interview),
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(interview_response(saved))
+}
+
+#[tauri::command]
+fn delete_interview_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: DeleteRecordInput,
+) -> Result<DeleteRecordResponse, String> {
+    let removed =
+        delete_interview(&stores.scoped(input.outdir)?, input.id).map_err(|e| e.to_string())?;
+    Ok(DeleteRecordResponse { ok: true, removed })
+}
+
+/// Best-effort: the export already succeeded, so a tracking failure must not fail the command.
+fn record_export(
+    stores: &Stores,
+    repo_root: &Path,
+    packet_dir: &Path,
+    format: &str,
+    output_path: &Path,
+) {
+    if let Ok(store) =
+        configured_output_base(repo_root).and_then(|base| stores.get(base.join("applykit.db")))
+    {
+        let _ = record_export_event(&store, packet_dir, format, output_path);
+    }
+}
+
+#[tauri::command]
+fn insights_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: Option<ListJobsInput>,
+) -> Result<InsightsResponse, String> {
+    let repo_root = repo_root()?;
+    let banks = load_banks(&repo_root).map_err(|e| e.to_string())?;
+    let workflow = load_config(&repo_root).map_err(|e| e.to_string())?.tracker;
+    let store = stores.scoped(input.and_then(|i| i.outdir))?;
+    let insights = collect_insights(&store, &banks, &workflow).map_err(|e| e.to_string())?;
+    let funnels = |items: Vec<Funnel>| items.into_iter().map(FunnelResponse::from).collect();
     Ok(InsightsResponse {
-        replies_by_track: insights.replies_by_track,
-        common_gaps: insights.common_gaps,
-        keyword_correlations: insights.keyword_correlations,
+        overall: insights.overall.into(),
+        by_track: funnels(insights.by_track),
+        by_source: funnels(insights.by_source),
+        by_baseline: funnels(insights.by_baseline),
+        by_fit_bucket: funnels(insights.by_fit_bucket),
+        median_days_to_reply: insights.median_days_to_reply,
+        reply_samples: insights.reply_samples,
+        missing_skills: insights
+            .missing_skills
+            .into_iter()
+            .map(|m| MissingSkillResponse {
+                skill: m.skill,
+                kind: m.kind,
+                jobs: m.jobs,
+                unapproved: m.unapproved,
+                example: m.example,
+            })
+            .collect(),
+        keyword_correlations: insights
+            .keyword_correlations
+            .into_iter()
+            .map(|t| TermOutcomeResponse {
+                term: t.term,
+                kind: t.kind,
+                jobs: t.jobs,
+                positive: t.positive,
+                positive_rate: t.positive_rate,
+                lift: t.lift,
+            })
+            .collect(),
     })
 }
 
 #[tauri::command]
-fn export_markdown_cmd(input: ExportInput) -> Result<ExportResponse, String> {
+fn export_markdown_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: ExportInput,
+) -> Result<ExportResponse, String> {
     let repo_root = repo_root()?;
     let packet_dir = resolve_packet_dir(&repo_root, &input.packet_dir)?;
     let out_dir = resolve_export_out_dir(&repo_root, &packet_dir, input.out_dir)?;
     let output = export_markdown_bundle(&packet_dir, &out_dir).map_err(|e| e.to_string())?;
+    record_export(&stores, &repo_root, &packet_dir, "markdown", &output);
     Ok(ExportResponse {
         ok: true,
         output_path: Some(output.display().to_string()),
@@ -757,7 +1711,10 @@ fn export_markdown_cmd(input: ExportInput) -> Result<ExportResponse, String> {
 }
 
 #[tauri::command]
-fn export_docx_cmd(input: ExportInput) -> Result<ExportResponse, String> {
+fn export_docx_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: ExportInput,
+) -> Result<ExportResponse, String> {
     let repo_root = repo_root()?;
     let packet_dir = resolve_packet_dir(&repo_root, &input.packet_dir)?;
     let out_dir = resolve_export_out_dir(&repo_root, &packet_dir, input.out_dir)?;
@@ -773,17 +1730,27 @@ fn export_docx_cmd(input: ExportInput) -> Result<ExportResponse, String> {
     let out_path = out_dir.join(file_name);
 
     match export_docx(&packet_dir, &out_path) {
-        Ok(()) => Ok(ExportResponse {
-            ok: true,
-            output_path: Some(out_path.display().to_string()),
-            message: "DOCX export complete".to_string(),
-        }),
+        Ok(()) => {
+            record_export(&stores, &repo_root, &packet_dir, "docx", &out_path);
+            Ok(ExportResponse {
+                ok: true,
+                output_path: Some(out_path.display().to_string()),
+                message: "DOCX export complete".to_string(),
+            })
+        }
         Err(err) => Ok(ExportResponse { ok: false, output_path: None, message: err.to_string() }),
     }
 }
 
 #[tauri::command]
-fn export_pdf_cmd(input: ExportInput) -> Result<ExportResponse, String> {
+fn export_pdf_cmd(
+    stores: tauri::State<'_, Stores>,
+    input: ExportInput,
+) -> Result<ExportResponse, String> {
+    export_pdf_packet(&stores, input)
+}
+
+fn export_pdf_packet(stores: &Stores, input: ExportInput) -> Result<ExportResponse, String> {
     let repo_root = repo_root()?;
     let packet_dir = resolve_packet_dir(&repo_root, &input.packet_dir)?;
     let out_dir = resolve_export_out_dir(&repo_root, &packet_dir, input.out_dir)?;
@@ -799,11 +1766,14 @@ fn export_pdf_cmd(input: ExportInput) -> Result<ExportResponse, String> {
     let out_path = out_dir.join(file_name);
 
     match export_pdf(&packet_dir, &out_path) {
-        Ok(()) => Ok(ExportResponse {
-            ok: true,
-            output_path: Some(out_path.display().to_string()),
-            message: "PDF export complete".to_string(),
-        }),
+        Ok(()) => {
+            record_export(stores, &repo_root, &packet_dir, "pdf", &out_path);
+            Ok(ExportResponse {
+                ok: true,
+                output_path: Some(out_path.display().to_string()),
+                message: "PDF export complete".to_string(),
+            })
+        }
         Err(err) => Ok(ExportResponse {
             ok: false,
             output_path: None,
@@ -870,14 +1840,23 @@ fn get_templates_preview_cmd() -> Result<TemplatesPreviewResponse, String> {
         recruiter_template: preview.recruiter_template,
         hiring_manager_template: preview.hiring_manager_template,
         cover_short_template: preview.cover_short_template,
+        summarize_jd_prompt: preview.summarize_jd_prompt,
+        summarize_jd_repair_prompt: preview.summarize_jd_repair_prompt,
+        rewrite_message_prompt: preview.rewrite_message_prompt,
+        rewrite_bullet_prompt: preview.rewrite_bullet_prompt,
     })
 }
 
 #[tauri::command]
 fn set_bullet_approved_cmd(input: SetBulletApprovedInput) -> Result<MutationResponse, String> {
     let repo_root = repo_root()?;
-    let resp = set_bullet_approved_value(&repo_root, &input.id, input.approved)
-        .map_err(|e| e.to_string())?;
+    let resp = set_bullet_approved_value(
+        &repo_root,
+        &input.id,
+        input.approved,
+        input.create_pending_skills.unwrap_or(false),
+    )
+    .map_err(|e| e.to_string())?;
     Ok(to_mutation_response(resp))
 }
 
@@ -928,6 +1907,7 @@ fn create_bullet_cmd(input: CreateBulletInput) -> Result<MutationResponse, Strin
             tags: input.tags.unwrap_or_default(),
             tools: input.tools.unwrap_or_default(),
             approved: input.approved.unwrap_or(false),
+            create_pending_skills: input.create_pending_skills.unwrap_or(false),
         },
     )
     .map_err(|e| e.to_string())?;
@@ -977,11 +1957,31 @@ fn open_output_folder(path: String) -> Result<(), String> {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .manage(GenerationControl::default())
+        .manage(Stores::default())
         .invoke_handler(tauri::generate_handler![
             generate_packet_cmd,
+            cancel_generation_cmd,
+            clear_llm_cache_cmd,
+            check_llm_health_cmd,
             get_packet_detail_cmd,
             list_jobs_cmd,
             update_job_status_cmd,
+            get_status_workflow_cmd,
+            list_due_actions_cmd,
+            export_reminders_ics_cmd,
+            get_job_timeline_cmd,
+            find_duplicate_jobs_cmd,
+            search_jobs_cmd,
+            export_tracker_cmd,
+            import_tracker_csv_cmd,
+            backup_database_cmd,
+            check_database_cmd,
+            get_job_people_cmd,
+            save_contact_cmd,
+            delete_contact_cmd,
+            save_interview_cmd,
+            delete_interview_cmd,
             insights_cmd,
             export_markdown_cmd,
             export_docx_cmd,
@@ -1128,11 +2128,14 @@ allowed_tasks = []
             .expect("resume");
 
         let _cwd = CwdGuard::set_to(repo.path());
-        let response = export_pdf_cmd(ExportInput {
-            packet_dir: packet_dir.to_string_lossy().to_string(),
-            out_dir: None,
-            file_name: Some("packet.pdf".to_string()),
-        })
+        let response = export_pdf_packet(
+            &Stores::default(),
+            ExportInput {
+                packet_dir: packet_dir.to_string_lossy().to_string(),
+                out_dir: None,
+                file_name: Some("packet.pdf".to_string()),
+            },
+        )
         .expect("command response");
 
         assert!(response.ok);
@@ -1153,11 +2156,14 @@ allowed_tasks = []
         std::fs::create_dir_all(&not_packet).expect("dir");
 
         let _cwd = CwdGuard::set_to(repo.path());
-        let err = export_pdf_cmd(ExportInput {
-            packet_dir: not_packet.to_string_lossy().to_string(),
-            out_dir: None,
-            file_name: None,
-        })
+        let err = export_pdf_packet(
+            &Stores::default(),
+            ExportInput {
+                packet_dir: not_packet.to_string_lossy().to_string(),
+                out_dir: None,
+                file_name: None,
+            },
+        )
         .expect_err("invalid packet dir");
         assert!(err.contains("ReviewData.json"));
     }
@@ -6,12 +6,24 @@ import { MarkdownViewer } from "./components/MarkdownViewer";
 import { ToastHost, type Toast } from "./components/ToastHost";
 import { invokeSafe } from "./lib/tauri";
 import type {
+  ContactDraft,
+  DueActions,
+  DatabaseCheckResponse,
+  DuplicateJob,
   ExportResponse,
   GenerateRequest,
   GenerateResponse,
+  ImportTrackerResponse,
+  Insights,
+  InterviewDraft,
+  JobEvent,
+  JobPeople,
   JobSummary,
+  LlmHealthResponse,
   PacketDetail,
+  SearchHit,
   SettingsModel,
+  StatusWorkflow,
   UpdateJobStatusResponse
 } from "./lib/types";
 import { Dashboard } from "./screens/Dashboard";
@@ -39,14 +51,16 @@ export default function App() {
   const [view, setView] = useState<View>("dashboard");
   const [paletteOpen, setPaletteOpen] = useState(false);
   const [busy, setBusy] = useState(false);
+  const [generationRunId, setGenerationRunId] = useState<string | null>(null);
   const [jobs, setJobs] = useState<JobSummary[]>([]);
-  const [insights, setInsights] = useState<{
-    repliesByTrack: [string, number][];
-    commonGaps: [string, number][];
-    keywordCorrelations: [string, number][];
-  } | null>(null);
+  const [insights, setInsights] = useState<Insights | null>(null);
   const [selectedDetail, setSelectedDetail] = useState<PacketDetail | undefined>(undefined);
   const [selectedJobId, setSelectedJobId] = useState<string | undefined>(undefined);
+  const [timeline, setTimeline] = useState<JobEvent[]>([]);
+  const [due, setDue] = useState<DueActions | undefined>(undefined);
+  const [people, setPeople] = useState<JobPeople>({ contacts: [], interviews: [] });
+  const [peopleVersion, setPeopleVersion] = useState(0);
+  const [statusWorkflow, setStatusWorkflow] = useState<StatusWorkflow | undefined>(undefined);
   const [settings, setSettings] = useState<SettingsModel>(defaultSettings);
   const [showPreview, setShowPreview] = useState(true);
   const [previewMode, setPreviewMode] = useState<"preview" | "diff">("preview");
@@ -76,11 +90,7 @@ export default function App() {
 
   const loadInsights = async () => {
     try {
-      const response = await invokeSafe<{
-        repliesByTrack: [string, number][];
-        commonGaps: [string, number][];
-        keywordCorrelations: [string, number][];
-      }>("insights_cmd", {});
+      const response = await invokeSafe<Insights>("insights_cmd", {});
       setInsights(response);
     } catch {
       setInsights(null);
@@ -96,6 +106,22 @@ export default function App() {
     }
   };
 
+  const loadDue = async () => {
+    try {
+      setDue(await invokeSafe<DueActions>("list_due_actions_cmd", {}));
+    } catch {
+      setDue(undefined);
+    }
+  };
+
+  const loadStatusWorkflow = async () => {
+    try {
+      setStatusWorkflow(await invokeSafe<StatusWorkflow>("get_status_workflow_cmd", {}));
+    } catch {
+      setStatusWorkflow(undefined);
+    }
+  };
+
   const openJobById = async (jobId: string) => {
     try {
       const detail = await invokeSafe<PacketDetail>("get_packet_detail_cmd", {
@@ -114,8 +140,67 @@ export default function App() {
     void loadJobs();
     void loadInsights();
     void loadSettings();
+    void loadStatusWorkflow();
+    void loadDue();
   }, []);
 
+  const timelineJobId =
+    selectedJobId ?? jobs.find((j) => j.outputDir === selectedDetail?.packetDir)?.id;
+
+  useEffect(() => {
+    if (!timelineJobId) {
+      setTimeline([]);
+      return;
+    }
+    let active = true;
+    void invokeSafe<{ events: JobEvent[] }>("get_job_timeline_cmd", { input: { id: timelineJobId } })
+      .then((response) => {
+        if (active) {
+          setTimeline(response.events);
+        }
+      })
+      .catch(() => {
+        if (active) {
+          setTimeline([]);
+        }
+      });
+    return () => {
+      active = false;
+    };
+  }, [timelineJobId, jobs]);
+
+  useEffect(() => {
+    if (!timelineJobId) {
+      setPeople({ contacts: [], interviews: [] });
+      return;
+    }
+    let active = true;
+    void invokeSafe<JobPeople>("get_job_people_cmd", { input: { jobId: timelineJobId } })
+      .then((response) => {
+        if (active) {
+          setPeople(response);
+        }
+      })
+      .catch(() => {
+        if (active) {
+          setPeople({ contacts: [], interviews: [] });
+        }
+      });
+    return () => {
+      active = false;
+    };
+  }, [timelineJobId, peopleVersion]);
+
+  const mutatePeople = async (command: string, input: Record<string, unknown>, done: string) => {
+    try {
+      await invokeSafe(command, { input });
+      setPeopleVersion((v) => v + 1);
+      pushToast(done, "success");
+    } catch (err) {
+      pushToast(err instanceof Error ? err.message : "Update failed", "error");
+    }
+  };
+
   useEffect(() => {
     const onKeyDown = (event: KeyboardEvent) => {
       if ((event.metaKey || event.ctrlKey) && event.key.toLowerCase() === "k") {
@@ -181,10 +266,42 @@ export default function App() {
           jobs={jobs}
           onNewJob={() => setView("new-job")}
           onOpenJob={(jobId) => void openJobById(jobId)}
-          insights={{
-            repliesByTrack: insights?.repliesByTrack ?? [],
-            commonGaps: insights?.commonGaps ?? [],
-            keywordCorrelations: insights?.keywordCorrelations ?? []
+          insights={insights ?? undefined}
+          due={due}
+          onSearchText={(query) =>
+            invokeSafe<SearchHit[]>("search_jobs_cmd", { input: { query, limit: 20 } }).catch((err) => {
+              pushToast(err instanceof Error ? err.message : "Search failed", "error");
+              return [];
+            })
+          }
+          onExportTracker={async (format) => {
+            try {
+              const response = await invokeSafe<ExportResponse>("export_tracker_cmd", { input: { format } });
+              pushToast(`${response.message}: ${response.outputPath ?? ""}`, "success");
+            } catch (err) {
+              pushToast(err instanceof Error ? err.message : "Export failed", "error");
+            }
+          }}
+          onImportTracker={async (csvText) => {
+            try {
+              const report = await invokeSafe<ImportTrackerResponse>("import_tracker_csv_cmd", {
+                input: { csvText }
+              });
+              pushToast(
+                `Imported ${report.created.length} of ${report.rows} rows (${report.duplicates.length} duplicates, ${report.errors.length} errors)`,
+                report.errors.length > 0 ? "error" : "success"
+              );
+              await loadJobs();
+            } catch (err) {
+              pushToast(err instanceof Error ? err.message : "Import failed", "error");
+            }
+          }}
+          onExportReminders={async () => {
+            const response = await invokeSafe<ExportResponse>("export_reminders_ics_cmd", {});
+            pushToast(
+              response.outputPath ? `${response.message}: ${response.outputPath}` : response.message,
+              response.ok ? "success" : "error"
+            );
           }}
         />
       );
@@ -194,8 +311,34 @@ export default function App() {
       return (
         <NewJob
           busy={busy}
+          onCheckDuplicates={(company, role, jdText) =>
+            invokeSafe<DuplicateJob[]>("find_duplicate_jobs_cmd", {
+              input: { company, role, jdText }
+            }).catch(() => [])
+          }
+          onCancel={
+            generationRunId
+              ? async () => {
+                  try {
+                    const response = await invokeSafe<{ ok: boolean; cancelled: boolean }>(
+                      "cancel_generation_cmd",
+                      { input: { runId: generationRunId } }
+                    );
+                    pushToast(
+                      response.cancelled ? "Cancelling generation..." : "Generation already finished",
+                      "info"
+                    );
+                  } catch (err) {
+                    const message = err instanceof Error ? err.message : "Cancel failed";
+                    pushToast(message, "error");
+                  }
+                }
+              : undefined
+          }
           onGenerate={async (req: GenerateRequest) => {
+            const runId = uid();
             setBusy(true);
+            setGenerationRunId(runId);
             try {
               const response = await invokeSafe<GenerateResponse>("generate_packet_cmd", {
                 input: {
@@ -204,13 +347,19 @@ export default function App() {
                   source: req.source,
                   baseline: req.baseline,
                   jdText: req.jdText,
-                  allowUnapproved: settings.allowUnapproved
+                  allowUnapproved: settings.allowUnapproved,
+                  bypassLlmCache: req.bypassLlmCache ?? false,
+                  regenerateJobId: req.regenerateJobId,
+                  runId
                 }
               });
               setSelectedDetail(response.packetDetail);
-              setSelectedJobId(undefined);
+              setSelectedJobId(req.regenerateJobId);
               setView("job-review");
-              pushToast("Packet generated", "success");
+              pushToast(
+                req.regenerateJobId ? "Packet regenerated into existing job" : "Packet generated",
+                "success"
+              );
               await loadJobs();
               await loadInsights();
             } catch (err) {
@@ -218,6 +367,7 @@ export default function App() {
               pushToast(message, "error");
             } finally {
               setBusy(false);
+              setGenerationRunId(null);
             }
           }}
         />
@@ -228,6 +378,27 @@ export default function App() {
       return (
         <JobReview
           detail={selectedDetail}
+          timeline={timeline}
+          workflow={statusWorkflow}
+          nextActionDue={jobs.find((j) => j.id === timelineJobId)?.nextActionDue}
+          contacts={people.contacts}
+          interviews={people.interviews}
+          onSaveContact={async (draft: ContactDraft) => {
+            if (timelineJobId) {
+              await mutatePeople("save_contact_cmd", { jobId: timelineJobId, ...draft }, "Contact saved");
+            }
+          }}
+          onDeleteContact={async (id: number) => {
+            await mutatePeople("delete_contact_cmd", { id }, "Contact removed");
+          }}
+          onSaveInterview={async (draft: InterviewDraft) => {
+            if (timelineJobId) {
+              await mutatePeople("save_interview_cmd", { jobId: timelineJobId, ...draft }, "Interview saved");
+            }
+          }}
+          onDeleteInterview={async (id: number) => {
+            await mutatePeople("delete_interview_cmd", { id }, "Interview removed");
+          }}
           approvedOnly={!settings.allowUnapproved}
           onCopy={async (text) => {
             await navigator.clipboard.writeText(text);
@@ -259,7 +430,7 @@ export default function App() {
             });
             pushToast(response.message, response.ok ? "success" : "error");
           }}
-          onUpdateTracker={async (status, nextAction, notes) => {
+          onUpdateTracker={async (status, nextAction, notes, closedReason, nextActionDue) => {
             if (!selectedDetail) {
               return;
             }
@@ -275,7 +446,9 @@ export default function App() {
                   id: jobId,
                   status,
                   nextAction,
-                  notes
+                  notes,
+                  closedReason: closedReason || undefined,
+                  nextActionDue: nextActionDue || undefined
                 }
               });
               if (!result.ok) {
@@ -284,6 +457,7 @@ export default function App() {
 
               pushToast("Tracker updated", "success");
               await loadJobs();
+              await loadDue();
               await loadInsights();
               await openJobById(jobId);
             } catch (err) {
@@ -368,6 +542,82 @@ export default function App() {
           ))}
         </fieldset>
         <div className="row end">
+          <button
+            className="btn"
+            onClick={async () => {
+              try {
+                const health = await invokeSafe<LlmHealthResponse>("check_llm_health_cmd", {});
+                if (!health.reachable) {
+                  pushToast(`LLM unreachable: ${health.error ?? health.baseUrl}`, "error");
+                } else if (!health.modelAvailable) {
+                  pushToast(
+                    `Model ${health.model} not found (available: ${health.models.join(", ") || "none"})`,
+                    "error"
+                  );
+                } else {
+                  pushToast(`LLM ok: ${health.model} (${health.latencyMs ?? 0} ms)`, "success");
+                }
+              } catch (err) {
+                const message = err instanceof Error ? err.message : "LLM health check failed";
+                pushToast(message, "error");
+              }
+            }}
+          >
+            Check Connection
+          </button>
+          <button
+            className="btn"
+            onClick={async () => {
+              try {
+                const cleared = await invokeSafe<{ ok: boolean; removed: number }>(
+                  "clear_llm_cache_cmd",
+                  {}
+                );
+                pushToast(`Cleared ${cleared.removed} cached LLM responses`, "success");
+              } catch (err) {
+                const message = err instanceof Error ? err.message : "Failed to clear LLM cache";
+                pushToast(message, "error");
+              }
+            }}
+          >
+            Clear LLM Cache
+          </button>
+          <button
+            className="btn"
+            onClick={async () => {
+              try {
+                const backup = await invokeSafe<ExportResponse>("backup_database_cmd", { input: {} });
+                pushToast(`${backup.message}: ${backup.outputPath ?? ""}`, "success");
+              } catch (err) {
+                const message = err instanceof Error ? err.message : "Database backup failed";
+                pushToast(message, "error");
+              }
+            }}
+          >
+            Back Up Database
+          </button>
+          <button
+            className="btn"
+            onClick={async () => {
+              try {
+                const check = await invokeSafe<DatabaseCheckResponse>("check_database_cmd", { input: {} });
+                if (check.ok) {
+                  pushToast(`Database ok (schema v${check.schemaVersion})`, "success");
+                } else {
+                  const orphaned = Object.values(check.orphaned).reduce((sum, count) => sum + count, 0);
+                  pushToast(
+                    `Database check: integrity ${check.integrity.join("; ")}, schema v${check.schemaVersion}/v${check.latestVersion}, ${orphaned} orphaned rows, ${check.missingPackets.length} missing packet folders`,
+                    "error"
+                  );
+                }
+              } catch (err) {
+                const message = err instanceof Error ? err.message : "Database check failed";
+                pushToast(message, "error");
+              }
+            }}
+          >
+            Check Database
+          </button>
           <button
             className="btn btn-primary"
             onClick={async () => {
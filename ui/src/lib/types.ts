@@ -6,6 +6,17 @@ export interface GenerateRequest {
   source: string;
   baseline: "1pg" | "2pg";
   jdText: string;
+  bypassLlmCache?: boolean;
+  regenerateJobId?: string;
+}
+
+export interface DuplicateJob {
+  jobId: string;
+  company: string;
+  role: string;
+  status: string;
+  createdAt: string;
+  reason: "same_jd" | "same_company_role";
 }
 
 export interface FitBreakdown {
@@ -73,9 +84,12 @@ export interface PacketDetail {
   extractionSource?: "deterministic" | "llm_merged";
   extractionDiagnostics?: {
     summarizeAttempted: boolean;
+    summarizeRepairAttempted?: boolean;
     summarizeMerged: boolean;
     summarizeFallbackReasons: string[];
+    injectionWarnings?: string[];
   };
+  promptVersions?: Record<string, string>;
   extractedKeywords: string[];
   extractedTools: string[];
   extractedRequirements: string[];
@@ -93,12 +107,14 @@ export interface PacketDetail {
 }
 
 export interface GenerateResponse {
+  runId: string;
   packetDir: string;
   fitTotal: number;
   track: string;
   filesWritten: string[];
   truthPassed: boolean;
   packetDetail: PacketDetail;
+  duplicateJobs: DuplicateJob[];
 }
 
 export interface JobSummary {
@@ -114,6 +130,96 @@ export interface JobSummary {
   notes?: string;
   outputDir?: string;
   updatedAt: string;
+  statusEnteredAt?: string;
+  closedReason?: string;
+  nextActionDue?: string;
+}
+
+export interface Contact {
+  id: number;
+  jobId: string;
+  role: "recruiter" | "hiring_manager" | "referrer";
+  name: string;
+  email?: string;
+  notes?: string;
+  updatedAt: string;
+}
+
+export interface Interview {
+  id: number;
+  jobId: string;
+  scheduledAt: string;
+  kind: string;
+  interviewers: string[];
+  outcome?: string;
+  notes?: string;
+  updatedAt: string;
+}
+
+export interface JobPeople {
+  contacts: Contact[];
+  interviews: Interview[];
+}
+
+export type ContactDraft = Pick<Contact, "role" | "name" | "email">;
+export type InterviewDraft = Pick<Interview, "scheduledAt" | "kind" | "interviewers" | "outcome">;
+
+export interface ImportIssue {
+  line: number;
+  company: string;
+  role: string;
+  reason: string;
+}
+
+export interface ImportTrackerResponse {
+  rows: number;
+  mapping: Record<string, string>;
+  created: string[];
+  duplicates: ImportIssue[];
+  errors: ImportIssue[];
+  dryRun: boolean;
+}
+
+export interface DatabaseCheckResponse {
+  ok: boolean;
+  integrity: string[];
+  schemaVersion: number;
+  latestVersion: number;
+  orphaned: Record<string, number>;
+  missingPackets: Array<{ jobId: string; outputDir: string }>;
+}
+
+export interface SearchHit {
+  jobId: string;
+  company: string;
+  role: string;
+  status: string;
+  score: number;
+  snippet: string;
+}
+
+export interface Reminder {
+  jobId: string;
+  company: string;
+  role: string;
+  status: string;
+  action: string;
+  due: string;
+  kind: "scheduled" | "follow_up";
+}
+
+export interface DueActions {
+  today: string;
+  overdue: Reminder[];
+  upcoming: Reminder[];
+}
+
+export interface JobEvent {
+  id: string;
+  jobId: string;
+  eventType: string;
+  payload: Record<string, unknown>;
+  createdAt: string;
 }
 
 export interface SettingsModel {
@@ -125,6 +231,17 @@ export interface SettingsModel {
   llmAllowedTasks: string[];
 }
 
+export interface LlmHealthResponse {
+  provider: string;
+  baseUrl: string;
+  reachable: boolean;
+  latencyMs?: number;
+  models: string[];
+  model: string;
+  modelAvailable: boolean;
+  error?: string;
+}
+
 export interface ExportResponse {
   ok: boolean;
   outputPath?: string;
@@ -137,12 +254,20 @@ export interface UpdateJobStatusResponse {
   status: string;
   nextAction?: string;
   notes?: string;
+  closedReason?: string;
+}
+
+export interface StatusWorkflow {
+  statuses: string[];
+  closedStatuses: string[];
+  transitions: Record<string, string[]>;
 }
 
 export interface MutationResponse {
   ok: boolean;
   message: string;
   updatedAt: string;
+  warnings: string[];
 }
 
 export type TemplateKey =
@@ -150,4 +275,48 @@ export type TemplateKey =
   | "resume_2pg_base"
   | "recruiter"
   | "hiring_manager"
-  | "cover_short";
+  | "cover_short"
+  | "summarize_jd_prompt"
+  | "summarize_jd_repair_prompt"
+  | "rewrite_message_prompt"
+  | "rewrite_bullet_prompt";
+
+export interface Funnel {
+  key: string;
+  applied: number;
+  replied: number;
+  interviewed: number;
+  offered: number;
+  replyRate: number;
+  interviewRate: number;
+  offerRate: number;
+}
+
+export interface TermOutcome {
+  term: string;
+  kind: "keyword" | "tool";
+  jobs: number;
+  positive: number;
+  positiveRate: number;
+  lift: number;
+}
+
+export interface MissingSkill {
+  skill: string;
+  kind: "requirement" | "tool";
+  jobs: number;
+  unapproved: boolean;
+  example: string;
+}
+
+export interface Insights {
+  overall: Funnel;
+  byTrack: Funnel[];
+  bySource: Funnel[];
+  byBaseline: Funnel[];
+  byFitBucket: Funnel[];
+  medianDaysToReply: number | null;
+  replySamples: number;
+  missingSkills: MissingSkill[];
+  keywordCorrelations: TermOutcome[];
+}
@@ -51,6 +51,7 @@ export function Banks({ onNotify }: Props) {
   const [newBulletTags, setNewBulletTags] = useState("");
   const [newBulletTools, setNewBulletTools] = useState("");
   const [newBulletApproved, setNewBulletApproved] = useState(false);
+  const [createPendingSkills, setCreatePendingSkills] = useState(false);
 
   const load = useCallback(async () => {
     const response = await invokeSafe<BanksPreviewResponse>("get_banks_preview_cmd", {});
@@ -132,7 +133,11 @@ export function Banks({ onNotify }: Props) {
       setSaving(true);
       try {
         const response = await invokeSafe<MutationResponse>(command, { input });
-        onNotify(response.message, response.ok ? "success" : "error");
+        if (response.ok && response.warnings.length > 0) {
+          onNotify(`${response.message}: ${response.warnings.join("; ")}`, "info");
+        } else {
+          onNotify(response.message, response.ok ? "success" : "error");
+        }
         await load();
         return true;
       } catch (err) {
@@ -163,6 +168,14 @@ export function Banks({ onNotify }: Props) {
           />
           Only approved entries
         </label>
+        <label className="row">
+          <input
+            type="checkbox"
+            checked={createPendingSkills}
+            onChange={(e) => setCreatePendingSkills(e.target.checked)}
+          />
+          Add unknown bullet tools as pending skills
+        </label>
       </section>
 
       {loading ? <section className="card">Loading banks preview...</section> : null}
@@ -324,7 +337,8 @@ export function Banks({ onNotify }: Props) {
                     category: split(newBulletCategory),
                     tags: split(newBulletTags),
                     tools: split(newBulletTools),
-                    approved: newBulletApproved
+                    approved: newBulletApproved,
+                    createPendingSkills
                   });
                   if (ok) {
                     setNewBulletId("");
@@ -373,7 +387,11 @@ export function Banks({ onNotify }: Props) {
                       checked={row.approved}
                       disabled={saving}
                       onChange={(e) =>
-                        void runMutation("set_bullet_approved_cmd", { id: row.id, approved: e.target.checked })
+                        void runMutation("set_bullet_approved_cmd", {
+                          id: row.id,
+                          approved: e.target.checked,
+                          createPendingSkills
+                        })
                       }
                     />
                   </td>
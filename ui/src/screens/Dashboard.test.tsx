@@ -49,7 +49,6 @@ describe("Dashboard filters", () => {
         jobs={jobs}
         onNewJob={vi.fn()}
         onOpenJob={onOpenJob}
-        insights={{ repliesByTrack: [], commonGaps: [], keywordCorrelations: [] }}
       />
     );
 
@@ -64,4 +63,116 @@ describe("Dashboard filters", () => {
     fireEvent.click(screen.getByRole("button", { name: /open packet for acme support engineer/i }));
     expect(onOpenJob).toHaveBeenCalledWith("1");
   });
+
+  it("lists overdue and upcoming follow-ups and exports them", () => {
+    const onOpenJob = vi.fn();
+    const onExportReminders = vi.fn();
+    render(
+      <Dashboard
+        jobs={[]}
+        onNewJob={vi.fn()}
+        onOpenJob={onOpenJob}
+        due={{
+          today: "2026-03-10",
+          overdue: [
+            {
+              jobId: "stale",
+              company: "Gamma",
+              role: "IT Ops",
+              status: "applied",
+              action: "Follow up: no change 7 days after applied",
+              due: "2026-03-08",
+              kind: "follow_up"
+            }
+          ],
+          upcoming: [
+            {
+              jobId: "booked",
+              company: "Delta",
+              role: "Support Lead",
+              status: "interview",
+              action: "Send thank-you",
+              due: "2026-03-12",
+              kind: "scheduled"
+            }
+          ]
+        }}
+        onExportReminders={onExportReminders}
+      />
+    );
+
+    expect(screen.getByText("Overdue")).toBeInTheDocument();
+    expect(screen.getByText("2026-03-12")).toBeInTheDocument();
+    expect(screen.getByText("(suggested)")).toBeInTheDocument();
+
+    fireEvent.click(screen.getByRole("button", { name: /open follow-up for gamma it ops/i }));
+    expect(onOpenJob).toHaveBeenCalledWith("stale");
+    fireEvent.click(screen.getByRole("button", { name: "Export Calendar (.ics)" }));
+    expect(onExportReminders).toHaveBeenCalled();
+  });
+
+  it("runs full-text search and opens a hit", async () => {
+    const onOpenJob = vi.fn();
+    const onSearchText = vi.fn().mockResolvedValue([
+      {
+        jobId: "k8s",
+        company: "Globex",
+        role: "Platform Engineer",
+        status: "applied",
+        score: 4.2,
+        snippet: "...own [kubernetes] upgrades..."
+      }
+    ]);
+    render(<Dashboard jobs={[]} onNewJob={vi.fn()} onOpenJob={onOpenJob} onSearchText={onSearchText} />);
+
+    fireEvent.change(screen.getByLabelText("Search packet text"), { target: { value: "kubernetes" } });
+    fireEvent.click(screen.getByRole("button", { name: "Search" }));
+
+    expect(await screen.findByText("...own [kubernetes] upgrades...")).toBeInTheDocument();
+    expect(onSearchText).toHaveBeenCalledWith("kubernetes");
+    fireEvent.click(screen.getByRole("button", { name: /open search result globex/i }));
+    expect(onOpenJob).toHaveBeenCalledWith("k8s");
+  });
+
+  it("shows conversion funnels and keyword lift", () => {
+    const funnel = (key: string, applied: number, replied: number) => ({
+      key,
+      applied,
+      replied,
+      interviewed: 0,
+      offered: 0,
+      replyRate: replied / applied,
+      interviewRate: 0,
+      offerRate: 0
+    });
+    render(
+      <Dashboard
+        jobs={[]}
+        onNewJob={vi.fn()}
+        onOpenJob={vi.fn()}
+        insights={{
+          overall: funnel("all", 4, 2),
+          byTrack: [funnel("support", 4, 2)],
+          bySource: [funnel("referral", 2, 2)],
+          byBaseline: [],
+          byFitBucket: [],
+          medianDaysToReply: 3,
+          replySamples: 2,
+          missingSkills: [
+            { skill: "terraform", kind: "requirement", jobs: 3, unapproved: false, example: "3 years Terraform" },
+            { skill: "jamf", kind: "tool", jobs: 1, unapproved: true, example: "Jamf" }
+          ],
+          keywordCorrelations: [
+            { term: "okta", kind: "tool", jobs: 2, positive: 2, positiveRate: 1, lift: 2 }
+          ]
+        }}
+      />
+    );
+
+    expect(screen.getByText(/median 3\.0 days to reply/)).toBeInTheDocument();
+    expect(screen.getByText(/referral: 2 applied, 100% reply/)).toBeInTheDocument();
+    expect(screen.getByText(/terraform: 3 packets/)).toBeInTheDocument();
+    expect(screen.getByText(/jamf: 1 packet \(tool, approve in Banks\)/)).toBeInTheDocument();
+    expect(screen.getByText(/okta \(tool\): 2\/2 replied, 2\.00× lift/)).toBeInTheDocument();
+  });
 });
@@ -1,22 +1,43 @@
-import { useMemo, useState } from "react";
-import type { JobSummary } from "../lib/types";
+import { type FormEvent, useMemo, useState } from "react";
+import type { DueActions, Funnel, Insights, JobSummary, Reminder, SearchHit } from "../lib/types";
 
 interface Props {
   jobs: JobSummary[];
   onNewJob: () => void;
   onOpenJob: (jobId: string) => void;
-  insights?: {
-    repliesByTrack: [string, number][];
-    commonGaps: [string, number][];
-    keywordCorrelations: [string, number][];
-  };
+  insights?: Insights;
+  due?: DueActions;
+  onExportReminders?: () => void;
+  onSearchText?: (query: string) => Promise<SearchHit[]>;
+  onExportTracker?: (format: "csv" | "json") => void;
+  onImportTracker?: (csvText: string) => void;
 }
 
-export function Dashboard({ jobs, onNewJob, onOpenJob, insights }: Props) {
+export function Dashboard({
+  jobs,
+  onNewJob,
+  onOpenJob,
+  insights,
+  due,
+  onExportReminders,
+  onSearchText,
+  onExportTracker,
+  onImportTracker
+}: Props) {
   const [daysFilter, setDaysFilter] = useState<"all" | "7" | "30">("all");
   const [trackFilter, setTrackFilter] = useState("all");
   const [statusFilter, setStatusFilter] = useState("all");
   const [search, setSearch] = useState("");
+  const [textQuery, setTextQuery] = useState("");
+  const [textHits, setTextHits] = useState<SearchHit[] | null>(null);
+
+  const onTextSearch = async (event: FormEvent) => {
+    event.preventDefault();
+    if (!onSearchText || !textQuery.trim()) {
+      return;
+    }
+    setTextHits(await onSearchText(textQuery));
+  };
 
   const filteredJobs = useMemo(() => {
     let out = jobs.slice();
@@ -75,6 +96,37 @@ export function Dashboard({ jobs, onNewJob, onOpenJob, insights }: Props) {
       <section className="card recent-packets-card" aria-labelledby="recent-packets-heading">
         <div className="row between wrap filters-panel">
           <h3 id="recent-packets-heading">Recent Packets</h3>
+          {onExportTracker || onImportTracker ? (
+            <div className="row wrap">
+              {onExportTracker ? (
+                <>
+                  <button className="btn" onClick={() => onExportTracker("csv")}>
+                    Export CSV
+                  </button>
+                  <button className="btn" onClick={() => onExportTracker("json")}>
+                    Export JSON
+                  </button>
+                </>
+              ) : null}
+              {onImportTracker ? (
+                <label>
+                  Import CSV
+                  <input
+                    aria-label="Import tracker CSV"
+                    type="file"
+                    accept=".csv,text/csv"
+                    onChange={async (e) => {
+                      const file = e.target.files?.[0];
+                      if (file) {
+                        onImportTracker(await file.text());
+                        e.target.value = "";
+                      }
+                    }}
+                  />
+                </label>
+              ) : null}
+            </div>
+          ) : null}
           <div className="row wrap filters-controls">
             <label>
               Search jobs
@@ -169,33 +221,113 @@ export function Dashboard({ jobs, onNewJob, onOpenJob, insights }: Props) {
         </div>
       </section>
 
+      {onSearchText ? (
+        <section className="card stack-sm" aria-labelledby="text-search-heading">
+          <form className="row between wrap" onSubmit={(e) => void onTextSearch(e)}>
+            <h3 id="text-search-heading">Search Packets</h3>
+            <div className="row wrap">
+              <input
+                aria-label="Search packet text"
+                value={textQuery}
+                onChange={(e) => setTextQuery(e.target.value)}
+                placeholder="Search JDs, notes and resumes"
+              />
+              <button className="btn" type="submit" disabled={!textQuery.trim()}>
+                Search
+              </button>
+            </div>
+          </form>
+          {textHits === null ? null : textHits.length === 0 ? (
+            <p className="subtle">No packets match.</p>
+          ) : (
+            <ul className="stack-sm">
+              {textHits.map((hit) => (
+                <li key={hit.jobId} className="row between wrap">
+                  <span>
+                    <strong>
+                      {hit.company} · {hit.role}
+                    </strong>{" "}
+                    <span className="subtle">{hit.snippet}</span>
+                  </span>
+                  <button
+                    className="btn btn-secondary"
+                    onClick={() => onOpenJob(hit.jobId)}
+                    aria-label={`Open search result ${hit.company} ${hit.role}`}
+                  >
+                    Open
+                  </button>
+                </li>
+              ))}
+            </ul>
+          )}
+        </section>
+      ) : null}
+
+      <section className="card stack-sm" aria-labelledby="due-heading">
+        <div className="row between wrap">
+          <h3 id="due-heading">Follow-ups</h3>
+          {onExportReminders ? (
+            <button className="btn" onClick={onExportReminders}>
+              Export Calendar (.ics)
+            </button>
+          ) : null}
+        </div>
+        {(due?.overdue.length ?? 0) + (due?.upcoming.length ?? 0) === 0 ? (
+          <p className="subtle">Nothing due in the next two weeks.</p>
+        ) : (
+          <ul className="stack-sm">
+            {[
+              ...(due?.overdue ?? []).map((r): [Reminder, boolean] => [r, true]),
+              ...(due?.upcoming ?? []).map((r): [Reminder, boolean] => [r, false])
+            ].map(([reminder, overdue]) => (
+              <li key={`${reminder.jobId}-${reminder.kind}`} className="row between wrap">
+                <span>
+                  <strong>{overdue ? "Overdue" : reminder.due}</strong> {reminder.company} · {reminder.role}:{" "}
+                  {reminder.action}
+                  {reminder.kind === "follow_up" ? <span className="subtle"> (suggested)</span> : null}
+                </span>
+                <button
+                  className="btn btn-secondary"
+                  onClick={() => onOpenJob(reminder.jobId)}
+                  aria-label={`Open follow-up for ${reminder.company} ${reminder.role}`}
+                >
+                  Open
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </section>
+
       <section className="card">
         <h3>Insights</h3>
-        <p className="subtle">Replies by track, common gaps, and keyword correlations.</p>
-        <div className="insights-grid">
-          <div>
-            <strong>Replies by track</strong>
-            {(insights?.repliesByTrack ?? []).length === 0 ? (
-              <p className="subtle">No reply data yet.</p>
-            ) : (
-              <ul>
-                {(insights?.repliesByTrack ?? []).map(([track, count]) => (
-                  <li key={track}>
-                    {track}: {count}
-                  </li>
-                ))}
-              </ul>
+        <p className="subtle">
+          Applied → reply → interview → offer, with the JD terms that most often led to a reply.
+        </p>
+        {insights && insights.overall.applied > 0 && (
+          <p>
+            {insights.overall.applied} applied · {percent(insights.overall.replyRate)} reply ·{" "}
+            {percent(insights.overall.interviewRate)} interview · {percent(insights.overall.offerRate)} offer
+            {insights.medianDaysToReply !== null && (
+              <> · median {insights.medianDaysToReply.toFixed(1)} days to reply</>
             )}
-          </div>
+          </p>
+        )}
+        <div className="insights-grid">
+          <FunnelList title="By track" funnels={insights?.byTrack ?? []} />
+          <FunnelList title="By source" funnels={insights?.bySource ?? []} />
+          <FunnelList title="By baseline" funnels={insights?.byBaseline ?? []} />
+          <FunnelList title="By fit score" funnels={insights?.byFitBucket ?? []} />
           <div>
-            <strong>Common gaps</strong>
-            {(insights?.commonGaps ?? []).length === 0 ? (
-              <p className="subtle">No gap trends yet.</p>
+            <strong>Most missing skills</strong>
+            {(insights?.missingSkills ?? []).length === 0 ? (
+              <p className="subtle">No gaps found in packets yet.</p>
             ) : (
               <ul>
-                {(insights?.commonGaps ?? []).slice(0, 5).map(([gap, count]) => (
-                  <li key={gap}>
-                    {gap}: {count}
+                {(insights?.missingSkills ?? []).slice(0, 8).map((missing) => (
+                  <li key={`${missing.kind}-${missing.skill}`} title={missing.example}>
+                    {missing.skill}: {missing.jobs} {missing.jobs === 1 ? "packet" : "packets"}
+                    {missing.kind === "tool" && (missing.unapproved ? " (tool, approve in Banks)" : " (tool, not in bank)")}
                   </li>
                 ))}
               </ul>
@@ -207,9 +339,9 @@ export function Dashboard({ jobs, onNewJob, onOpenJob, insights }: Props) {
               <p className="subtle">No correlation data yet.</p>
             ) : (
               <ul>
-                {(insights?.keywordCorrelations ?? []).slice(0, 5).map(([keyword, count]) => (
-                  <li key={keyword}>
-                    {keyword}: {count}
+                {(insights?.keywordCorrelations ?? []).slice(0, 5).map((term) => (
+                  <li key={`${term.kind}-${term.term}`}>
+                    {term.term} ({term.kind}): {term.positive}/{term.jobs} replied, {term.lift.toFixed(2)}× lift
                   </li>
                 ))}
               </ul>
@@ -220,3 +352,27 @@ export function Dashboard({ jobs, onNewJob, onOpenJob, insights }: Props) {
     </section>
   );
 }
+
+function percent(rate: number) {
+  return `${Math.round(rate * 100)}%`;
+}
+
+function FunnelList({ title, funnels }: { title: string; funnels: Funnel[] }) {
+  return (
+    <div>
+      <strong>{title}</strong>
+      {funnels.length === 0 ? (
+        <p className="subtle">No applications yet.</p>
+      ) : (
+        <ul>
+          {funnels.map((f) => (
+            <li key={f.key}>
+              {f.key}: {f.applied} applied, {percent(f.replyRate)} reply, {percent(f.interviewRate)} interview,{" "}
+              {percent(f.offerRate)} offer
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
@@ -146,7 +146,9 @@ describe("JobReview tracker state", () => {
     expect(onUpdateTracker).toHaveBeenCalledWith(
       "new",
       "book interview prep",
-      "focus on incident examples"
+      "focus on incident examples",
+      "",
+      ""
     );
   });
 
@@ -220,4 +222,99 @@ describe("JobReview tracker state", () => {
     fireEvent.click(screen.getByRole("tab", { name: /export/i }));
     expect(screen.getByText(/Unsupported claim found/i)).toBeInTheDocument();
   });
+
+  it("limits status choices to workflow transitions and captures a closed reason", () => {
+    const onUpdateTracker = vi.fn().mockResolvedValue(undefined);
+    render(
+      <JobReview
+        detail={detail("/tmp/packet-d", "offer", "decide by Friday")}
+        workflow={{
+          statuses: ["new", "offer", "accepted", "rejected"],
+          closedStatuses: ["accepted", "rejected"],
+          transitions: { offer: ["accepted", "rejected"] }
+        }}
+        approvedOnly
+        onCopy={vi.fn().mockResolvedValue(undefined)}
+        onOpenFolder={vi.fn().mockResolvedValue(undefined)}
+        onExportMarkdown={vi.fn().mockResolvedValue(undefined)}
+        onExportDocx={vi.fn().mockResolvedValue(undefined)}
+        onExportPdf={vi.fn().mockResolvedValue(undefined)}
+        onUpdateTracker={onUpdateTracker}
+      />
+    );
+
+    fireEvent.click(screen.getByRole("tab", { name: /tracker/i }));
+    const options = screen.getAllByRole("option").map((o) => o.textContent);
+    expect(options).toEqual(["offer", "accepted", "rejected"]);
+    expect(screen.queryByPlaceholderText("Role filled internally")).not.toBeInTheDocument();
+
+    fireEvent.change(screen.getByDisplayValue("offer"), { target: { value: "rejected" } });
+    fireEvent.change(screen.getByPlaceholderText("Role filled internally"), {
+      target: { value: "comp below range" }
+    });
+    fireEvent.click(screen.getByRole("button", { name: "Save Tracker" }));
+
+    expect(onUpdateTracker).toHaveBeenCalledWith(
+      "rejected",
+      "decide by Friday",
+      "",
+      "comp below range",
+      ""
+    );
+  });
+
+  it("adds and removes contacts and interview rounds", () => {
+    const onSaveContact = vi.fn().mockResolvedValue(undefined);
+    const onDeleteContact = vi.fn().mockResolvedValue(undefined);
+    const onSaveInterview = vi.fn().mockResolvedValue(undefined);
+    render(
+      <JobReview
+        detail={detail("/tmp/packet-e", "interview", "")}
+        contacts={[
+          {
+            id: 7,
+            jobId: "job-e",
+            role: "hiring_manager",
+            name: "Morgan Manager",
+            updatedAt: "2026-03-01T00:00:00Z"
+          }
+        ]}
+        interviews={[]}
+        onSaveContact={onSaveContact}
+        onDeleteContact={onDeleteContact}
+        onSaveInterview={onSaveInterview}
+        approvedOnly
+        onCopy={vi.fn().mockResolvedValue(undefined)}
+        onOpenFolder={vi.fn().mockResolvedValue(undefined)}
+        onExportMarkdown={vi.fn().mockResolvedValue(undefined)}
+        onExportDocx={vi.fn().mockResolvedValue(undefined)}
+        onExportPdf={vi.fn().mockResolvedValue(undefined)}
+        onUpdateTracker={vi.fn().mockResolvedValue(undefined)}
+      />
+    );
+
+    fireEvent.click(screen.getByRole("tab", { name: /tracker/i }));
+    expect(screen.getByText("Morgan Manager")).toBeInTheDocument();
+    fireEvent.click(screen.getByRole("button", { name: "Remove contact Morgan Manager" }));
+    expect(onDeleteContact).toHaveBeenCalledWith(7);
+
+    fireEvent.change(screen.getByLabelText("Contact name"), { target: { value: "Casey Recruiter" } });
+    fireEvent.click(screen.getByRole("button", { name: "Add Contact" }));
+    expect(onSaveContact).toHaveBeenCalledWith({
+      role: "recruiter",
+      name: "Casey Recruiter",
+      email: undefined
+    });
+
+    fireEvent.change(screen.getByLabelText("Interview date"), { target: { value: "2026-03-12" } });
+    fireEvent.change(screen.getByLabelText("Interview type"), { target: { value: "technical" } });
+    fireEvent.change(screen.getByLabelText("Interviewers"), { target: { value: "Ana, Bo" } });
+    fireEvent.click(screen.getByRole("button", { name: "Add Interview" }));
+    expect(onSaveInterview).toHaveBeenCalledWith({
+      scheduledAt: "2026-03-12",
+      kind: "technical",
+      interviewers: ["Ana", "Bo"],
+      outcome: undefined
+    });
+  });
 });
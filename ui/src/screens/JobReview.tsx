@@ -5,19 +5,51 @@ import { DiffViewer } from "../components/DiffViewer";
 import { FitScoreCard } from "../components/FitScoreCard";
 import { GapList } from "../components/GapList";
 import { MarkdownViewer } from "../components/MarkdownViewer";
-import type { AppTab, PacketDetail } from "../lib/types";
+import type {
+  AppTab,
+  Contact,
+  ContactDraft,
+  Interview,
+  InterviewDraft,
+  JobEvent,
+  PacketDetail,
+  StatusWorkflow
+} from "../lib/types";
 
 interface Props {
   detail?: PacketDetail;
+  timeline?: JobEvent[];
+  workflow?: StatusWorkflow;
+  nextActionDue?: string;
+  contacts?: Contact[];
+  interviews?: Interview[];
+  onSaveContact?: (draft: ContactDraft) => Promise<void>;
+  onDeleteContact?: (id: number) => Promise<void>;
+  onSaveInterview?: (draft: InterviewDraft) => Promise<void>;
+  onDeleteInterview?: (id: number) => Promise<void>;
   approvedOnly: boolean;
   onCopy: (text: string) => Promise<void>;
   onOpenFolder: (path: string) => Promise<void>;
   onExportMarkdown: (packetDir: string) => Promise<void>;
   onExportDocx: (packetDir: string) => Promise<void>;
   onExportPdf: (packetDir: string) => Promise<void>;
-  onUpdateTracker: (status: string, nextAction: string, notes: string) => Promise<void>;
+  onUpdateTracker: (
+    status: string,
+    nextAction: string,
+    notes: string,
+    closedReason: string,
+    nextActionDue: string
+  ) => Promise<void>;
 }
 
+const legacyStatuses = ["new", "applied", "reply", "interview", "closed"];
+
+const contactRoles: Array<{ id: Contact["role"]; label: string }> = [
+  { id: "recruiter", label: "Recruiter" },
+  { id: "hiring_manager", label: "Hiring manager" },
+  { id: "referrer", label: "Referrer" }
+];
+
 const tabs: Array<{ id: AppTab; label: string }> = [
   { id: "overview", label: "Overview" },
   { id: "resume", label: "Resume" },
@@ -28,6 +60,15 @@ const tabs: Array<{ id: AppTab; label: string }> = [
 
 export function JobReview({
   detail,
+  timeline = [],
+  workflow,
+  nextActionDue,
+  contacts = [],
+  interviews = [],
+  onSaveContact,
+  onDeleteContact,
+  onSaveInterview,
+  onDeleteInterview,
   approvedOnly,
   onCopy,
   onOpenFolder,
@@ -40,21 +81,41 @@ export function JobReview({
   const [status, setStatus] = useState(detail?.trackerRow.status ?? "new");
   const [nextAction, setNextAction] = useState(detail?.trackerRow.nextAction ?? "");
   const [notes, setNotes] = useState("");
+  const [closedReason, setClosedReason] = useState("");
+  const [due, setDue] = useState(nextActionDue ?? "");
+  const [contactDraft, setContactDraft] = useState<ContactDraft>({ role: "recruiter", name: "" });
+  const [interviewDraft, setInterviewDraft] = useState({
+    scheduledAt: "",
+    kind: "",
+    interviewers: "",
+    outcome: ""
+  });
 
   useEffect(() => {
     setStatus(detail?.trackerRow.status ?? "new");
     setNextAction(detail?.trackerRow.nextAction ?? "");
     setNotes("");
-  }, [detail?.packetDir, detail?.trackerRow.status, detail?.trackerRow.nextAction]);
+    setClosedReason("");
+    setDue(nextActionDue ?? "");
+  }, [detail?.packetDir, detail?.trackerRow.status, detail?.trackerRow.nextAction, nextActionDue]);
 
   const packetDir = detail?.packetDir;
   const trackerDirty = useMemo(() => {
     return (
       status !== (detail?.trackerRow.status ?? "new") ||
       nextAction !== (detail?.trackerRow.nextAction ?? "") ||
-      notes.length > 0
+      due !== (nextActionDue ?? "") ||
+      notes.length > 0 ||
+      closedReason.length > 0
     );
-  }, [status, nextAction, notes, detail]);
+  }, [status, nextAction, due, notes, closedReason, detail, nextActionDue]);
+
+  const currentStatus = detail?.trackerRow.status ?? "new";
+  // Only the current status and the moves the configured workflow allows from it.
+  const statusOptions = workflow
+    ? [currentStatus, ...(workflow.transitions[currentStatus] ?? [])]
+    : legacyStatuses;
+  const closing = workflow?.closedStatuses.includes(status) ?? false;
 
   return (
     <section className="stack-lg">
@@ -101,6 +162,16 @@ export function JobReview({
                     summarize_jd fallback: {detail.extractionDiagnostics.summarizeFallbackReasons.join(", ")}
                   </p>
                 ) : null}
+                {detail.extractionDiagnostics?.injectionWarnings?.length ? (
+                  <div className="subtle">
+                    <strong>Possible prompt injection (withheld from LLM):</strong>
+                    <ul>
+                      {detail.extractionDiagnostics.injectionWarnings.map((line) => (
+                        <li key={line}>{line}</li>
+                      ))}
+                    </ul>
+                  </div>
+                ) : null}
                 <p>
                   <strong>Keywords:</strong> {detail.extractedKeywords.join(", ") || "-"}
                 </p>
@@ -211,13 +282,23 @@ export function JobReview({
               <label>
                 Status
                 <select value={status} onChange={(e) => setStatus(e.target.value)}>
-                  <option value="new">new</option>
-                  <option value="applied">applied</option>
-                  <option value="reply">reply</option>
-                  <option value="interview">interview</option>
-                  <option value="closed">closed</option>
+                  {statusOptions.map((option) => (
+                    <option key={option} value={option}>
+                      {option}
+                    </option>
+                  ))}
                 </select>
               </label>
+              {closing ? (
+                <label>
+                  Closed reason
+                  <input
+                    value={closedReason}
+                    onChange={(e) => setClosedReason(e.target.value)}
+                    placeholder="Role filled internally"
+                  />
+                </label>
+              ) : null}
               <label>
                 Next action
                 <input
@@ -226,6 +307,10 @@ export function JobReview({
                   placeholder="Follow up on Monday"
                 />
               </label>
+              <label>
+                Due date
+                <input type="date" value={due} onChange={(e) => setDue(e.target.value)} />
+              </label>
               <label>
                 Notes
                 <textarea
@@ -241,8 +326,15 @@ export function JobReview({
                   disabled={!trackerDirty}
                   onClick={async () => {
                     try {
-                      await onUpdateTracker(status, nextAction, notes);
+                      await onUpdateTracker(
+                        status,
+                        nextAction,
+                        notes,
+                        closing ? closedReason : "",
+                        due
+                      );
                       setNotes("");
+                      setClosedReason("");
                     } catch {
                       // parent handler already surfaces user-facing error feedback
                     }
@@ -251,6 +343,172 @@ export function JobReview({
                   Save Tracker
                 </button>
               </div>
+              <h3>Contacts</h3>
+              {contacts.length ? (
+                <ul className="stack-sm">
+                  {contacts.map((contact) => (
+                    <li key={contact.id} className="row between wrap">
+                      <span>
+                        <strong>{contact.name}</strong>{" "}
+                        <span className="subtle">
+                          {contactRoles.find((r) => r.id === contact.role)?.label ?? contact.role}
+                          {contact.email ? ` · ${contact.email}` : ""}
+                        </span>
+                      </span>
+                      {onDeleteContact ? (
+                        <button
+                          className="btn btn-secondary"
+                          aria-label={`Remove contact ${contact.name}`}
+                          onClick={() => void onDeleteContact(contact.id)}
+                        >
+                          Remove
+                        </button>
+                      ) : null}
+                    </li>
+                  ))}
+                </ul>
+              ) : (
+                <p className="subtle">No contacts yet. Recruiter and hiring manager names greet regenerated messages.</p>
+              )}
+              {onSaveContact ? (
+                <div className="row wrap">
+                  <label>
+                    Contact role
+                    <select
+                      value={contactDraft.role}
+                      onChange={(e) =>
+                        setContactDraft({ ...contactDraft, role: e.target.value as Contact["role"] })
+                      }
+                    >
+                      {contactRoles.map((role) => (
+                        <option key={role.id} value={role.id}>
+                          {role.label}
+                        </option>
+                      ))}
+                    </select>
+                  </label>
+                  <label>
+                    Contact name
+                    <input
+                      value={contactDraft.name}
+                      onChange={(e) => setContactDraft({ ...contactDraft, name: e.target.value })}
+                    />
+                  </label>
+                  <label>
+                    Contact email
+                    <input
+                      value={contactDraft.email ?? ""}
+                      onChange={(e) => setContactDraft({ ...contactDraft, email: e.target.value })}
+                    />
+                  </label>
+                  <button
+                    className="btn"
+                    disabled={!contactDraft.name.trim()}
+                    onClick={async () => {
+                      await onSaveContact({
+                        ...contactDraft,
+                        email: contactDraft.email?.trim() || undefined
+                      });
+                      setContactDraft({ role: contactDraft.role, name: "" });
+                    }}
+                  >
+                    Add Contact
+                  </button>
+                </div>
+              ) : null}
+              <h3>Interviews</h3>
+              {interviews.length ? (
+                <ul className="stack-sm">
+                  {interviews.map((round) => (
+                    <li key={round.id} className="row between wrap">
+                      <span>
+                        <strong>{round.scheduledAt}</strong> {round.kind}
+                        {round.interviewers.length ? ` with ${round.interviewers.join(", ")}` : ""}
+                        <span className="subtle"> · {round.outcome ?? "pending"}</span>
+                      </span>
+                      {onDeleteInterview ? (
+                        <button
+                          className="btn btn-secondary"
+                          aria-label={`Remove ${round.kind} interview on ${round.scheduledAt}`}
+                          onClick={() => void onDeleteInterview(round.id)}
+                        >
+                          Remove
+                        </button>
+                      ) : null}
+                    </li>
+                  ))}
+                </ul>
+              ) : (
+                <p className="subtle">No interviews recorded.</p>
+              )}
+              {onSaveInterview ? (
+                <div className="row wrap">
+                  <label>
+                    Interview date
+                    <input
+                      type="date"
+                      value={interviewDraft.scheduledAt}
+                      onChange={(e) => setInterviewDraft({ ...interviewDraft, scheduledAt: e.target.value })}
+                    />
+                  </label>
+                  <label>
+                    Interview type
+                    <input
+                      value={interviewDraft.kind}
+                      onChange={(e) => setInterviewDraft({ ...interviewDraft, kind: e.target.value })}
+                      placeholder="phone_screen"
+                    />
+                  </label>
+                  <label>
+                    Interviewers
+                    <input
+                      value={interviewDraft.interviewers}
+                      onChange={(e) => setInterviewDraft({ ...interviewDraft, interviewers: e.target.value })}
+                      placeholder="Comma separated"
+                    />
+                  </label>
+                  <label>
+                    Outcome
+                    <input
+                      value={interviewDraft.outcome}
+                      onChange={(e) => setInterviewDraft({ ...interviewDraft, outcome: e.target.value })}
+                      placeholder="pending"
+                    />
+                  </label>
+                  <button
+                    className="btn"
+                    disabled={!interviewDraft.scheduledAt || !interviewDraft.kind.trim()}
+                    onClick={async () => {
+                      await onSaveInterview({
+                        scheduledAt: interviewDraft.scheduledAt,
+                        kind: interviewDraft.kind.trim(),
+                        interviewers: interviewDraft.interviewers
+                          .split(",")
+                          .map((name) => name.trim())
+                          .filter(Boolean),
+                        outcome: interviewDraft.outcome.trim() || undefined
+                      });
+                      setInterviewDraft({ scheduledAt: "", kind: "", interviewers: "", outcome: "" });
+                    }}
+                  >
+                    Add Interview
+                  </button>
+                </div>
+              ) : null}
+              <h3>Timeline</h3>
+              {timeline.length ? (
+                <ul className="stack-sm">
+                  {timeline.map((event) => (
+                    <li key={event.id}>
+                      <span className="subtle">{event.createdAt}</span> {event.eventType}
+                      {"to" in event.payload ? ` → ${String(event.payload.to ?? "")}` : ""}
+                      {"format" in event.payload ? ` (${String(event.payload.format)})` : ""}
+                    </li>
+                  ))}
+                </ul>
+              ) : (
+                <p className="subtle">No tracked events yet.</p>
+              )}
             </section>
           ) : null}
         </Tabs.Content>
@@ -16,3 +16,51 @@ describe("NewJob import", () => {
     });
   });
 });
+
+describe("NewJob duplicates", () => {
+  it("offers regenerating into an existing job when a duplicate is found", async () => {
+    const onGenerate = vi.fn().mockResolvedValue(undefined);
+    const onCheckDuplicates = vi.fn().mockResolvedValue([
+      {
+        jobId: "Acme:Support:2026-02-14:abc",
+        company: "Acme",
+        role: "Support",
+        status: "applied",
+        createdAt: "2026-02-14T09:00:00Z",
+        reason: "same_jd"
+      }
+    ]);
+    render(<NewJob busy={false} onGenerate={onGenerate} onCheckDuplicates={onCheckDuplicates} />);
+
+    fireEvent.change(screen.getByPlaceholderText("Acme"), { target: { value: "Acme" } });
+    fireEvent.change(screen.getByPlaceholderText("Senior Support Engineer"), { target: { value: "Support" } });
+    fireEvent.change(screen.getByPlaceholderText("Paste job description here"), { target: { value: "JD" } });
+    fireEvent.click(screen.getByRole("button", { name: /Generate Packet/ }));
+
+    expect(await screen.findByText("Possible duplicate")).toBeInTheDocument();
+    expect(onGenerate).not.toHaveBeenCalled();
+
+    fireEvent.click(screen.getByRole("button", { name: "Regenerate into this job" }));
+    await waitFor(() =>
+      expect(onGenerate).toHaveBeenCalledWith(
+        expect.objectContaining({ regenerateJobId: "Acme:Support:2026-02-14:abc" })
+      )
+    );
+  });
+});
+
+describe("NewJob cancel", () => {
+  it("shows a cancel button only while a cancellable generation is running", () => {
+    const onCancel = vi.fn().mockResolvedValue(undefined);
+    const onGenerate = vi.fn().mockResolvedValue(undefined);
+    const { rerender } = render(<NewJob busy={false} onGenerate={onGenerate} onCancel={onCancel} />);
+    expect(screen.queryByRole("button", { name: "Cancel generation" })).not.toBeInTheDocument();
+
+    rerender(<NewJob busy onGenerate={onGenerate} onCancel={onCancel} />);
+    fireEvent.click(screen.getByRole("button", { name: "Cancel generation" }));
+    expect(onCancel).toHaveBeenCalledTimes(1);
+
+    rerender(<NewJob busy onGenerate={onGenerate} />);
+    expect(screen.queryByRole("button", { name: "Cancel generation" })).not.toBeInTheDocument();
+  });
+});
@@ -1,18 +1,44 @@
 import { type ChangeEventHandler, useState } from "react";
-import type { GenerateRequest } from "../lib/types";
+import type { DuplicateJob, GenerateRequest } from "../lib/types";
 
 interface Props {
   busy: boolean;
   onGenerate: (request: GenerateRequest) => Promise<void>;
+  /** Set while a generation is running and can be cancelled. */
+  onCancel?: () => Promise<void>;
+  onCheckDuplicates?: (company: string, role: string, jdText: string) => Promise<DuplicateJob[]>;
 }
 
-export function NewJob({ busy, onGenerate }: Props) {
+const duplicateReasonLabel: Record<DuplicateJob["reason"], string> = {
+  same_jd: "same JD",
+  same_company_role: "same company and role"
+};
+
+export function NewJob({ busy, onGenerate, onCancel, onCheckDuplicates }: Props) {
   const [company, setCompany] = useState("");
   const [role, setRole] = useState("");
   const [source, setSource] = useState("manual");
   const [baseline, setBaseline] = useState<"1pg" | "2pg">("1pg");
   const [jdText, setJdText] = useState("");
+  const [bypassLlmCache, setBypassLlmCache] = useState(false);
   const [importFileName, setImportFileName] = useState<string | null>(null);
+  const [duplicates, setDuplicates] = useState<DuplicateJob[] | null>(null);
+
+  const generate = (regenerateJobId?: string) => {
+    setDuplicates(null);
+    return onGenerate({ company, role, source, baseline, jdText, bypassLlmCache, regenerateJobId });
+  };
+
+  const onGenerateClick = async () => {
+    if (onCheckDuplicates && duplicates === null) {
+      const found = await onCheckDuplicates(company, role, jdText);
+      if (found.length > 0) {
+        setDuplicates(found);
+        return;
+      }
+    }
+    await generate();
+  };
 
   const normalizeInput = (value: string) => {
     return value.replace(/\r\n/g, "\n").replace(/\r/g, "\n").trimEnd();
@@ -38,6 +64,7 @@ export function NewJob({ busy, onGenerate }: Props) {
     const text = await readTextFromFile(file);
     setJdText(normalizeInput(text));
     setImportFileName(file.name);
+    setDuplicates(null);
   };
 
   return (
@@ -50,13 +77,19 @@ export function NewJob({ busy, onGenerate }: Props) {
       <div className="card form-grid">
         <label>
           Company
-          <input value={company} onChange={(e) => setCompany(e.target.value)} placeholder="Acme" />
+          <input value={company} onChange={(e) => {
+              setCompany(e.target.value);
+              setDuplicates(null);
+            }} placeholder="Acme" />
         </label>
         <label>
           Role title
           <input
             value={role}
-            onChange={(e) => setRole(e.target.value)}
+            onChange={(e) => {
+              setRole(e.target.value);
+              setDuplicates(null);
+            }}
             placeholder="Senior Support Engineer"
           />
         </label>
@@ -84,21 +117,64 @@ export function NewJob({ busy, onGenerate }: Props) {
           </div>
           <textarea
             value={jdText}
-            onChange={(e) => setJdText(normalizeInput(e.target.value))}
+            onChange={(e) => {
+              setJdText(normalizeInput(e.target.value));
+              setDuplicates(null);
+            }}
             rows={14}
             placeholder="Paste job description here"
           />
         </label>
-        <div className="span-2 row end">
+        <div className="span-2 row between">
+          <label>
+            <input
+              type="checkbox"
+              checked={bypassLlmCache}
+              onChange={(e) => setBypassLlmCache(e.target.checked)}
+            />
+            Bypass LLM cache
+          </label>
           <button
             className="btn btn-primary"
             disabled={busy || !company || !role || !jdText}
-            onClick={() => onGenerate({ company, role, source, baseline, jdText })}
+            onClick={() => void onGenerateClick()}
           >
             {busy ? "Generating..." : "Generate Packet (⌘Enter)"}
           </button>
+          {busy && onCancel ? (
+            <button className="btn" onClick={() => void onCancel()}>
+              Cancel generation
+            </button>
+          ) : null}
         </div>
       </div>
+
+      {duplicates && duplicates.length > 0 ? (
+        <div className="card stack" role="alert">
+          <h3>Possible duplicate</h3>
+          <p className="subtle">
+            Regenerating into an existing job keeps its status history, contacts and interviews.
+          </p>
+          <ul className="stack">
+            {duplicates.map((job) => (
+              <li key={job.jobId} className="row between wrap">
+                <span>
+                  {job.company} - {job.role} <span className="chip">{job.status}</span>{" "}
+                  <span className="subtle">({duplicateReasonLabel[job.reason]})</span>
+                </span>
+                <button className="btn" disabled={busy} onClick={() => void generate(job.jobId)}>
+                  Regenerate into this job
+                </button>
+              </li>
+            ))}
+          </ul>
+          <div className="row">
+            <button className="btn btn-primary" disabled={busy} onClick={() => void generate()}>
+              Create new job anyway
+            </button>
+          </div>
+        </div>
+      ) : null}
     </section>
   );
 }
@@ -13,6 +13,10 @@ interface TemplatesPreviewResponse {
   recruiterTemplate: string;
   hiringManagerTemplate: string;
   coverShortTemplate: string;
+  summarizeJdPrompt: string;
+  summarizeJdRepairPrompt: string;
+  rewriteMessagePrompt: string;
+  rewriteBulletPrompt: string;
 }
 
 const templateOptions: Array<{ key: TemplateKey; label: string }> = [
@@ -20,7 +24,11 @@ const templateOptions: Array<{ key: TemplateKey; label: string }> = [
   { key: "resume_2pg_base", label: "Resume 2pg Base" },
   { key: "recruiter", label: "Recruiter Message" },
   { key: "hiring_manager", label: "Hiring Manager Message" },
-  { key: "cover_short", label: "Cover Short Message" }
+  { key: "cover_short", label: "Cover Short Message" },
+  { key: "summarize_jd_prompt", label: "LLM Prompt: Summarize JD" },
+  { key: "summarize_jd_repair_prompt", label: "LLM Prompt: Repair JD Summary" },
+  { key: "rewrite_message_prompt", label: "LLM Prompt: Rewrite Message" },
+  { key: "rewrite_bullet_prompt", label: "LLM Prompt: Rewrite Bullet" }
 ];
 
 export function Templates({ onNotify }: Props) {
@@ -67,7 +75,11 @@ export function Templates({ onNotify }: Props) {
       resume_2pg_base: preview.resume2pgBase,
       recruiter: preview.recruiterTemplate,
       hiring_manager: preview.hiringManagerTemplate,
-      cover_short: preview.coverShortTemplate
+      cover_short: preview.coverShortTemplate,
+      summarize_jd_prompt: preview.summarizeJdPrompt,
+      summarize_jd_repair_prompt: preview.summarizeJdRepairPrompt,
+      rewrite_message_prompt: preview.rewriteMessagePrompt,
+      rewrite_bullet_prompt: preview.rewriteBulletPrompt
     } satisfies Record<TemplateKey, string>;
   }, [preview]);
 
@@ -24,7 +24,6 @@ describe("accessibility", () => {
         ]}
         onNewJob={vi.fn()}
         onOpenJob={vi.fn()}
-        insights={{ repliesByTrack: [], commonGaps: [], keywordCorrelations: [] }}
       />
     );
 
@@ -56,7 +56,6 @@ describe("responsive layout hooks", () => {
         ]}
         onNewJob={vi.fn()}
         onOpenJob={onOpenJob}
-        insights={{ repliesByTrack: [], commonGaps: [], keywordCorrelations: [] }}
       />
     );
 